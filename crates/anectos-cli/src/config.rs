@@ -0,0 +1,56 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub const DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+
+/// Contents of the CLI config file (`~/.config/anectos/cli.toml` by default):
+///
+/// ```toml
+/// rpc_url = "http://127.0.0.1:8899"
+/// keypair_path = "/home/me/.config/solana/id.json"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub rpc_url: Option<String>,
+    pub keypair_path: Option<PathBuf>,
+}
+
+impl Config {
+    fn home_path(relative: &str) -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(relative))
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        Self::home_path(".config/anectos/cli.toml")
+    }
+
+    /// Loads `path`, or the default config file if it exists.
+    pub fn load(path: Option<&Path>) -> Result<Config> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match Self::default_path().filter(|p| p.exists()) {
+                Some(path) => path,
+                None => return Ok(Config::default()),
+            },
+        };
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading config {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("parsing config {}", path.display()))
+    }
+
+    /// Command-line flag, then config file, then the local validator.
+    pub fn rpc_url(&self, flag: Option<String>) -> String {
+        flag.or_else(|| self.rpc_url.clone())
+            .unwrap_or_else(|| DEFAULT_RPC_URL.to_string())
+    }
+
+    /// Command-line flag, then config file, then the Solana CLI default keypair.
+    pub fn keypair_path(&self, flag: Option<PathBuf>) -> Result<PathBuf> {
+        flag.or_else(|| self.keypair_path.clone())
+            .or_else(|| Self::home_path(".config/solana/id.json"))
+            .context("no keypair configured; pass --keypair or set keypair_path")
+    }
+}
@@ -0,0 +1,332 @@
+//! `anectos-cli`: round administration and inspection for operators.
+//!
+//! Every transaction command accepts `--dry-run` to simulate instead of sending,
+//! and `--json` for machine-readable output.
+
+mod config;
+mod rpc;
+mod view;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Discriminator, Space};
+use anectos::state::{Contribution, FundingRound, Project};
+use anectos_client::{accounts, instructions, pda};
+use anyhow::{anyhow, Context as _, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::{Parser, Subcommand};
+use serde_json::{json, Map, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use config::Config;
+use rpc::RpcClient;
+
+#[derive(Parser)]
+#[command(name = "anectos-cli", version, about = "Operator tooling for Anectos funding rounds")]
+struct Cli {
+    /// Config file (default: ~/.config/anectos/cli.toml)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// RPC URL, overrides the config file
+    #[arg(long, short = 'u', global = true)]
+    url: Option<String>,
+    /// Signer keypair path, overrides the config file
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<PathBuf>,
+    /// Simulate transactions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Funding round lifecycle
+    #[command(subcommand)]
+    Round(RoundCommand),
+    /// Project inspection
+    #[command(subcommand)]
+    Project(ProjectCommand),
+    /// Contribution records
+    #[command(subcommand)]
+    Contributions(ContributionsCommand),
+}
+
+#[derive(Subcommand)]
+enum RoundCommand {
+    /// Create a funding round and its metadata account
+    Init {
+        #[arg(long)]
+        matching_pool: u64,
+        #[arg(long)]
+        start_time: i64,
+        #[arg(long)]
+        end_time: i64,
+        #[arg(long)]
+        nft_metadata_uri: String,
+        /// Keypair for the new round account (default: a fresh keypair)
+        #[arg(long)]
+        round_keypair: Option<PathBuf>,
+    },
+    /// Create the round vault PDA that holds the matching pool
+    CreateVault { round: Pubkey },
+    /// Deposit lamports into the round vault
+    Fund { round: Pubkey, amount: u64 },
+    /// Set the declared matching pool to the round vault balance
+    SyncPool { round: Pubkey },
+    /// Set the area used as the settlement denominator
+    SetAreaMax { round: Pubkey, area_max: u128 },
+    /// Settle matching for the signer's project
+    Settle { round: Pubkey },
+    /// Deactivate and close the round account
+    Close { round: Pubkey },
+    /// Show the round and its metadata
+    Show { round: Pubkey },
+    /// Dump the raw round and project accounts for `anectos-sim verify`
+    Snapshot {
+        round: Pubkey,
+        /// Write the snapshot here instead of printing it
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProjectCommand {
+    /// Show the project created by `creator`, whose key seeds its address
+    Show { creator: Pubkey },
+}
+
+#[derive(Subcommand)]
+enum ContributionsCommand {
+    /// List every contribution to the project created by `creator`
+    List { creator: Pubkey },
+}
+
+struct Context {
+    rpc: RpcClient,
+    keypair_path: PathBuf,
+    dry_run: bool,
+}
+
+impl Context {
+    fn payer(&self) -> Result<Keypair> {
+        read_keypair_file(&self.keypair_path)
+            .map_err(|e| anyhow!("reading keypair {}: {e}", self.keypair_path.display()))
+    }
+
+    /// Signs and sends (or simulates) `ixs`, returning a report of the outcome.
+    fn submit(&self, action: &str, ixs: &[Instruction], extra_signers: &[&Keypair]) -> Result<Map<String, Value>> {
+        let payer = self.payer()?;
+        let mut signers = vec![&payer];
+        signers.extend_from_slice(extra_signers);
+        let blockhash = self.rpc.latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &signers, blockhash);
+
+        let mut report = Map::new();
+        report.insert("action".into(), json!(action));
+        report.insert("dry_run".into(), json!(self.dry_run));
+        if self.dry_run {
+            let simulation = self.rpc.simulate(&tx)?;
+            report.insert("success".into(), json!(simulation.err.is_none()));
+            report.insert("error".into(), simulation.err.unwrap_or(Value::Null));
+            report.insert("units_consumed".into(), json!(simulation.units_consumed));
+            report.insert("logs".into(), json!(simulation.logs));
+        } else {
+            let signature = self.rpc.send_and_confirm(&tx)?;
+            report.insert("success".into(), json!(true));
+            report.insert("signature".into(), json!(signature));
+        }
+        Ok(report)
+    }
+}
+
+fn run(cli: Cli) -> Result<Value> {
+    let config = Config::load(cli.config.as_deref())?;
+    let ctx = Context {
+        rpc: RpcClient::new(config.rpc_url(cli.url)),
+        keypair_path: config.keypair_path(cli.keypair)?,
+        dry_run: cli.dry_run,
+    };
+
+    let report = match cli.command {
+        Command::Round(command) => round(&ctx, command)?,
+        Command::Project(ProjectCommand::Show { creator }) => {
+            let address = pda::project_pda(&creator).0;
+            let project = accounts::fetch_project(&ctx.rpc, &creator)?;
+            view::project(&address, &project)
+        }
+        Command::Contributions(ContributionsCommand::List { creator }) => {
+            let project = pda::project_pda(&creator).0;
+            // Contribution layout: discriminator, `version`, then `project`
+            let records = ctx.rpc.program_accounts(
+                Contribution::DISCRIMINATOR.len() + Contribution::INIT_SPACE,
+                &[(Contribution::DISCRIMINATOR.len() + 1, project.as_ref())],
+            )?;
+            let mut contributions = records
+                .iter()
+                .map(|(address, data)| Ok((address, accounts::decode::<Contribution>(address, data)?)))
+                .collect::<Result<Vec<_>>>()?;
+            contributions.sort_by_key(|(_, c)| std::cmp::Reverse(c.total_contributed));
+            let total: u64 = contributions.iter().map(|(_, c)| c.total_contributed).sum();
+            json!({
+                "project": project.to_string(),
+                "count": contributions.len(),
+                "total_contributed": total,
+                "contributions": contributions
+                    .iter()
+                    .map(|(address, c)| view::contribution(address, c))
+                    .collect::<Vec<_>>(),
+            })
+        }
+    };
+    Ok(report)
+}
+
+fn round(ctx: &Context, command: RoundCommand) -> Result<Value> {
+    let owner = || ctx.payer().map(|k| k.pubkey());
+    let report = match command {
+        RoundCommand::Init {
+            matching_pool,
+            start_time,
+            end_time,
+            nft_metadata_uri,
+            round_keypair,
+        } => {
+            let round = match round_keypair {
+                Some(path) => read_keypair_file(&path)
+                    .map_err(|e| anyhow!("reading round keypair {}: {e}", path.display()))?,
+                None => Keypair::new(),
+            };
+            let ix = instructions::initialize_funding_round(
+                owner()?,
+                round.pubkey(),
+                matching_pool,
+                start_time,
+                end_time,
+                nft_metadata_uri,
+            );
+            let mut report = ctx.submit("initialize_funding_round", &[ix], &[&round])?;
+            report.insert("round".into(), json!(round.pubkey().to_string()));
+            report
+        }
+        RoundCommand::CreateVault { round } => {
+            let mut report = ctx.submit("create_round_vault", &[instructions::create_round_vault(owner()?, round)], &[])?;
+            report.insert("round_vault".into(), json!(pda::round_vault_pda(&round).0.to_string()));
+            report
+        }
+        RoundCommand::Fund { round, amount } => {
+            ctx.submit("fund_round_pool", &[instructions::fund_round_pool(owner()?, round, amount)], &[])?
+        }
+        RoundCommand::SyncPool { round } => {
+            ctx.submit("set_matching_pool", &[instructions::set_matching_pool(owner()?, round)], &[])?
+        }
+        RoundCommand::SetAreaMax { round, area_max } => {
+            ctx.submit("set_area_max", &[instructions::set_area_max(owner()?, round, area_max)], &[])?
+        }
+        RoundCommand::Settle { round } => ctx.submit(
+            "settle_matching_for_project",
+            &[instructions::settle_matching_for_project(owner()?, round)],
+            &[],
+        )?,
+        RoundCommand::Close { round } => {
+            ctx.submit("close_round", &[instructions::close_round(owner()?, round)], &[])?
+        }
+        RoundCommand::Show { round } => {
+            let funding_round: FundingRound = accounts::fetch_funding_round(&ctx.rpc, &round)?;
+            let meta = accounts::fetch_optional(&ctx.rpc, &pda::funding_round_metadata_pda(&round).0)?;
+            return Ok(view::round(&round, &funding_round, meta.as_ref()));
+        }
+        RoundCommand::Snapshot { round, out } => {
+            let round_data = ctx
+                .rpc
+                .get_account_data(&round)?
+                .ok_or_else(|| anyhow!("round {round} not found"))?;
+            // Project layout: discriminator, `version`, `project_id`, then `round`
+            let projects = ctx.rpc.program_accounts(
+                Project::DISCRIMINATOR.len() + Project::INIT_SPACE,
+                &[(Project::DISCRIMINATOR.len() + 1 + 32, round.as_ref())],
+            )?;
+            let mut snapshot = Map::new();
+            snapshot.insert(round.to_string(), json!(STANDARD.encode(round_data)));
+            for (address, data) in &projects {
+                snapshot.insert(address.to_string(), json!(STANDARD.encode(data)));
+            }
+            let snapshot = json!({ "accounts": snapshot });
+            let Some(out) = out else {
+                return Ok(snapshot);
+            };
+            std::fs::write(&out, serde_json::to_string_pretty(&snapshot)?)
+                .with_context(|| format!("writing {}", out.display()))?;
+            let mut report = Map::new();
+            report.insert("round".into(), json!(round.to_string()));
+            report.insert("projects".into(), json!(projects.len()));
+            report.insert("out".into(), json!(out.display().to_string()));
+            report
+        }
+    };
+    Ok(Value::Object(report))
+}
+
+fn print_text(value: &Value, indent: usize) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    Value::Object(_) | Value::Array(_) => {
+                        println!("{pad}{key}:");
+                        print_text(value, indent + 2);
+                    }
+                    Value::String(s) => println!("{pad}{key}: {s}"),
+                    other => println!("{pad}{key}: {other}"),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                println!("{pad}[{i}]");
+                print_text(item, indent + 2);
+            }
+        }
+        Value::String(s) => println!("{pad}{s}"),
+        other => println!("{pad}{other}"),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let json_output = cli.json;
+    match run(cli) {
+        Ok(report) => {
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+            } else {
+                print_text(&report, 0);
+            }
+            // A failed simulation is reported but still exits non-zero for scripts
+            if report["success"] == json!(false) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            }
+        }
+        Err(e) => {
+            if json_output {
+                println!("{}", json!({ "success": false, "error": format!("{e:#}") }));
+            } else {
+                eprintln!("error: {e:#}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
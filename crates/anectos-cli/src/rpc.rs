@@ -0,0 +1,159 @@
+//! Minimal JSON-RPC client covering the calls the CLI needs.
+
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anectos_client::accounts::AccountFetcher;
+use anectos_client::ClientError;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_transaction::Transaction;
+
+const COMMITMENT: &str = "confirmed";
+const CONFIRM_ATTEMPTS: u32 = 60;
+
+pub struct Simulation {
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self { url, agent: ureq::Agent::new() }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .with_context(|| format!("{method} request to {}", self.url))?
+            .into_json()?;
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+        Ok(response["result"].clone())
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": COMMITMENT }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .context("getLatestBlockhash returned no blockhash")?;
+        Hash::from_str(blockhash).map_err(|e| anyhow!("invalid blockhash {blockhash}: {e}"))
+    }
+
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": COMMITMENT }]),
+        )?;
+        if result["value"].is_null() {
+            return Ok(None);
+        }
+        decode_data(&result["value"]["data"]).map(Some)
+    }
+
+    /// Program accounts of exactly `data_size` bytes matching every `(offset, bytes)` filter.
+    pub fn program_accounts(&self, data_size: usize, filters: &[(usize, &[u8])]) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let mut rpc_filters = vec![json!({ "dataSize": data_size })];
+        for (offset, bytes) in filters {
+            rpc_filters.push(json!({
+                "memcmp": { "offset": offset, "bytes": STANDARD.encode(bytes), "encoding": "base64" }
+            }));
+        }
+        let result = self.call(
+            "getProgramAccounts",
+            json!([
+                anectos::ID.to_string(),
+                { "encoding": "base64", "commitment": COMMITMENT, "filters": rpc_filters }
+            ]),
+        )?;
+        result
+            .as_array()
+            .context("getProgramAccounts returned no accounts")?
+            .iter()
+            .map(|entry| {
+                let pubkey = entry["pubkey"].as_str().context("missing pubkey")?;
+                let pubkey = Pubkey::from_str(pubkey).map_err(|e| anyhow!("invalid pubkey {pubkey}: {e}"))?;
+                Ok((pubkey, decode_data(&entry["account"]["data"])?))
+            })
+            .collect()
+    }
+
+    pub fn simulate(&self, tx: &Transaction) -> Result<Simulation> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([encode_tx(tx)?, { "encoding": "base64", "commitment": COMMITMENT, "sigVerify": true }]),
+        )?;
+        let value = &result["value"];
+        Ok(Simulation {
+            err: Some(value["err"].clone()).filter(|e| !e.is_null()),
+            logs: value["logs"]
+                .as_array()
+                .map(|logs| logs.iter().filter_map(|l| l.as_str().map(str::to_string)).collect())
+                .unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// Sends `tx` with preflight checks and waits until it is confirmed.
+    pub fn send_and_confirm(&self, tx: &Transaction) -> Result<String> {
+        let signature = self
+            .call(
+                "sendTransaction",
+                json!([encode_tx(tx)?, { "encoding": "base64", "preflightCommitment": COMMITMENT }]),
+            )?
+            .as_str()
+            .context("sendTransaction returned no signature")?
+            .to_string();
+
+        for _ in 0..CONFIRM_ATTEMPTS {
+            let result = self.call(
+                "getSignatureStatuses",
+                json!([[signature], { "searchTransactionHistory": false }]),
+            )?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {signature} failed: {}", status["err"]);
+                }
+                if matches!(status["confirmationStatus"].as_str(), Some("confirmed" | "finalized")) {
+                    return Ok(signature);
+                }
+            }
+            sleep(Duration::from_millis(500));
+        }
+        bail!("transaction {signature} was not confirmed in time")
+    }
+}
+
+impl AccountFetcher for RpcClient {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        self.get_account_data(address).map_err(|e| ClientError::Fetch {
+            address: *address,
+            message: e.to_string(),
+        })
+    }
+}
+
+fn encode_tx(tx: &Transaction) -> Result<String> {
+    Ok(STANDARD.encode(bincode::serialize(tx)?))
+}
+
+/// Decodes `["<base64>", "base64"]` account data.
+fn decode_data(data: &Value) -> Result<Vec<u8>> {
+    let encoded = data[0].as_str().context("account data is not base64 encoded")?;
+    Ok(STANDARD.decode(encoded)?)
+}
@@ -0,0 +1,89 @@
+//! JSON views of program accounts for `show` and `list` output.
+
+use anchor_lang::prelude::Pubkey;
+use anectos::state::{Contribution, FundingRound, Ledger, Project};
+use anectos::FundingRoundMeta;
+use serde_json::{json, Value};
+
+fn ledger(ledger: &Ledger) -> Value {
+    json!({
+        "donations_received": ledger.donations_received,
+        "donations_withdrawn": ledger.donations_withdrawn,
+        "matching_allocated": ledger.matching_allocated,
+        "matching_withdrawn": ledger.matching_withdrawn,
+        "refunds_paid": ledger.refunds_paid,
+        "matching_returned": ledger.matching_returned,
+    })
+}
+
+pub fn round(address: &Pubkey, round: &FundingRound, meta: Option<&FundingRoundMeta>) -> Value {
+    json!({
+        "address": address.to_string(),
+        "owner": round.owner.to_string(),
+        "is_active": round.is_active,
+        "matching_pool": round.matching_pool,
+        "pool_distributed": round.pool_distributed,
+        "total_donations": round.total_donations,
+        "contributor_count": round.contributor_count,
+        // u128 areas are rendered as strings so JSON consumers don't lose precision
+        "area": round.area.to_string(),
+        "area_max": round.area_max.to_string(),
+        "round_vault": round.project_vault.to_string(),
+        "reviewer": round.reviewer.to_string(),
+        "arbitrator": round.arbitrator.to_string(),
+        "rollover_round": round.rollover_round.to_string(),
+        "settlement_started": round.settlement_started,
+        "paused_groups": round.paused_groups,
+        "vesting_cliff_secs": round.vesting_cliff_secs,
+        "vesting_duration_secs": round.vesting_duration_secs,
+        "ledger": ledger(&round.ledger),
+        "start_time": meta.map(|m| m.start_time),
+        "end_time": meta.map(|m| m.end_time),
+        "nft_metadata_uri": meta.map(|m| m.nft_metadata_uri.clone()),
+    })
+}
+
+pub fn project(address: &Pubkey, project: &Project) -> Value {
+    let milestones: Vec<Value> = project
+        .milestones
+        .iter()
+        .map(|m| {
+            json!({
+                "amount": m.amount,
+                "is_achieved": m.is_achieved,
+                "due_date": m.due_date,
+                "matching_clawed_back": m.matching_clawed_back,
+                "released_at": m.released_at,
+            })
+        })
+        .collect();
+    json!({
+        "address": address.to_string(),
+        "owner": project.owner.to_string(),
+        "round": project.round.to_string(),
+        "target_amount": project.target_amount,
+        "current_funding": project.current_funding,
+        "matching_pool": project.matching_pool,
+        "matching_unlocked": project.matching_unlocked,
+        "pool_distributed": project.pool_distributed,
+        "area": project.area.to_string(),
+        "is_whitelisted": project.is_whitelisted,
+        "refunds_enabled": project.refunds_enabled,
+        "disputed_amount": project.disputed_amount,
+        "vesting_start": project.vesting_start,
+        "vesting_cliff": project.vesting_cliff,
+        "vesting_end": project.vesting_end,
+        "milestones": milestones,
+        "ledger": ledger(&project.ledger),
+    })
+}
+
+pub fn contribution(address: &Pubkey, contribution: &Contribution) -> Value {
+    json!({
+        "address": address.to_string(),
+        "contributor": contribution.contributor.to_string(),
+        "total_contributed": contribution.total_contributed,
+        "is_excluded": contribution.is_excluded,
+        "refunded": contribution.refunded,
+    })
+}
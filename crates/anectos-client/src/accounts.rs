@@ -0,0 +1,79 @@
+//! Account fetch and decode helpers.
+//!
+//! Fetching is abstracted behind [`AccountFetcher`] so callers can plug in their
+//! own RPC client, a cache, or an in-memory snapshot.
+
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use anectos::state::{Contribution, FundingRound, Project, ProjectMeta};
+use anectos::FundingRoundMeta;
+
+use crate::pda::*;
+use crate::ClientError;
+
+pub trait AccountFetcher {
+    /// Returns the raw account data, or `None` if the account does not exist.
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError>;
+}
+
+/// An in-memory snapshot of account data keyed by address
+impl AccountFetcher for HashMap<Pubkey, Vec<u8>> {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        Ok(self.get(address).cloned())
+    }
+}
+
+/// Decodes an Anchor account, checking its discriminator.
+pub fn decode<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T, ClientError> {
+    let mut data = data;
+    T::try_deserialize(&mut data).map_err(|source| ClientError::AccountDecode {
+        address: *address,
+        source,
+    })
+}
+
+pub fn fetch_optional<T: AccountDeserialize>(
+    fetcher: &impl AccountFetcher,
+    address: &Pubkey,
+) -> Result<Option<T>, ClientError> {
+    fetcher
+        .account_data(address)?
+        .map(|data| decode(address, &data))
+        .transpose()
+}
+
+pub fn fetch<T: AccountDeserialize>(fetcher: &impl AccountFetcher, address: &Pubkey) -> Result<T, ClientError> {
+    fetch_optional(fetcher, address)?.ok_or(ClientError::AccountNotFound(*address))
+}
+
+/// `creator` is the key the project was created with, which seeds its address; after
+/// an ownership transfer it differs from `Project::owner`.
+pub fn fetch_project(fetcher: &impl AccountFetcher, creator: &Pubkey) -> Result<Project, ClientError> {
+    fetch(fetcher, &project_pda(creator).0)
+}
+
+pub fn fetch_project_meta(fetcher: &impl AccountFetcher, project: &Pubkey) -> Result<ProjectMeta, ClientError> {
+    fetch(fetcher, &project_metadata_pda(project).0)
+}
+
+pub fn fetch_funding_round(fetcher: &impl AccountFetcher, funding_round: &Pubkey) -> Result<FundingRound, ClientError> {
+    fetch(fetcher, funding_round)
+}
+
+pub fn fetch_funding_round_meta(
+    fetcher: &impl AccountFetcher,
+    funding_round: &Pubkey,
+) -> Result<FundingRoundMeta, ClientError> {
+    fetch(fetcher, &funding_round_metadata_pda(funding_round).0)
+}
+
+/// Returns `None` if `contributor` has never contributed to `project`.
+pub fn fetch_contribution(
+    fetcher: &impl AccountFetcher,
+    project: &Pubkey,
+    contributor: &Pubkey,
+) -> Result<Option<Contribution>, ClientError> {
+    fetch_optional(fetcher, &contribution_pda(project, contributor).0)
+}
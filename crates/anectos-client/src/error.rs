@@ -0,0 +1,15 @@
+use anchor_lang::prelude::Pubkey;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("failed to decode account {address}: {source}")]
+    AccountDecode {
+        address: Pubkey,
+        source: anchor_lang::error::Error,
+    },
+    #[error("failed to fetch account {address}: {message}")]
+    Fetch { address: Pubkey, message: String },
+}
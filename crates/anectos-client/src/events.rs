@@ -0,0 +1,115 @@
+//! Event decoding.
+//!
+//! The program emits events through a self-CPI (`emit_cpi!`), so they are read from
+//! the transaction's inner instructions rather than its (truncatable) logs.
+//! [`parse_logs`] is kept for transactions from before CPI emission.
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anectos::state::*;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+macro_rules! anectos_events {
+    ($($name:ident),* $(,)?) => {
+        /// Every event the program can emit
+        pub enum AnectosEvent {
+            $($name($name),)*
+        }
+
+        impl AnectosEvent {
+            /// Decodes `discriminator || borsh(event)`.
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                $(
+                    if let Some(body) = data.strip_prefix($name::DISCRIMINATOR) {
+                        return $name::try_from_slice(body).ok().map(Self::$name);
+                    }
+                )*
+                None
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name(_) => stringify!($name),)*
+                }
+            }
+        }
+    };
+}
+
+anectos_events!(
+    ContributionMade,
+    ProjectCreated,
+    MilestoneCompleted,
+    ContributionFlagged,
+    ProposalCreated,
+    VoteCast,
+    VoterStakeUpdated,
+    ProposalFinalized,
+    QuadraticVoteCast,
+    MilestoneSubmitted,
+    MilestoneVoteCast,
+    MilestoneRejected,
+    RefundClaimed,
+    MilestoneChallenged,
+    ChallengeResolved,
+    PauseUpdated,
+    MatchingClaimed,
+    VestingCancelled,
+    MatchingClawedBack,
+    RoundInitialized,
+    RoundClosed,
+    RoundVaultCreated,
+    RoundPoolFunded,
+    ProjectPoolFunded,
+    MatchingPoolSynced,
+    MatchingSettled,
+    FundingStageChanged,
+    FundsWithdrawn,
+    AreaMaxUpdated,
+    RoundSettingsUpdated,
+    MilestoneDeadlinesSet,
+    GovernanceConfigUpdated,
+    GuardiansUpdated,
+    AccountMigrated,
+    ProjectMetaUpdated,
+    ProjectOwnerProposed,
+    ProjectOwnerTransferred,
+    PayoutSplitsSet,
+    PayoutDistributed,
+    ProjectTeamConfigured,
+    TeamActionProposed,
+    TeamActionApproved,
+    TeamActionExecuted,
+    ContributionWithdrawn,
+    ContributionClosed,
+    ProjectClosed,
+);
+
+/// Decodes the data of an event self-CPI instruction (`EVENT_IX_TAG_LE || event`).
+pub fn decode_cpi_event(ix_data: &[u8]) -> Option<AnectosEvent> {
+    AnectosEvent::decode(ix_data.strip_prefix(EVENT_IX_TAG_LE)?)
+}
+
+/// Collects events from a transaction's inner instructions, given as
+/// `(program_id, data)` pairs. Instructions for other programs are skipped.
+pub fn parse_inner_instructions<'a>(
+    instructions: impl IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+) -> Vec<AnectosEvent> {
+    instructions
+        .into_iter()
+        .filter(|(program_id, _)| **program_id == anectos::ID)
+        .filter_map(|(_, data)| decode_cpi_event(data))
+        .collect()
+}
+
+/// Collects events logged with `emit!` as `Program data: <base64>` lines.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<AnectosEvent> {
+    logs.iter()
+        .filter_map(|line| line.as_ref().strip_prefix(PROGRAM_DATA))
+        .filter_map(|encoded| STANDARD.decode(encoded.trim()).ok())
+        .filter_map(|data| AnectosEvent::decode(&data))
+        .collect()
+}
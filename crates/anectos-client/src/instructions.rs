@@ -0,0 +1,1111 @@
+//! Instruction builders mirroring `app/src/lib/instructions.ts`.
+//!
+//! Each builder derives the PDAs it needs; pass the wallet and round keys only.
+//! Project and vault PDAs are seeded by the key a project was created with, so after
+//! an ownership transfer pass the creator where a builder derives them from the owner.
+//! Every program instruction has a builder here; `client_test` fails when one is
+//! missing. [`build`] assembles variants from the generated `accounts::*` and
+//! `instruction::*` types, e.g. with accounts a builder does not derive.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anectos::{accounts, instruction, FundingStage, PayoutRecipient, ProposalAction, SDGGoals, TeamAction, VotingMode};
+
+use crate::pda::*;
+
+/// Builds an Anectos instruction from its generated account and argument structs.
+pub fn build<A: ToAccountMetas, D: InstructionData>(accounts: A, data: D) -> Instruction {
+    Instruction {
+        program_id: anectos::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn event_authority() -> Pubkey {
+    event_authority_pda().0
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_project(
+    owner: Pubkey,
+    round: Pubkey,
+    title: String,
+    description: String,
+    target_amount: u64,
+    milestone_count: u8,
+    sdg_goals: Vec<SDGGoals>,
+    project_image_metadata_uri: String,
+) -> Instruction {
+    let project = project_pda(&owner).0;
+    build(
+        accounts::CreateProject {
+            owner,
+            project,
+            project_metadata: project_metadata_pda(&project).0,
+            project_tombstone: project_tombstone_pda(&owner).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CreateProject {
+            title,
+            description,
+            round,
+            target_amount,
+            milestone_count,
+            sdg_goals,
+            project_image_metadata_uri,
+        },
+    )
+}
+
+/// `funding_round` is a fresh keypair and must also sign the transaction.
+pub fn initialize_funding_round(
+    owner: Pubkey,
+    funding_round: Pubkey,
+    matching_pool: u64,
+    start_time: i64,
+    end_time: i64,
+    nft_metadata_uri: String,
+) -> Instruction {
+    build(
+        accounts::InitializeFundingRound {
+            owner,
+            funding_round,
+            funding_round_metadata: funding_round_metadata_pda(&funding_round).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::InitializeFundingRound {
+            matching_pool,
+            start_time,
+            end_time,
+            nft_metadata_uri,
+        },
+    )
+}
+
+pub fn contribute(user: Pubkey, funding_round: Pubkey, project_owner: Pubkey, amount: u64) -> Instruction {
+    let project = project_pda(&project_owner).0;
+    build(
+        accounts::Contribute {
+            funding_round,
+            project,
+            vault: vault_pda(&project_owner).0,
+            user,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            contribution: contribution_pda(&project, &user).0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::Contribute { amount },
+    )
+}
+
+/// One leg per `(creator, amount)`, in order.
+pub fn contribute_batch(user: Pubkey, funding_round: Pubkey, legs: &[(Pubkey, u64)]) -> Instruction {
+    let mut ix = build(
+        accounts::ContributeBatch {
+            funding_round,
+            user,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ContributeBatch { amounts: legs.iter().map(|&(_, amount)| amount).collect() },
+    );
+    for (creator, _) in legs {
+        let project = project_pda(creator).0;
+        ix.accounts.extend([
+            AccountMeta::new(project, false),
+            AccountMeta::new(vault_pda(creator).0, false),
+            AccountMeta::new(contribution_pda(&project, &user).0, false),
+        ]);
+    }
+    ix
+}
+
+pub fn withdraw_contribution(contributor: Pubkey, funding_round: Pubkey, creator: Pubkey, amount: u64) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::WithdrawContribution {
+            contributor,
+            funding_round,
+            funding_round_metadata: funding_round_metadata_pda(&funding_round).0,
+            project,
+            vault: vault_pda(&creator).0,
+            contribution: contribution_pda(&project, &contributor).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::WithdrawContribution { amount },
+    )
+}
+
+pub fn complete_milestone(authority: Pubkey, project_owner: Pubkey, milestone_index: u8) -> Instruction {
+    let project = project_pda(&project_owner).0;
+    build(
+        accounts::CompleteMilestone {
+            authority,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            milestone_review: milestone_review_pda(&project, milestone_index).0,
+            vault: vault_pda(&project_owner).0,
+            protocol_config: protocol_config_pda().0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CompleteMilestone { milestone_index },
+    )
+}
+
+pub fn change_project_funding_stage(
+    user: Pubkey,
+    project_owner: Pubkey,
+    funding_round: Pubkey,
+    new_stage: FundingStage,
+) -> Instruction {
+    let project = project_pda(&project_owner).0;
+    build(
+        accounts::ChangeProjectFundingStage {
+            user,
+            project_meta: project_metadata_pda(&project).0,
+            project,
+            funding_round,
+            funding_round_metadata: funding_round_metadata_pda(&funding_round).0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ChangeProjectFundingStage { new_stage },
+    )
+}
+
+pub fn close_round(owner: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::CloseRound {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CloseRound {},
+    )
+}
+
+pub fn distribute_funds_to_owner(owner: Pubkey, funding_round: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::DistributeFundsToOwner {
+            owner,
+            vault: vault_pda(&owner).0,
+            project: project_pda(&owner).0,
+            funding_round,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::DistributeFundsToOwner { amount },
+    )
+}
+
+pub fn create_round_vault(owner: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::CreateRoundVault {
+            owner,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CreateRoundVault {},
+    )
+}
+
+/// Settlement is signed by the project owner.
+pub fn settle_matching_for_project(project_owner: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::SettleMatchingForProject {
+            owner: project_owner,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            project: project_pda(&project_owner).0,
+            project_vault: vault_pda(&project_owner).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SettleMatchingForProject {},
+    )
+}
+
+pub fn set_matching_pool(owner: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::SetMatchingPool {
+            owner,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetMatchingPool {},
+    )
+}
+
+pub fn fund_round_pool(funder: Pubkey, funding_round: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::FundRoundPool {
+            funder,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::FundRoundPool { amount },
+    )
+}
+
+pub fn fund_project_pool(funder: Pubkey, funding_round: Pubkey, project_owner: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::FundProjectPool {
+            funder,
+            funding_round,
+            project: project_pda(&project_owner).0,
+            round_vault: round_vault_pda(&funding_round).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::FundProjectPool { amount },
+    )
+}
+
+pub fn set_area_max(owner: Pubkey, funding_round: Pubkey, area_max: u128) -> Instruction {
+    build(
+        accounts::SetAreaMax {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetAreaMax { area_max },
+    )
+}
+
+/// `admin` must be the program's upgrade authority.
+pub fn initialize_protocol_config(admin: Pubkey, guardians: Vec<Pubkey>, guardian_threshold: u8) -> Instruction {
+    build(
+        accounts::InitializeProtocolConfig {
+            admin,
+            protocol_config: protocol_config_pda().0,
+            program_data: program_data_address(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::InitializeProtocolConfig { guardians, guardian_threshold },
+    )
+}
+
+/// Pauses `paused_groups` for one round, or protocol-wide when `funding_round` is `None`.
+/// `approvers` are guardians co-signing once a guardian threshold is configured.
+pub fn set_pause(admin: Pubkey, funding_round: Option<Pubkey>, paused_groups: u8, approvers: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        accounts::SetPause {
+            admin,
+            protocol_config: protocol_config_pda().0,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetPause { paused_groups },
+    );
+    ix.accounts.extend(approvers.iter().map(|approver| AccountMeta::new_readonly(*approver, true)));
+    ix
+}
+
+/// Pass `round_owner` to co-sign an update made after the project left Planning.
+pub fn update_project_meta(
+    owner: Pubkey,
+    funding_round: Pubkey,
+    round_owner: Option<Pubkey>,
+    title: String,
+    description: String,
+    image_metadata_uri: String,
+    sdg_goals: Vec<SDGGoals>,
+) -> Instruction {
+    let project = project_pda(&owner).0;
+    build(
+        accounts::UpdateProjectMeta {
+            owner,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            funding_round,
+            round_owner,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::UpdateProjectMeta { title, description, image_metadata_uri, sdg_goals },
+    )
+}
+
+/// `creator` is the key the project was created with (`Project::creator`).
+pub fn propose_project_owner(owner: Pubkey, creator: Pubkey, new_owner: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::ProposeProjectOwner {
+            owner,
+            project,
+            owner_transfer: owner_transfer_pda(&project).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ProposeProjectOwner { new_owner },
+    )
+}
+
+/// `proposed_by` is the owner who nominated `new_owner` and receives the proposal's rent.
+pub fn accept_project_owner(new_owner: Pubkey, creator: Pubkey, proposed_by: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::AcceptProjectOwner {
+            new_owner,
+            project,
+            owner_transfer: owner_transfer_pda(&project).0,
+            proposed_by,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::AcceptProjectOwner {},
+    )
+}
+
+pub fn set_payout_splits(owner: Pubkey, creator: Pubkey, recipients: Vec<PayoutRecipient>) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::SetPayoutSplits {
+            owner,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            payout_config: payout_config_pda(&project).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetPayoutSplits { recipients },
+    )
+}
+
+/// `recipients` must list the configured payout recipients in order.
+pub fn distribute_payout(caller: Pubkey, creator: Pubkey, funding_round: Pubkey, recipients: &[Pubkey]) -> Instruction {
+    let project = project_pda(&creator).0;
+    let mut ix = build(
+        accounts::DistributePayout {
+            caller,
+            project,
+            funding_round,
+            vault: vault_pda(&creator).0,
+            payout_config: payout_config_pda(&project).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::DistributePayout {},
+    );
+    ix.accounts.extend(recipients.iter().map(|recipient| AccountMeta::new(*recipient, false)));
+    ix
+}
+
+pub fn configure_project_team(owner: Pubkey, creator: Pubkey, members: Vec<Pubkey>, threshold: u8) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::ConfigureProjectTeam {
+            owner,
+            project,
+            project_team: project_team_pda(&project).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ConfigureProjectTeam { members, threshold },
+    )
+}
+
+/// `id` is the team's current `action_count`.
+pub fn propose_team_action(member: Pubkey, creator: Pubkey, id: u64, action: TeamAction) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::ProposeTeamAction {
+            member,
+            project,
+            project_team: project_team_pda(&project).0,
+            team_action: team_action_pda(&project, id).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ProposeTeamAction { action },
+    )
+}
+
+pub fn approve_team_action(member: Pubkey, creator: Pubkey, id: u64) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::ApproveTeamAction {
+            member,
+            project_team: project_team_pda(&project).0,
+            team_action: team_action_pda(&project, id).0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ApproveTeamAction {},
+    )
+}
+
+/// Passes the optional accounts `action` needs. `proposer` receives the proposal's rent;
+/// `round_owner` co-signs metadata updates made after Planning.
+pub fn execute_team_action(
+    member: Pubkey,
+    creator: Pubkey,
+    funding_round: Pubkey,
+    id: u64,
+    proposer: Pubkey,
+    action: &TeamAction,
+    round_owner: Option<Pubkey>,
+) -> Instruction {
+    let project = project_pda(&creator).0;
+    let withdraw = matches!(action, TeamAction::Withdraw { .. });
+    let recipient = match action {
+        TeamAction::Withdraw { recipient, .. } => Some(*recipient),
+        _ => None,
+    };
+    let milestone_review = match action {
+        TeamAction::SubmitMilestone { milestone_index } => Some(milestone_review_pda(&project, *milestone_index).0),
+        _ => None,
+    };
+    let submit = milestone_review.is_some();
+    let splits = matches!(action, TeamAction::SetPayoutSplits { .. });
+    let meta = matches!(action, TeamAction::UpdateMeta { .. });
+    build(
+        accounts::ExecuteTeamAction {
+            member,
+            project,
+            project_team: project_team_pda(&project).0,
+            team_action: team_action_pda(&project, id).0,
+            proposer,
+            funding_round: (withdraw || meta || submit).then_some(funding_round),
+            vault: withdraw.then(|| vault_pda(&creator).0),
+            recipient,
+            milestone_review,
+            funding_round_metadata: submit.then(|| funding_round_metadata_pda(&funding_round).0),
+            project_meta: (splits || meta).then(|| project_metadata_pda(&project).0),
+            payout_config: splits.then(|| payout_config_pda(&project).0),
+            round_owner,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ExecuteTeamAction {},
+    )
+}
+
+/// `funding_round` is the project's round, which may already be closed.
+pub fn close_contribution(contributor: Pubkey, creator: Pubkey, funding_round: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::CloseContribution {
+            contributor,
+            contribution: contribution_pda(&project, &contributor).0,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CloseContribution {},
+    )
+}
+
+pub fn close_project(owner: Pubkey, creator: Pubkey, funding_round: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::CloseProject {
+            owner,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            vault: vault_pda(&creator).0,
+            creator,
+            tombstone: project_tombstone_pda(&creator).0,
+            funding_round,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CloseProject {},
+    )
+}
+
+/// `funding_round` is the project's round, which may already be closed.
+pub fn submit_milestone(owner: Pubkey, funding_round: Pubkey, milestone_index: u8) -> Instruction {
+    let project = project_pda(&owner).0;
+    build(
+        accounts::SubmitMilestone {
+            owner,
+            project,
+            milestone_review: milestone_review_pda(&project, milestone_index).0,
+            funding_round,
+            funding_round_metadata: funding_round_metadata_pda(&funding_round).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SubmitMilestone { milestone_index },
+    )
+}
+
+/// `attempt` is the review's current `attempt`; each attempt takes one vote per contributor.
+pub fn vote_milestone(
+    contributor: Pubkey,
+    project_owner: Pubkey,
+    milestone_index: u8,
+    attempt: u8,
+    approve: bool,
+) -> Instruction {
+    let project = project_pda(&project_owner).0;
+    let milestone_review = milestone_review_pda(&project, milestone_index).0;
+    build(
+        accounts::VoteMilestone {
+            contributor,
+            project,
+            milestone_review,
+            contribution: contribution_pda(&project, &contributor).0,
+            milestone_vote: milestone_vote_pda(&milestone_review, attempt, &contributor).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::VoteMilestone { milestone_index, approve },
+    )
+}
+
+/// `admin` must be the program's upgrade authority.
+pub fn initialize_governance(
+    admin: Pubkey,
+    acts_mint: Pubkey,
+    proposal_threshold: u64,
+    voting_period: i64,
+    quorum: u64,
+    approval_threshold_bps: u16,
+) -> Instruction {
+    build(
+        accounts::InitializeGovernance {
+            admin,
+            governance: governance_pda().0,
+            program_data: program_data_address(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::InitializeGovernance {
+            acts_mint,
+            proposal_threshold,
+            voting_period,
+            quorum,
+            approval_threshold_bps,
+        },
+    )
+}
+
+/// `escrow_token_account` is an ACTS account owned by [`governance_escrow_pda`];
+/// `token_program` is the program owning both token accounts.
+pub fn deposit_governance_stake(
+    voter: Pubkey,
+    voter_token_account: Pubkey,
+    escrow_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::DepositGovernanceStake {
+            voter,
+            governance: governance_pda().0,
+            voter_stake: voter_stake_pda(&voter).0,
+            voter_token_account,
+            escrow_token_account,
+            escrow_authority: governance_escrow_pda().0,
+            token_program,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::DepositGovernanceStake { amount },
+    )
+}
+
+pub fn withdraw_governance_stake(
+    voter: Pubkey,
+    voter_token_account: Pubkey,
+    escrow_token_account: Pubkey,
+    token_program: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::WithdrawGovernanceStake {
+            voter,
+            governance: governance_pda().0,
+            voter_stake: voter_stake_pda(&voter).0,
+            voter_token_account,
+            escrow_token_account,
+            escrow_authority: governance_escrow_pda().0,
+            token_program,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::WithdrawGovernanceStake { amount },
+    )
+}
+
+/// `id` is the governance config's current `proposal_count`.
+pub fn create_proposal(
+    proposer: Pubkey,
+    proposer_token_account: Pubkey,
+    id: u64,
+    action: ProposalAction,
+    description_uri: String,
+    voting_mode: VotingMode,
+) -> Instruction {
+    build(
+        accounts::CreateProposal {
+            proposer,
+            governance: governance_pda().0,
+            proposal: proposal_pda(id).0,
+            proposer_token_account,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CreateProposal { action, description_uri, voting_mode },
+    )
+}
+
+pub fn cast_vote(voter: Pubkey, id: u64, approve: bool) -> Instruction {
+    let proposal = proposal_pda(id).0;
+    build(
+        accounts::CastVote {
+            voter,
+            governance: governance_pda().0,
+            proposal,
+            vote_record: vote_pda(&proposal, &voter).0,
+            voter_stake: voter_stake_pda(&voter).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CastVote { approve },
+    )
+}
+
+pub fn configure_quadratic_voting(
+    admin: Pubkey,
+    voice_credits_per_epoch: u64,
+    qv_epoch_duration: i64,
+    qv_quorum: u64,
+    qv_min_stake: u64,
+) -> Instruction {
+    build(
+        accounts::ConfigureQuadraticVoting {
+            admin,
+            governance: governance_pda().0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ConfigureQuadraticVoting {
+            voice_credits_per_epoch,
+            qv_epoch_duration,
+            qv_quorum,
+            qv_min_stake,
+        },
+    )
+}
+
+/// `epoch` is the proposal's `epoch`; credits are drawn from that epoch's budget.
+pub fn cast_quadratic_vote(voter: Pubkey, id: u64, epoch: u64, approve: bool, credits: u64) -> Instruction {
+    let proposal = proposal_pda(id).0;
+    build(
+        accounts::CastQuadraticVote {
+            voter,
+            governance: governance_pda().0,
+            proposal,
+            voice_credits: voice_credits_pda(epoch, &voter).0,
+            vote_record: quadratic_vote_pda(&proposal, &voter).0,
+            voter_stake: voter_stake_pda(&voter).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CastQuadraticVote { approve, credits },
+    )
+}
+
+/// Pass the project's `creator` and round for a `WhitelistProject` proposal.
+pub fn execute_proposal(executor: Pubkey, id: u64, whitelist: Option<(Pubkey, Pubkey)>) -> Instruction {
+    let project = whitelist.map(|(creator, _)| project_pda(&creator).0);
+    build(
+        accounts::ExecuteProposal {
+            executor,
+            governance: governance_pda().0,
+            proposal: proposal_pda(id).0,
+            project,
+            project_meta: project.map(|project| project_metadata_pda(&project).0),
+            funding_round: whitelist.map(|(_, round)| round),
+            protocol_config: protocol_config_pda().0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ExecuteProposal {},
+    )
+}
+
+/// Rewrites a pre-versioning account in the current layout; `data` selects the
+/// account type, e.g. `instruction::MigrateContribution {}`. The payer covers the extra rent.
+pub fn migrate<D: InstructionData>(payer: Pubkey, account: Pubkey, data: D) -> Instruction {
+    build(
+        accounts::MigrateAccount {
+            payer,
+            account,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        data,
+    )
+}
+
+/// Migrates a pre-versioning `Project` account. `funding_round` is the project's round,
+/// which must be migrated first unless it was closed.
+pub fn migrate_project(payer: Pubkey, account: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateProject {
+            payer,
+            account,
+            funding_round,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::MigrateProject {},
+    )
+}
+
+/// Migrates a pre-versioning `ProjectMeta` account.
+pub fn migrate_project_meta(payer: Pubkey, account: Pubkey) -> Instruction {
+    migrate(payer, account, instruction::MigrateProjectMeta {})
+}
+
+/// Migrates a pre-versioning `FundingRound` account.
+pub fn migrate_funding_round(payer: Pubkey, account: Pubkey) -> Instruction {
+    migrate(payer, account, instruction::MigrateFundingRound {})
+}
+
+/// Migrates a pre-versioning `FundingRoundMeta` account.
+pub fn migrate_funding_round_meta(payer: Pubkey, account: Pubkey) -> Instruction {
+    migrate(payer, account, instruction::MigrateFundingRoundMeta {})
+}
+
+/// Migrates a pre-versioning `Contribution` account.
+pub fn migrate_contribution(payer: Pubkey, account: Pubkey) -> Instruction {
+    migrate(payer, account, instruction::MigrateContribution {})
+}
+
+/// Pass `Pubkey::default()` to leave reviewing to the round owner only.
+pub fn set_round_reviewer(owner: Pubkey, funding_round: Pubkey, reviewer: Pubkey) -> Instruction {
+    build(
+        accounts::SetRoundReviewer {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetRoundReviewer { reviewer },
+    )
+}
+
+/// `authority` is the round owner or its reviewer.
+pub fn flag_contribution(
+    authority: Pubkey,
+    funding_round: Pubkey,
+    creator: Pubkey,
+    contributor: Pubkey,
+    reason_code: u8,
+) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::FlagContribution {
+            authority,
+            funding_round,
+            funding_round_metadata: funding_round_metadata_pda(&funding_round).0,
+            project,
+            contribution: contribution_pda(&project, &contributor).0,
+            protocol_config: protocol_config_pda().0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::FlagContribution { reason_code },
+    )
+}
+
+/// `funding_round` is the project's round.
+pub fn claim_refund(contributor: Pubkey, funding_round: Pubkey, creator: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::ClaimRefund {
+            contributor,
+            funding_round,
+            project,
+            vault: vault_pda(&creator).0,
+            contribution: contribution_pda(&project, &contributor).0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ClaimRefund {},
+    )
+}
+
+/// Pass `Pubkey::default()` to leave challenges to the round owner only.
+pub fn set_round_arbitrator(owner: Pubkey, funding_round: Pubkey, arbitrator: Pubkey) -> Instruction {
+    build(
+        accounts::SetRoundArbitrator {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetRoundArbitrator { arbitrator },
+    )
+}
+
+/// `attempt` is the review's current `attempt`; the challenger posts the round's bond.
+pub fn challenge_milestone(
+    challenger: Pubkey,
+    creator: Pubkey,
+    milestone_index: u8,
+    attempt: u8,
+    evidence_uri: String,
+) -> Instruction {
+    let project = project_pda(&creator).0;
+    let milestone_review = milestone_review_pda(&project, milestone_index).0;
+    build(
+        accounts::ChallengeMilestone {
+            challenger,
+            project,
+            milestone_review,
+            contribution: contribution_pda(&project, &challenger).0,
+            challenge: milestone_challenge_pda(&milestone_review, attempt).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ChallengeMilestone { milestone_index, evidence_uri },
+    )
+}
+
+/// `authority` is the round owner or its arbitrator; `challenger` receives the
+/// challenge's rent and, when upheld, its bond back.
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_challenge(
+    authority: Pubkey,
+    funding_round: Pubkey,
+    creator: Pubkey,
+    milestone_index: u8,
+    attempt: u8,
+    challenger: Pubkey,
+    upheld: bool,
+) -> Instruction {
+    let project = project_pda(&creator).0;
+    let milestone_review = milestone_review_pda(&project, milestone_index).0;
+    build(
+        accounts::ResolveChallenge {
+            authority,
+            funding_round,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            milestone_review,
+            challenge: milestone_challenge_pda(&milestone_review, attempt).0,
+            challenger,
+            vault: vault_pda(&creator).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ResolveChallenge { milestone_index, upheld },
+    )
+}
+
+/// Checks the round against the (project, vault) pairs of `creators`.
+pub fn audit_round(funding_round: Pubkey, creators: &[Pubkey]) -> Instruction {
+    let mut ix = build(
+        accounts::AuditRound {
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+        },
+        instruction::AuditRound {},
+    );
+    for creator in creators {
+        ix.accounts.extend([
+            AccountMeta::new_readonly(project_pda(creator).0, false),
+            AccountMeta::new_readonly(vault_pda(creator).0, false),
+        ]);
+    }
+    ix
+}
+
+/// `approvers` are members of the current guardian set and must also sign.
+pub fn configure_guardians(
+    admin: Pubkey,
+    guardians: Vec<Pubkey>,
+    guardian_threshold: u8,
+    approvers: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        accounts::ConfigureGuardians {
+            admin,
+            protocol_config: protocol_config_pda().0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ConfigureGuardians { guardians, guardian_threshold },
+    );
+    ix.accounts.extend(approvers.iter().map(|approver| AccountMeta::new_readonly(*approver, true)));
+    ix
+}
+
+pub fn set_round_vesting(
+    owner: Pubkey,
+    funding_round: Pubkey,
+    vesting_cliff_secs: i64,
+    vesting_duration_secs: i64,
+) -> Instruction {
+    build(
+        accounts::SetRoundVesting {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetRoundVesting { vesting_cliff_secs, vesting_duration_secs },
+    )
+}
+
+pub fn claim_vested(owner: Pubkey, creator: Pubkey, funding_round: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimVested {
+            owner,
+            vault: vault_pda(&creator).0,
+            project: project_pda(&creator).0,
+            funding_round,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ClaimVested {},
+    )
+}
+
+/// Signed by the round owner.
+pub fn cancel_vesting(owner: Pubkey, funding_round: Pubkey, creator: Pubkey) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::CancelVesting {
+            owner,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            project_vault: vault_pda(&creator).0,
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::CancelVesting {},
+    )
+}
+
+/// One due date per milestone, strictly increasing and in the future.
+pub fn set_milestone_deadlines(owner: Pubkey, creator: Pubkey, due_dates: Vec<i64>) -> Instruction {
+    let project = project_pda(&creator).0;
+    build(
+        accounts::SetMilestoneDeadlines {
+            owner,
+            project,
+            project_meta: project_metadata_pda(&project).0,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetMilestoneDeadlines { due_dates },
+    )
+}
+
+pub fn set_round_rollover(owner: Pubkey, funding_round: Pubkey, rollover_round: Pubkey) -> Instruction {
+    build(
+        accounts::SetRoundRollover {
+            owner,
+            funding_round,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::SetRoundRollover { rollover_round },
+    )
+}
+
+/// Permissionless. Pass the round's `rollover_round` when it has one.
+pub fn clawback_expired_milestones(
+    authority: Pubkey,
+    funding_round: Pubkey,
+    creator: Pubkey,
+    rollover_round: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ClawbackExpiredMilestones {
+            authority,
+            funding_round,
+            round_vault: round_vault_pda(&funding_round).0,
+            project: project_pda(&creator).0,
+            project_vault: vault_pda(&creator).0,
+            rollover_round,
+            rollover_vault: rollover_round.map(|round| round_vault_pda(&round).0),
+            protocol_config: protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: anectos::ID,
+        },
+        instruction::ClawbackExpiredMilestones {},
+    )
+}
@@ -0,0 +1,15 @@
+//! Rust client for the Anectos program.
+//!
+//! Instruction builders and account types come from the program crate itself
+//! (`anectos::accounts` / `anectos::instruction`), so a change to an instruction's
+//! accounts or arguments breaks this crate at compile time instead of on-chain.
+
+pub mod accounts;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+
+pub use anectos as program;
+pub use anectos::ID as PROGRAM_ID;
+pub use error::ClientError;
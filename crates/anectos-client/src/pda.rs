@@ -0,0 +1,131 @@
+//! Program-derived addresses, one function per seed in `anectos::constants`.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::pubkey;
+use anectos::constants::*;
+
+const BPF_LOADER_UPGRADEABLE: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &anectos::ID)
+}
+
+/// seeds = [b"project", creator]; the creator is the project's first owner
+pub fn project_pda(creator: &Pubkey) -> (Pubkey, u8) {
+    find(&[PROJECT_SEED, creator.as_ref()])
+}
+
+/// seeds = [b"vault", creator]
+pub fn vault_pda(creator: &Pubkey) -> (Pubkey, u8) {
+    find(&[VAULT_SEED, creator.as_ref()])
+}
+
+/// seeds = [b"round_vault", funding_round]
+pub fn round_vault_pda(funding_round: &Pubkey) -> (Pubkey, u8) {
+    find(&[ROUND_VAULT_SEED, funding_round.as_ref()])
+}
+
+/// seeds = [b"contrib", project, contributor]
+pub fn contribution_pda(project: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+    find(&[CONTRIBUTION_SEED, project.as_ref(), contributor.as_ref()])
+}
+
+/// seeds = [b"project_metadata", project]
+pub fn project_metadata_pda(project: &Pubkey) -> (Pubkey, u8) {
+    find(&[PROJECT_METADATA_SEED, project.as_ref()])
+}
+
+/// seeds = [b"funding_round_metadata", funding_round]
+pub fn funding_round_metadata_pda(funding_round: &Pubkey) -> (Pubkey, u8) {
+    find(&[FUNDING_ROUND_METADATA_SEED, funding_round.as_ref()])
+}
+
+/// seeds = [b"governance"]
+pub fn governance_pda() -> (Pubkey, u8) {
+    find(&[GOVERNANCE_SEED])
+}
+
+/// seeds = [b"proposal", id (u64 LE)]
+pub fn proposal_pda(id: u64) -> (Pubkey, u8) {
+    find(&[PROPOSAL_SEED, &id.to_le_bytes()])
+}
+
+/// seeds = [b"vote", proposal, voter]
+pub fn vote_pda(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    find(&[VOTE_SEED, proposal.as_ref(), voter.as_ref()])
+}
+
+/// seeds = [b"qv_vote", proposal, voter]
+pub fn quadratic_vote_pda(proposal: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    find(&[QUADRATIC_VOTE_SEED, proposal.as_ref(), voter.as_ref()])
+}
+
+/// seeds = [b"voice_credits", epoch (u64 LE), voter]
+pub fn voice_credits_pda(epoch: u64, voter: &Pubkey) -> (Pubkey, u8) {
+    find(&[VOICE_CREDITS_SEED, &epoch.to_le_bytes(), voter.as_ref()])
+}
+
+/// seeds = [b"voter_stake", voter]
+pub fn voter_stake_pda(voter: &Pubkey) -> (Pubkey, u8) {
+    find(&[VOTER_STAKE_SEED, voter.as_ref()])
+}
+
+/// Owner of the token account holding staked ACTS: seeds = [b"governance_escrow"]
+pub fn governance_escrow_pda() -> (Pubkey, u8) {
+    find(&[GOVERNANCE_ESCROW_SEED])
+}
+
+/// The program's upgradeable-loader data account, which records its upgrade authority
+pub fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(&[anectos::ID.as_ref()], &BPF_LOADER_UPGRADEABLE).0
+}
+
+/// seeds = [b"milestone_review", project, milestone_index]
+pub fn milestone_review_pda(project: &Pubkey, milestone_index: u8) -> (Pubkey, u8) {
+    find(&[MILESTONE_REVIEW_SEED, project.as_ref(), &[milestone_index]])
+}
+
+/// seeds = [b"milestone_vote", milestone_review, attempt, contributor]
+pub fn milestone_vote_pda(milestone_review: &Pubkey, attempt: u8, contributor: &Pubkey) -> (Pubkey, u8) {
+    find(&[MILESTONE_VOTE_SEED, milestone_review.as_ref(), &[attempt], contributor.as_ref()])
+}
+
+/// seeds = [b"challenge", milestone_review, attempt]
+pub fn milestone_challenge_pda(milestone_review: &Pubkey, attempt: u8) -> (Pubkey, u8) {
+    find(&[MILESTONE_CHALLENGE_SEED, milestone_review.as_ref(), &[attempt]])
+}
+
+/// seeds = [b"owner_transfer", project]
+pub fn owner_transfer_pda(project: &Pubkey) -> (Pubkey, u8) {
+    find(&[OWNER_TRANSFER_SEED, project.as_ref()])
+}
+
+/// seeds = [b"payout_config", project]
+pub fn payout_config_pda(project: &Pubkey) -> (Pubkey, u8) {
+    find(&[PAYOUT_CONFIG_SEED, project.as_ref()])
+}
+
+/// seeds = [b"project_team", project]
+pub fn project_team_pda(project: &Pubkey) -> (Pubkey, u8) {
+    find(&[PROJECT_TEAM_SEED, project.as_ref()])
+}
+
+/// seeds = [b"team_action", project, id]
+pub fn team_action_pda(project: &Pubkey, id: u64) -> (Pubkey, u8) {
+    find(&[TEAM_ACTION_SEED, project.as_ref(), &id.to_le_bytes()])
+}
+
+/// seeds = [b"project_tombstone", creator]; exists once the creator's project was closed
+pub fn project_tombstone_pda(creator: &Pubkey) -> (Pubkey, u8) {
+    find(&[PROJECT_TOMBSTONE_SEED, creator.as_ref()])
+}
+
+/// seeds = [b"protocol_config"]
+pub fn protocol_config_pda() -> (Pubkey, u8) {
+    find(&[PROTOCOL_CONFIG_SEED])
+}
+
+/// Signer of the program's self-CPI event instructions: seeds = [b"__event_authority"]
+pub fn event_authority_pda() -> (Pubkey, u8) {
+    find(&[b"__event_authority"])
+}
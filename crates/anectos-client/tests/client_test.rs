@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{system_program, AccountSerialize, AnchorDeserialize, Discriminator, Event, InstructionData, ToAccountMetas};
+use anectos::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION};
+use anectos::state::{Contribution, ContributionMade};
+use anectos_client::{accounts, events, instructions, pda};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Names of the `pub fn`s in `source` after the first occurrence of `start`.
+fn pub_fns<'a>(source: &'a str, start: &str) -> Vec<&'a str> {
+    let body = &source[source.find(start).expect("start marker")..];
+    body.split("pub fn ")
+        .skip(1)
+        .map(|rest| rest.split(|c: char| !(c.is_alphanumeric() || c == '_')).next().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod instruction_tests {
+    use super::*;
+
+    #[test]
+    fn test_contribute_accounts_and_data() {
+        let user = Pubkey::new_unique();
+        let round = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let ix = instructions::contribute(user, round, owner, 5_000);
+
+        assert_eq!(ix.program_id, anectos::ID);
+        let project = pda::project_pda(&owner).0;
+        let contribution = pda::contribution_pda(&project, &user).0;
+        let user_meta = ix.accounts.iter().find(|m| m.pubkey == user).unwrap();
+        assert!(user_meta.is_signer && user_meta.is_writable);
+        assert!(ix.accounts.iter().any(|m| m.pubkey == contribution && m.is_writable));
+        assert_eq!(ix.accounts.last().unwrap().pubkey, anectos::ID);
+
+        let data = ix.data.strip_prefix(anectos::instruction::Contribute::DISCRIMINATOR).unwrap();
+        let args = anectos::instruction::Contribute::try_from_slice(data).unwrap();
+        assert_eq!(args.amount, 5_000);
+    }
+
+    #[test]
+    fn test_settlement_uses_round_vault_pda() {
+        let owner = Pubkey::new_unique();
+        let round = Pubkey::new_unique();
+        let ix = instructions::settle_matching_for_project(owner, round);
+        let round_vault = Pubkey::find_program_address(&[b"round_vault", round.as_ref()], &anectos::ID).0;
+        assert_eq!(ix.accounts[2].pubkey, round_vault);
+    }
+
+    #[test]
+    fn test_every_program_instruction_has_a_builder() {
+        let program = pub_fns(include_str!("../../../programs/anectos/src/lib.rs"), "pub mod anectos");
+        let builders = pub_fns(include_str!("../src/instructions.rs"), "");
+        assert!(program.len() > 50, "parsed only {} program instructions", program.len());
+        let missing: Vec<_> = program.iter().filter(|name| !builders.contains(name)).collect();
+        assert!(missing.is_empty(), "instructions without a builder: {missing:?}");
+    }
+
+    #[test]
+    fn test_close_project_matches_program_accounts_after_ownership_transfer() {
+        let (owner, creator, round) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ix = instructions::close_project(owner, creator, round);
+
+        let project = Pubkey::find_program_address(&[b"project", creator.as_ref()], &anectos::ID).0;
+        let expected = anectos::accounts::CloseProject {
+            owner,
+            project,
+            project_meta: Pubkey::find_program_address(&[b"project_metadata", project.as_ref()], &anectos::ID).0,
+            vault: Pubkey::find_program_address(&[b"vault", creator.as_ref()], &anectos::ID).0,
+            creator,
+            tombstone: Pubkey::find_program_address(&[b"project_tombstone", creator.as_ref()], &anectos::ID).0,
+            funding_round: round,
+            protocol_config: Pubkey::find_program_address(&[b"protocol_config"], &anectos::ID).0,
+            system_program: system_program::ID,
+            event_authority: Pubkey::find_program_address(&[b"__event_authority"], &anectos::ID).0,
+            program: anectos::ID,
+        };
+        assert_eq!(ix.accounts, expected.to_account_metas(None));
+        assert_eq!(ix.data, anectos::instruction::CloseProject {}.data());
+    }
+}
+
+#[cfg(test)]
+mod account_tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_contribution_from_snapshot() {
+        let project = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let contribution = Contribution {
+            version: ACCOUNT_VERSION,
+            project,
+            contributor,
+            total_contributed: 42,
+            is_excluded: false,
+            refunded: false,
+            bump: 255,
+            reserved: [0; 32],
+        };
+        let mut data = Vec::new();
+        contribution.try_serialize(&mut data).unwrap();
+
+        let mut snapshot = HashMap::new();
+        assert!(accounts::fetch_contribution(&snapshot, &project, &contributor).unwrap().is_none());
+
+        snapshot.insert(pda::contribution_pda(&project, &contributor).0, data);
+        let fetched = accounts::fetch_contribution(&snapshot, &project, &contributor).unwrap().unwrap();
+        assert_eq!(fetched.total_contributed, 42);
+        assert!(accounts::fetch_project(&snapshot, &contributor).is_err());
+    }
+}
+
+#[cfg(test)]
+mod event_tests {
+    use super::*;
+
+    fn contribution_made() -> ContributionMade {
+        ContributionMade {
+            schema_version: EVENT_SCHEMA_VERSION,
+            project: Pubkey::new_unique(),
+            contributor: Pubkey::new_unique(),
+            amount: 1_000,
+            new_total_funding: 3_000,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_decode_cpi_event() {
+        let event = contribution_made();
+        let ix_data = [EVENT_IX_TAG_LE, &event.data()].concat();
+        let other_program = Pubkey::new_unique();
+        let parsed = events::parse_inner_instructions([
+            (&other_program, ix_data.as_slice()),
+            (&anectos::ID, ix_data.as_slice()),
+        ]);
+
+        assert_eq!(parsed.len(), 1);
+        match &parsed[0] {
+            events::AnectosEvent::ContributionMade(e) => {
+                assert_eq!(e.schema_version, EVENT_SCHEMA_VERSION);
+                assert_eq!(e.amount, 1_000);
+                assert_eq!(e.project, event.project);
+            }
+            other => panic!("unexpected event {}", other.name()),
+        }
+    }
+
+    #[test]
+    fn test_parse_program_data_logs() {
+        let logs = vec![
+            "Program log: Instruction: Contribute".to_string(),
+            format!("Program data: {}", STANDARD.encode(contribution_made().data())),
+            "Program data: bm90IGFuIGV2ZW50".to_string(),
+        ];
+        let parsed = events::parse_logs(&logs);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].name(), "ContributionMade");
+    }
+}
@@ -0,0 +1,287 @@
+//! Off-chain replay of Anectos quadratic funding.
+//!
+//! Contributions are replayed through the program's own `math::area_delta` and
+//! settlements through `math::matching_allocation`, so results match the chain
+//! to the lamport.
+//!
+//! Input is a CSV with a `contributor,project,amount` header. In verify mode the
+//! `project` column must hold project account addresses, and contributions the
+//! reviewer excluded with `flag_contribution` must be left out.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::math::{area_delta, matching_allocation};
+use anectos::state::{FundingRound, Project};
+use anectos_client::accounts::{decode, fetch_funding_round};
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// u128 areas are rendered as strings so JSON consumers don't lose precision
+fn as_string<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContributionRow {
+    pub contributor: String,
+    pub project: String,
+    pub amount: u64,
+}
+
+pub fn read_contributions<R: Read>(reader: R) -> Result<Vec<ContributionRow>> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(reader)
+        .deserialize()
+        .enumerate()
+        .map(|(i, row)| row.with_context(|| format!("contribution row {}", i + 1)))
+        .collect()
+}
+
+/// Round state accumulated the way `contribute` does it
+#[derive(Default)]
+pub struct Replay {
+    pub round_area: u128,
+    pub project_areas: BTreeMap<String, u128>,
+    pub donations: BTreeMap<String, u64>,
+    pub contributors: BTreeMap<String, u32>,
+    totals: HashMap<(String, String), u64>,
+}
+
+impl Replay {
+    pub fn contribute(&mut self, row: &ContributionRow) -> Result<()> {
+        anyhow::ensure!(row.amount > 0, "zero contribution from {} to {}", row.contributor, row.project);
+        let total = self
+            .totals
+            .entry((row.project.clone(), row.contributor.clone()))
+            .or_default();
+        if *total == 0 {
+            *self.contributors.entry(row.project.clone()).or_default() += 1;
+        }
+        let prev = *total as u128;
+        let new_total = prev + row.amount as u128;
+        *total = u64::try_from(new_total).context("contributor total overflows u64")?;
+
+        let delta = area_delta(prev, new_total);
+        *self.project_areas.entry(row.project.clone()).or_default() += delta;
+        self.round_area += delta;
+        *self.donations.entry(row.project.clone()).or_default() += row.amount;
+        Ok(())
+    }
+
+    pub fn from_rows(rows: &[ContributionRow]) -> Result<Self> {
+        let mut replay = Replay::default();
+        for row in rows {
+            replay.contribute(row)?;
+        }
+        Ok(replay)
+    }
+}
+
+/// Matching budgets per project (`fund_project_pool`), with a default for unlisted projects
+pub struct Budgets {
+    pub default: u64,
+    pub per_project: HashMap<String, u64>,
+}
+
+impl Budgets {
+    pub fn get(&self, project: &str) -> u64 {
+        self.per_project.get(project).copied().unwrap_or(self.default)
+    }
+}
+
+/// Reads a `project,budget` CSV.
+pub fn read_budgets<R: Read>(reader: R, default: u64) -> Result<Budgets> {
+    #[derive(Deserialize)]
+    struct Row {
+        project: String,
+        budget: u64,
+    }
+    let mut per_project = HashMap::new();
+    for row in csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader).deserialize() {
+        let row: Row = row.context("budget row")?;
+        per_project.insert(row.project, row.budget);
+    }
+    Ok(Budgets { default, per_project })
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectOutcome {
+    pub project: String,
+    pub contributors: u32,
+    pub donations: u64,
+    #[serde(serialize_with = "as_string")]
+    pub area: u128,
+    pub budget: u64,
+    pub matched: u64,
+    pub unallocated: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SimulationReport {
+    #[serde(serialize_with = "as_string")]
+    pub round_area: u128,
+    #[serde(serialize_with = "as_string")]
+    pub area_max: u128,
+    pub total_donations: u64,
+    pub total_budget: u64,
+    pub total_matched: u64,
+    pub unallocated: u64,
+    /// total_matched / total_budget in basis points
+    pub utilisation_bps: u64,
+    /// Lamports lost to flooring each project's allocation
+    pub rounding_dust: u64,
+    pub projects: Vec<ProjectOutcome>,
+}
+
+/// Denominator used by `matching_allocation`, squared
+fn denominator(round_area: u128, area_max: u128) -> u128 {
+    let denom_area = round_area.max(area_max);
+    denom_area.saturating_mul(denom_area)
+}
+
+pub fn simulate(rows: &[ContributionRow], budgets: &Budgets, area_max: u128) -> Result<SimulationReport> {
+    let replay = Replay::from_rows(rows)?;
+    let den = denominator(replay.round_area, area_max);
+
+    let mut projects = Vec::new();
+    let mut dust_numerator: u128 = 0;
+    for (project, &area) in &replay.project_areas {
+        let budget = budgets.get(project);
+        let matched = matching_allocation(budget, area, replay.round_area, area_max)
+            .map_err(|e| anyhow!("allocation for {project}: {e}"))?;
+        if matched > 0 {
+            // remainder of budget * area^2 / den, which the program floors away
+            let exact = (budget as u128) * area * area;
+            dust_numerator += exact - matched * den;
+        }
+        let matched = u64::try_from(matched)?;
+        projects.push(ProjectOutcome {
+            project: project.clone(),
+            contributors: replay.contributors[project],
+            donations: replay.donations[project],
+            area,
+            budget,
+            matched,
+            unallocated: budget.saturating_sub(matched),
+        });
+    }
+
+    let total_budget: u64 = projects.iter().map(|p| p.budget).sum();
+    let total_matched: u64 = projects.iter().map(|p| p.matched).sum();
+    Ok(SimulationReport {
+        round_area: replay.round_area,
+        area_max,
+        total_donations: projects.iter().map(|p| p.donations).sum(),
+        total_budget,
+        total_matched,
+        unallocated: total_budget - total_matched,
+        utilisation_bps: (total_matched as u128 * 10_000)
+            .checked_div(total_budget as u128)
+            .unwrap_or(0) as u64,
+        rounding_dust: dust_numerator.checked_div(den).unwrap_or(0) as u64,
+        projects,
+    })
+}
+
+/// Raw account data keyed by address: `{ "accounts": { "<address>": "<base64>" } }`
+pub fn read_snapshot<R: Read>(reader: R) -> Result<HashMap<Pubkey, Vec<u8>>> {
+    #[derive(Deserialize)]
+    struct SnapshotFile {
+        accounts: HashMap<String, String>,
+    }
+    let file: SnapshotFile = serde_json::from_reader(reader).context("parsing snapshot")?;
+    file.accounts
+        .into_iter()
+        .map(|(address, data)| {
+            let pubkey = Pubkey::from_str(&address).map_err(|e| anyhow!("snapshot address {address}: {e}"))?;
+            Ok((pubkey, STANDARD.decode(data).with_context(|| format!("snapshot data for {address}"))?))
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectCheck {
+    pub project: String,
+    #[serde(serialize_with = "as_string")]
+    pub expected_area: u128,
+    #[serde(serialize_with = "as_string")]
+    pub onchain_area: u128,
+    pub expected_matched: u64,
+    pub onchain_matched: u64,
+    pub ok: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VerifyReport {
+    pub round: String,
+    #[serde(serialize_with = "as_string")]
+    pub expected_round_area: u128,
+    #[serde(serialize_with = "as_string")]
+    pub onchain_round_area: u128,
+    pub projects: Vec<ProjectCheck>,
+    /// CSV projects with no matching project account in the snapshot
+    pub unknown_projects: Vec<String>,
+    pub mismatches: usize,
+}
+
+/// Replays `rows` and checks areas and settled matching against a snapshot of the
+/// round and its project accounts.
+pub fn verify(rows: &[ContributionRow], snapshot: &HashMap<Pubkey, Vec<u8>>, round: &Pubkey) -> Result<VerifyReport> {
+    let funding_round: FundingRound = fetch_funding_round(snapshot, round)?;
+    let replay = Replay::from_rows(rows)?;
+
+    let mut onchain: BTreeMap<String, Project> = BTreeMap::new();
+    for (address, data) in snapshot {
+        if let Ok(project) = decode::<Project>(address, data) {
+            if project.round == *round {
+                onchain.insert(address.to_string(), project);
+            }
+        }
+    }
+
+    let mut projects = Vec::new();
+    for (address, project) in &onchain {
+        let expected_area = replay.project_areas.get(address).copied().unwrap_or(0);
+        let expected_matched = matching_allocation(
+            project.matching_pool,
+            expected_area,
+            replay.round_area,
+            funding_round.area_max,
+        )
+        .map_err(|e| anyhow!("allocation for {address}: {e}"))?;
+        let expected_matched = u64::try_from(expected_matched)?;
+        let onchain_matched = project.ledger.matching_allocated;
+        projects.push(ProjectCheck {
+            project: address.clone(),
+            expected_area,
+            onchain_area: project.area,
+            expected_matched,
+            onchain_matched,
+            ok: expected_area == project.area && expected_matched == onchain_matched,
+        });
+    }
+
+    let unknown_projects: Vec<String> = replay
+        .project_areas
+        .keys()
+        .filter(|p| !onchain.contains_key(*p))
+        .cloned()
+        .collect();
+    let mismatches = projects.iter().filter(|p| !p.ok).count()
+        + unknown_projects.len()
+        + usize::from(replay.round_area != funding_round.area);
+
+    Ok(VerifyReport {
+        round: round.to_string(),
+        expected_round_area: replay.round_area,
+        onchain_round_area: funding_round.area,
+        projects,
+        unknown_projects,
+        mismatches,
+    })
+}
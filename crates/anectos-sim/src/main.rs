@@ -0,0 +1,122 @@
+//! `anectos-sim`: model a round before it runs, or verify its settlement after.
+
+use std::fs::File;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::prelude::Pubkey;
+use anectos_sim::{read_budgets, read_contributions, read_snapshot, simulate, verify, Budgets};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "anectos-sim", version, about = "Quadratic funding simulator and settlement verifier")]
+struct Cli {
+    /// Print JSON instead of a table
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compute per-project matching for a set of contributions
+    Simulate {
+        /// CSV with `contributor,project,amount`
+        contributions: PathBuf,
+        /// Matching budget of each project
+        #[arg(long)]
+        pool: u64,
+        /// CSV with `project,budget` overriding --pool for listed projects
+        #[arg(long)]
+        budgets: Option<PathBuf>,
+        /// Round `area_max`, as set by `set_area_max`
+        #[arg(long, default_value_t = 0)]
+        area_max: u128,
+    },
+    /// Check on-chain areas and settled matching against the contributions
+    Verify {
+        /// CSV with `contributor,project,amount`; projects are account addresses
+        contributions: PathBuf,
+        /// Account snapshot, e.g. from `anectos-cli round snapshot`
+        #[arg(long)]
+        snapshot: PathBuf,
+        #[arg(long)]
+        round: Pubkey,
+    },
+}
+
+fn open(path: &PathBuf) -> Result<File> {
+    File::open(path).with_context(|| format!("opening {}", path.display()))
+}
+
+fn run(cli: Cli) -> Result<bool> {
+    match cli.command {
+        Command::Simulate { contributions, pool, budgets, area_max } => {
+            let rows = read_contributions(open(&contributions)?)?;
+            let budgets = match budgets {
+                Some(path) => read_budgets(open(&path)?, pool)?,
+                None => Budgets { default: pool, per_project: Default::default() },
+            };
+            let report = simulate(&rows, &budgets, area_max)?;
+            if cli.json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("{:<44} {:>6} {:>14} {:>14} {:>14}", "project", "donors", "donations", "budget", "matched");
+                for p in &report.projects {
+                    println!(
+                        "{:<44} {:>6} {:>14} {:>14} {:>14}",
+                        p.project, p.contributors, p.donations, p.budget, p.matched
+                    );
+                }
+                println!();
+                println!("round area:    {}", report.round_area);
+                println!("matched:       {} of {}", report.total_matched, report.total_budget);
+                println!("utilisation:   {}.{:02}%", report.utilisation_bps / 100, report.utilisation_bps % 100);
+                println!("rounding dust: {}", report.rounding_dust);
+            }
+            Ok(true)
+        }
+        Command::Verify { contributions, snapshot, round } => {
+            let rows = read_contributions(open(&contributions)?)?;
+            let snapshot = read_snapshot(open(&snapshot)?)?;
+            let report = verify(&rows, &snapshot, &round)?;
+            if cli.json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!(
+                    "round area: expected {} on-chain {}",
+                    report.expected_round_area, report.onchain_round_area
+                );
+                for p in &report.projects {
+                    println!(
+                        "{} {} area {}/{} matched {}/{}",
+                        if p.ok { "ok      " } else { "MISMATCH" },
+                        p.project,
+                        p.expected_area,
+                        p.onchain_area,
+                        p.expected_matched,
+                        p.onchain_matched
+                    );
+                }
+                for project in &report.unknown_projects {
+                    println!("MISSING  {project} is not in the snapshot");
+                }
+                println!("{} mismatch(es)", report.mismatches);
+            }
+            Ok(report.mismatches == 0)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use anectos::constants::ACCOUNT_VERSION;
+use anectos::state::{FundingRound, Ledger, Project};
+use anectos_sim::{read_contributions, simulate, verify, Budgets};
+
+fn budgets(default: u64) -> Budgets {
+    Budgets { default, per_project: HashMap::new() }
+}
+
+#[cfg(test)]
+mod simulate_tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_matches_integer_settlement() {
+        // A: x gives 50 twice (one contributor, sqrt(100) = 10) and y gives 100 -> area 20
+        // B: z gives 400 -> area 20
+        let csv = "contributor,project,amount\nx,A,50\nx,A,50\ny,A,100\nz,B,400\n";
+        let rows = read_contributions(csv.as_bytes()).unwrap();
+        let report = simulate(&rows, &budgets(1_000), 0).unwrap();
+
+        assert_eq!(report.round_area, 40);
+        assert_eq!(report.projects[0].contributors, 2);
+        assert_eq!(report.projects[0].matched, 250); // 1000 * 20^2 / 40^2
+        assert_eq!(report.projects[1].matched, 250);
+        assert_eq!(report.total_matched, 500);
+        assert_eq!(report.utilisation_bps, 2_500);
+        assert_eq!(report.rounding_dust, 0);
+    }
+
+    #[test]
+    fn test_rounding_dust() {
+        // areas 3 and 4: 100*9/49 = 18 r18, 100*16/49 = 32 r32 -> 50/49 lamports lost
+        let csv = "contributor,project,amount\nx,A,9\ny,B,16\n";
+        let rows = read_contributions(csv.as_bytes()).unwrap();
+        let report = simulate(&rows, &budgets(100), 0).unwrap();
+        assert_eq!(report.total_matched, 50);
+        assert_eq!(report.rounding_dust, 1);
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use super::*;
+
+    fn round(area: u128) -> FundingRound {
+        FundingRound {
+            version: ACCOUNT_VERSION,
+            owner: Pubkey::new_unique(),
+            matching_pool: 0,
+            total_donations: 0,
+            area,
+            area_max: 0,
+            contributor_count: 0,
+            project_vault: Pubkey::default(),
+            vault_bump: 0,
+            reserved: [0; 64],
+            is_active: true,
+            fee: 0,
+            pool_distributed: 0,
+            ledger: Ledger::default(),
+            reviewer: Pubkey::default(),
+            arbitrator: Pubkey::default(),
+            settlement_started: true,
+            paused_groups: 0,
+            rollover_round: Pubkey::default(),
+            vesting_cliff_secs: 0,
+            vesting_duration_secs: 0,
+            bump: 0,
+        }
+    }
+
+    fn project(round: Pubkey, area: u128, matching_pool: u64, matching_allocated: u64) -> Project {
+        Project {
+            version: ACCOUNT_VERSION,
+            project_id: Pubkey::default(),
+            round,
+            owner: Pubkey::new_unique(),
+            target_amount: 0,
+            area,
+            current_funding: 0,
+            matching_unlocked: 0,
+            matching_pool,
+            pool_distributed: 0,
+            is_whitelisted: true,
+            has_withdrawn: false,
+            milestone_count: 0,
+            milestones: vec![],
+            ledger: Ledger { matching_allocated, ..Ledger::default() },
+            refunds_enabled: false,
+            refund_pool: 0,
+            disputed_amount: 0,
+            vesting_start: 0,
+            vesting_cliff: 0,
+            vesting_end: 0,
+            bump: 0,
+            creator: Pubkey::default(),
+            has_payout_splits: false,
+            has_team: false,
+            bonds_forfeited: 0,
+            reserved: [0; 22],
+        }
+    }
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_verify_flags_settlement_mismatch() {
+        let round_key = Pubkey::new_unique();
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut snapshot = HashMap::new();
+        snapshot.insert(round_key, serialize(&round(40)));
+        snapshot.insert(a, serialize(&project(round_key, 20, 1_000, 250)));
+        snapshot.insert(b, serialize(&project(round_key, 20, 1_000, 251)));
+
+        let csv = format!("contributor,project,amount\nx,{a},100\ny,{a},100\nz,{b},400\n");
+        let rows = read_contributions(csv.as_bytes()).unwrap();
+        let report = verify(&rows, &snapshot, &round_key).unwrap();
+
+        assert_eq!(report.expected_round_area, 40);
+        assert_eq!(report.mismatches, 1);
+        let bad = report.projects.iter().find(|p| !p.ok).unwrap();
+        assert_eq!(bad.project, b.to_string());
+        assert_eq!((bad.expected_matched, bad.onchain_matched), (250, 251));
+    }
+}
@@ -5,19 +5,111 @@ pub const PROJECT_SEED: &[u8] = b"project";
 #[constant]
 pub const VAULT_SEED: &[u8] = b"vault";
 #[constant]
+pub const ROUND_VAULT_SEED: &[u8] = b"round_vault";
+#[constant]
+pub const CONTRIBUTION_SEED: &[u8] = b"contrib";
+#[constant]
 pub const FUNDING_ROUND_SEED: &[u8] = b"funding_round";
 #[constant]
 pub const PROJECT_METADATA_SEED: &[u8] = b"project_metadata";
 #[constant]
 pub const FUNDING_ROUND_METADATA_SEED: &[u8] = b"funding_round_metadata";
+#[constant]
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+#[constant]
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+#[constant]
+pub const VOTE_SEED: &[u8] = b"vote";
+#[constant]
+pub const QUADRATIC_VOTE_SEED: &[u8] = b"qv_vote";
+#[constant]
+pub const VOICE_CREDITS_SEED: &[u8] = b"voice_credits";
+#[constant]
+pub const VOTER_STAKE_SEED: &[u8] = b"voter_stake";
+/// Owner of the token account holding staked ACTS
+#[constant]
+pub const GOVERNANCE_ESCROW_SEED: &[u8] = b"governance_escrow";
+#[constant]
+pub const MILESTONE_REVIEW_SEED: &[u8] = b"milestone_review";
+#[constant]
+pub const MILESTONE_VOTE_SEED: &[u8] = b"milestone_vote";
+#[constant]
+pub const MILESTONE_CHALLENGE_SEED: &[u8] = b"challenge";
+#[constant]
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
+#[constant]
+pub const OWNER_TRANSFER_SEED: &[u8] = b"owner_transfer";
+#[constant]
+pub const PAYOUT_CONFIG_SEED: &[u8] = b"payout_config";
+#[constant]
+pub const PROJECT_TEAM_SEED: &[u8] = b"project_team";
+#[constant]
+pub const TEAM_ACTION_SEED: &[u8] = b"team_action";
+#[constant]
+pub const PROJECT_TOMBSTONE_SEED: &[u8] = b"project_tombstone";
 
 #[constant]
 pub const MAX_MILESTONES: u8 = 20;
+// Metadata limits in bytes; these size `ProjectMeta` and `FundingRoundMeta`
+#[constant]
+pub const MAX_TITLE_LEN: u16 = 64;
+#[constant]
+pub const MAX_DESCRIPTION_LEN: u16 = 512;
+#[constant]
+pub const MAX_URI_LEN: u16 = 200;
+/// Accepted metadata URI prefixes; an empty URI means none was provided
+pub const ALLOWED_URI_SCHEMES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+#[constant]
+pub const MIN_CONTRIBUTION_AMOUNT: u64 = 1000;
+/// Contributions can no longer be withdrawn this many seconds before the round ends
+#[constant]
+pub const CONTRIBUTION_WITHDRAWAL_CUTOFF: i64 = 24 * 60 * 60;
+#[constant]
+pub const BPS_DENOMINATOR: u16 = 10_000;
+#[constant]
+pub const MILESTONE_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+#[constant]
+pub const MILESTONE_APPROVAL_THRESHOLD_BPS: u16 = 5_000;
+#[constant]
+pub const MAX_MILESTONE_FAILURES: u8 = 3;
+#[constant]
+pub const MILESTONE_CHALLENGE_PERIOD: i64 = 3 * 24 * 60 * 60;
+#[constant]
+pub const MILESTONE_CHALLENGE_BOND: u64 = 100_000_000;
+#[constant]
+pub const CHALLENGE_REWARD_BPS: u16 = 500;
+#[constant]
+pub const MAX_GUARDIANS: u8 = 5;
+#[constant]
+pub const MAX_PAYOUT_RECIPIENTS: u8 = 8;
+#[constant]
+pub const MAX_TEAM_MEMBERS: u8 = 10;
+/// Projects per `contribute_batch`; each takes three accounts
+#[constant]
+pub const MAX_BATCH_CONTRIBUTIONS: u8 = 20;
+
+// Pause groups (bit flags) for `ProtocolConfig.paused_groups` and `FundingRound.paused_groups`.
+// Refund claims are never paused.
+#[constant]
+pub const PAUSE_CONTRIBUTIONS: u8 = 1 << 0;
+#[constant]
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 1;
+#[constant]
+pub const PAUSE_SETTLEMENT: u8 = 1 << 2;
+#[constant]
+pub const PAUSE_GOVERNANCE: u8 = 1 << 3;
+#[constant]
+pub const PAUSE_MILESTONES: u8 = 1 << 4;
 #[constant]
-pub const MAX_TITLE_LEN: u8 = 50;
+pub const PAUSE_ALL: u8 = PAUSE_CONTRIBUTIONS
+    | PAUSE_WITHDRAWALS
+    | PAUSE_SETTLEMENT
+    | PAUSE_GOVERNANCE
+    | PAUSE_MILESTONES;
 #[constant]
-pub const MAX_DESCRIPTION_LEN: u8 = 200;
+pub const MILESTONE_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
 #[constant]
-pub const MAX_URI_LEN: u8 = 200;
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+/// Layout version written to every account; accounts created before versioning read as 0
 #[constant]
-pub const MIN_CONTRIBUTION_AMOUNT: u64 = 1000;
\ No newline at end of file
+pub const ACCOUNT_VERSION: u8 = 1;
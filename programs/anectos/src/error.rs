@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::math::MathError;
+
 #[error_code]
 pub enum AnectosError {
     #[msg("The funding round is not active.")]
@@ -26,4 +28,173 @@ pub enum AnectosError {
     FundingRoundAlreadyClosed,
     #[msg("The funding stage status is invalid.")]
     InvalidFundingStageStatus,
+    #[msg("The review window for this round is not open.")]
+    ReviewWindowNotOpen,
+    #[msg("Matching settlement has already started for this round.")]
+    SettlementAlreadyStarted,
+    #[msg("The contribution has already been excluded from matching.")]
+    ContributionAlreadyExcluded,
+    #[msg("Invalid governance configuration.")]
+    InvalidGovernanceConfig,
+    #[msg("The token account is not a valid ACTS account for this wallet.")]
+    InvalidGovernanceTokenAccount,
+    #[msg("Not enough ACTS to perform this governance action.")]
+    InsufficientGovernanceTokens,
+    #[msg("The voting period for this proposal has ended.")]
+    VotingClosed,
+    #[msg("The voting period for this proposal is still open.")]
+    VotingStillOpen,
+    #[msg("The proposal has already been finalized.")]
+    ProposalAlreadyFinalized,
+    #[msg("The accounts supplied do not match the proposal action.")]
+    ProposalAccountsMismatch,
+    #[msg("Quadratic voting is not configured.")]
+    QuadraticVotingDisabled,
+    #[msg("This vote type does not match the proposal's voting mode.")]
+    WrongVotingMode,
+    #[msg("Not enough voice credits left in this epoch.")]
+    InsufficientVoiceCredits,
+    #[msg("Additional votes must be cast on the same side.")]
+    VoteDirectionMismatch,
+    #[msg("Staked ACTS stay locked until the proposals they voted on have closed.")]
+    StakeLocked,
+    #[msg("Only the program upgrade authority can initialize this account.")]
+    NotUpgradeAuthority,
+    #[msg("The milestone is not open for review.")]
+    MilestoneNotInReview,
+    #[msg("A review for this milestone is already in progress.")]
+    MilestoneReviewInProgress,
+    #[msg("The contribution is not eligible for this action.")]
+    ContributionNotEligible,
+    #[msg("The project is refunding its contributors.")]
+    ProjectRefunding,
+    #[msg("Refunds are not enabled for this project.")]
+    RefundsNotEnabled,
+    #[msg("The contribution has already been refunded.")]
+    AlreadyRefunded,
+    #[msg("The challenge window for this milestone is closed.")]
+    ChallengeWindowClosed,
+    #[msg("This milestone already has an open challenge.")]
+    ChallengeAlreadyOpen,
+    #[msg("The challenge is not open.")]
+    ChallengeNotOpen,
+    #[msg("The evidence URI is too long.")]
+    EvidenceUriTooLong,
+    #[msg("Requested amount is locked by an open dispute.")]
+    FundsLockedByDispute,
+    #[msg("Ledger arithmetic overflow.")]
+    LedgerOverflow,
+    #[msg("Audit accounts must be unique (project, project vault) pairs of this round.")]
+    InvalidAuditAccounts,
+    #[msg("Round vault balance is below its recorded liabilities.")]
+    RoundVaultUnderfunded,
+    #[msg("A project vault balance is below its recorded liabilities.")]
+    ProjectVaultUnderfunded,
+    #[msg("Settled matching exceeds the matching pool.")]
+    MatchingExceedsPool,
+    #[msg("This operation is currently paused.")]
+    OperationPaused,
+    #[msg("Invalid pause group or guardian configuration.")]
+    InvalidPauseConfig,
+    #[msg("Not enough guardian signatures.")]
+    GuardianApprovalMissing,
+    #[msg("Invalid vesting schedule.")]
+    InvalidVestingSchedule,
+    #[msg("There is nothing to claim yet.")]
+    NothingToClaim,
+    #[msg("The project has not been rejected.")]
+    ProjectNotRejected,
+    #[msg("Milestone deadlines must cover every milestone, be increasing and in the future.")]
+    InvalidMilestoneDeadlines,
+    #[msg("Rollover round accounts are missing or do not match the round configuration.")]
+    InvalidRolloverRound,
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+    #[msg("Division by zero.")]
+    DivisionByZero,
+    #[msg("Basis points must not exceed 10000.")]
+    InvalidBasisPoints,
+    #[msg("Value out of range.")]
+    ValueOutOfRange,
+    #[msg("The account already uses the current layout.")]
+    AccountAlreadyMigrated,
+    #[msg("The account data does not match the expected legacy layout.")]
+    UnknownAccountLayout,
+    #[msg("Title must not be empty.")]
+    EmptyTitle,
+    #[msg("Title exceeds MAX_TITLE_LEN bytes.")]
+    TitleTooLong,
+    #[msg("Description exceeds MAX_DESCRIPTION_LEN bytes.")]
+    DescriptionTooLong,
+    #[msg("URI exceeds MAX_URI_LEN bytes.")]
+    UriTooLong,
+    #[msg("URI must start with ipfs://, ar:// or https://.")]
+    UnsupportedUriScheme,
+    #[msg("Milestone count must be between 1 and MAX_MILESTONES.")]
+    InvalidMilestoneCount,
+    #[msg("Target amount must be greater than zero.")]
+    InvalidTargetAmount,
+    #[msg("Metadata of a project past the Planning stage needs the round owner's approval.")]
+    MetadataUpdateRequiresApproval,
+    #[msg("The new owner must differ from the current owner and not be the default key.")]
+    InvalidNewOwner,
+    #[msg("Payout splits need 1 to MAX_PAYOUT_RECIPIENTS distinct recipients with non-zero shares summing to 10000 bps.")]
+    InvalidPayoutSplits,
+    #[msg("Payout splits can only be changed while the project is in Planning.")]
+    PayoutSplitsLocked,
+    #[msg("This project pays out through its payout splits.")]
+    PayoutSplitsConfigured,
+    #[msg("Recipient accounts must match the payout splits, in order.")]
+    PayoutRecipientMismatch,
+    #[msg("A team needs 1 to MAX_TEAM_MEMBERS distinct members and a threshold between 1 and the member count.")]
+    InvalidTeamConfig,
+    #[msg("This project is controlled by its team; propose the action for team approval.")]
+    TeamApprovalRequired,
+    #[msg("Signer is not a member of the project team.")]
+    NotTeamMember,
+    #[msg("This member already approved the action.")]
+    TeamActionAlreadyApproved,
+    #[msg("The action does not have enough approvals from current team members.")]
+    TeamThresholdNotMet,
+    #[msg("Accounts passed do not match the team action being executed.")]
+    TeamActionAccountsMismatch,
+    #[msg("The funding round is still active.")]
+    RoundNotFinalized,
+    #[msg("The project is not Completed or Rejected.")]
+    ProjectNotFinalized,
+    #[msg("A refund is still claimable for this contribution.")]
+    RefundOutstanding,
+    #[msg("The project vault still holds funds.")]
+    VaultNotEmpty,
+    #[msg("Contributions can only be withdrawn during the contribution phase, before the cutoff.")]
+    ContributionWithdrawalClosed,
+    #[msg("Amount exceeds what this contributor has contributed.")]
+    WithdrawalExceedsContribution,
+    #[msg("A batch needs 1 to MAX_BATCH_CONTRIBUTIONS amounts, one (project, vault, contribution) triple each and no project twice.")]
+    InvalidContributionBatch,
+    #[msg("Batch accounts do not match the project, its vault or the contributor's record.")]
+    BatchAccountMismatch,
+    #[msg("Amount exceeds the funds released by approved milestones past their challenge window.")]
+    MilestoneNotReleased,
+    #[msg("Round start must precede its end, and the end must lie in the future.")]
+    InvalidRoundWindow,
+    #[msg("Project has matching left to settle from its round.")]
+    MatchingUnsettled,
+    #[msg("This creator's project was closed; its address cannot be reused.")]
+    ProjectAddressRetired,
+    #[msg("A partial withdrawal must leave at least MIN_CONTRIBUTION_AMOUNT contributed.")]
+    RemainingContributionTooSmall,
+    #[msg("Milestone reviews open once the round's contributions can no longer be withdrawn.")]
+    ContributionsStillWithdrawable,
+}
+
+impl From<MathError> for anchor_lang::error::Error {
+    fn from(e: MathError) -> Self {
+        match e {
+            MathError::Overflow => AnectosError::MathOverflow.into(),
+            MathError::DivisionByZero => AnectosError::DivisionByZero.into(),
+            MathError::InvalidBasisPoints => AnectosError::InvalidBasisPoints.into(),
+            MathError::OutOfRange => AnectosError::ValueOutOfRange.into(),
+        }
+    }
 }
\ No newline at end of file
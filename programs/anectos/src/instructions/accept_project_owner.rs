@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::EVENT_SCHEMA_VERSION,
+    error::AnectosError,
+    state::{Project, ProjectOwnerTransfer, ProjectOwnerTransferred},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptProjectOwner<'info> {
+    pub new_owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        close = proposed_by,
+        has_one = project,
+        has_one = new_owner,
+        has_one = proposed_by,
+        seeds = [b"owner_transfer", project.key().as_ref()],
+        bump = owner_transfer.bump
+    )]
+    pub owner_transfer: Account<'info, ProjectOwnerTransfer>,
+    /// Owner who made the proposal; gets the transfer account's rent back
+    #[account(mut)]
+    pub proposed_by: SystemAccount<'info>,
+}
+
+/// Hands the project, and with it every owner-gated instruction and future
+/// withdrawal from its vault, to the nominee. Project and vault addresses do not change.
+pub fn handler(ctx: Context<AcceptProjectOwner>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let previous_owner = project.owner;
+    // The proposal is void if it was made by someone who no longer owns the project
+    require_keys_eq!(ctx.accounts.proposed_by.key(), previous_owner, AnectosError::Unauthorized);
+
+    project.owner = ctx.accounts.new_owner.key();
+
+    emit_cpi!(ProjectOwnerTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        previous_owner,
+        new_owner: project.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
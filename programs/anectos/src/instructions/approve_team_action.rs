@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::EVENT_SCHEMA_VERSION,
+    error::AnectosError,
+    state::{ProjectTeam, TeamActionApproved, TeamActionProposal},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveTeamAction<'info> {
+    pub member: Signer<'info>,
+    #[account(
+        seeds = [b"project_team", team_action.project.as_ref()],
+        bump = project_team.bump
+    )]
+    pub project_team: Account<'info, ProjectTeam>,
+    #[account(
+        mut,
+        seeds = [b"team_action", team_action.project.as_ref(), team_action.id.to_le_bytes().as_ref()],
+        bump = team_action.bump
+    )]
+    pub team_action: Account<'info, TeamActionProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveTeamAction>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let team = &ctx.accounts.project_team;
+    require!(team.is_member(&member), AnectosError::NotTeamMember);
+
+    let proposal = &mut ctx.accounts.team_action;
+    // Approvals of members removed since proposing no longer count
+    proposal.approvals.retain(|key| team.is_member(key));
+    require!(!proposal.approvals.contains(&member), AnectosError::TeamActionAlreadyApproved);
+    proposal.approvals.push(member);
+
+    emit_cpi!(TeamActionApproved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: proposal.project,
+        team_action: proposal.key(),
+        member,
+        approvals: proposal.approvals.len() as u8,
+        threshold: team.threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{FundingRound, Project, RoundAuditReport};
+use crate::error::AnectosError;
+
+#[derive(Accounts)]
+pub struct AuditRound<'info> {
+    pub funding_round: Account<'info, FundingRound>,
+    /// round vault PDA (seeds = [b"round_vault", funding_round])
+    #[account(
+        seeds = [b"round_vault", funding_round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: SystemAccount<'info>,
+    // remaining_accounts: [project, project_vault] pairs belonging to this round
+}
+
+/// Read-only reconciliation of vault balances against program accounting.
+/// Checks, for the round and every (project, vault) pair passed in:
+/// - round vault lamports >= unsettled project matching budgets + rent-exempt minimum
+/// - project vault lamports >= donations held + matching held; project vaults keep no
+///   rent reserve of their own, a drained vault simply disappears
+/// - settled matching <= the project's budget, and summed <= the round matching pool
+/// - the round's own ledger of settled matching <= its matching pool, which also covers
+///   projects left out of the remaining accounts
+///
+/// Returns a `RoundAuditReport` (via return data) or fails with the broken invariant.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AuditRound<'info>>) -> Result<RoundAuditReport> {
+    let round = &ctx.accounts.funding_round;
+    let round_key = round.key();
+    let remaining = ctx.remaining_accounts;
+    let pairs = remaining.chunks_exact(2);
+    require!(pairs.remainder().is_empty(), AnectosError::InvalidAuditAccounts);
+
+    let rent_floor = Rent::get()?.minimum_balance(0);
+    let mut report = RoundAuditReport {
+        round: round_key,
+        rent_exempt_minimum: rent_floor,
+        round_vault_lamports: ctx.accounts.round_vault.lamports(),
+        matching_pool: round.matching_pool,
+        ..Default::default()
+    };
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(remaining.len() / 2);
+    for pair in pairs {
+        let project: Account<Project> = Account::try_from(&pair[0])?;
+        let vault = &pair[1];
+
+        require_keys_eq!(project.round, round_key, AnectosError::InvalidAuditAccounts);
+        require!(!seen.contains(&project.key()), AnectosError::InvalidAuditAccounts);
+        seen.push(project.key());
+
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[b"vault", project.creator.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(expected_vault, vault.key(), AnectosError::InvalidAuditAccounts);
+
+        require!(
+            project.ledger.matching_allocated <= project.matching_pool,
+            AnectosError::MatchingExceedsPool
+        );
+
+        let liabilities = (project.ledger.donations_held() as u128)
+            + (project.ledger.matching_held() as u128);
+        require!(
+            (vault.lamports() as u128) >= liabilities,
+            AnectosError::ProjectVaultUnderfunded
+        );
+
+        let unsettled = project.matching_pool.saturating_sub(project.ledger.matching_allocated);
+        report.round_vault_liabilities = report
+            .round_vault_liabilities
+            .checked_add(unsettled)
+            .ok_or(AnectosError::LedgerOverflow)?;
+        report.matching_allocated = report
+            .matching_allocated
+            .checked_add(project.ledger.matching_allocated)
+            .ok_or(AnectosError::LedgerOverflow)?;
+        report.project_vault_lamports = report.project_vault_lamports.saturating_add(vault.lamports());
+        report.project_vault_liabilities = report
+            .project_vault_liabilities
+            .saturating_add(liabilities as u64);
+        report.projects_checked += 1;
+    }
+
+    require!(
+        report.matching_allocated <= round.matching_pool,
+        AnectosError::MatchingExceedsPool
+    );
+    require!(
+        round.ledger.matching_allocated.saturating_sub(round.ledger.matching_returned) <= round.matching_pool,
+        AnectosError::MatchingExceedsPool
+    );
+    if report.round_vault_liabilities > 0 {
+        require!(
+            (report.round_vault_lamports as u128)
+                >= report.round_vault_liabilities as u128 + rent_floor as u128,
+            AnectosError::RoundVaultUnderfunded
+        );
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{
+    record_ledger_entry, FundingRound, FundingStage, LedgerEntry, Project, ProjectMeta,
+    ProtocolConfig, VestingCancelled,
+};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_WITHDRAWALS};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelVesting<'info> {
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub funding_round: Account<'info, FundingRound>,
+    /// round vault PDA
+    #[account(
+        mut,
+        seeds = [b"round_vault", funding_round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+    /// project vault PDA (seeds = [b"vault", project.creator])
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub project_vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Round owner only: returns the unvested matching of a rejected project to the round
+/// vault. Whatever has vested by now stays claimable by the project.
+pub fn handler(ctx: Context<CancelVesting>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(&ctx.accounts.funding_round))?;
+    require!(
+        ctx.accounts.project_meta.funding_stage == FundingStage::Rejected,
+        AnectosError::ProjectNotRejected
+    );
+
+    let round = &mut ctx.accounts.funding_round;
+    let project = &mut ctx.accounts.project;
+    let now = Clock::get()?.unix_timestamp;
+
+    let vested = project.vested_matching(now);
+    let total = project.ledger.matching_allocated.saturating_sub(project.ledger.matching_returned);
+    let unvested = total.saturating_sub(vested);
+    require!(unvested > 0, AnectosError::NothingToClaim);
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.project_vault]];
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.project_vault.to_account_info(),
+                to: ctx.accounts.round_vault.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        unvested,
+    )?;
+
+    record_ledger_entry(project, round, LedgerEntry::MatchingReturned(unvested))?;
+    // Freeze the schedule: what vested until now is all that will ever vest
+    project.vesting_cliff = project.vesting_cliff.min(now);
+    project.vesting_end = now;
+
+    emit_cpi!(VestingCancelled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        returned: unvested,
+        vested_total: vested,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    GovernanceConfig, Proposal, ProposalStatus, QuadraticVoteCast, QuadraticVoteRecord,
+    VoiceCredits, VoterStake, VotingMode, ProtocolConfig,
+};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_GOVERNANCE};
+use crate::error::AnectosError;
+use crate::math::isqrt_u128;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastQuadraticVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// Per-voter-per-epoch credit budget: seeds = [b"voice_credits", epoch, voter]
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoiceCredits::INIT_SPACE,
+        seeds = [b"voice_credits", proposal.epoch.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voice_credits: Account<'info, VoiceCredits>,
+    /// seeds = [b"qv_vote", proposal, voter]
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + QuadraticVoteRecord::INIT_SPACE,
+        seeds = [b"qv_vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, QuadraticVoteRecord>,
+    #[account(
+        mut,
+        has_one = voter,
+        seeds = [b"voter_stake", voter.key().as_ref()],
+        bump = voter_stake.bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Spends `credits` voice credits on a quadratic proposal. A voter's votes are
+/// sqrt(total credits spent), so casting n votes costs n^2 credits. Votes can be
+/// topped up later on the same side; only the sqrt delta is added to the tally.
+/// Credits go to wallets that staked at least `qv_min_stake` before the epoch began;
+/// that stake stays locked for the epoch and for every proposal it voted on.
+pub fn handler(ctx: Context<CastQuadraticVote>, approve: bool, credits: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE, None)?;
+    require!(credits > 0, AnectosError::InsufficientVoiceCredits);
+
+    let governance = &ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+    let voice_credits = &mut ctx.accounts.voice_credits;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voter = ctx.accounts.voter.key();
+
+    require!(proposal.status == ProposalStatus::Voting, AnectosError::ProposalAlreadyFinalized);
+    require!(proposal.voting_mode == VotingMode::Quadratic, AnectosError::WrongVotingMode);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < proposal.voting_ends_at, AnectosError::VotingClosed);
+
+    let (epoch_start, epoch_end) = governance.qv_epoch_bounds(proposal.epoch);
+    let stake = &mut ctx.accounts.voter_stake;
+    // First use in this epoch: only stakes locked before it started receive an allocation
+    if voice_credits.voter == Pubkey::default() {
+        require!(
+            stake.weight_at(epoch_start) >= governance.qv_min_stake,
+            AnectosError::InsufficientGovernanceTokens
+        );
+
+        voice_credits.voter = voter;
+        voice_credits.epoch = proposal.epoch;
+        voice_credits.allocated = governance.voice_credits_per_epoch;
+        voice_credits.spent = 0;
+        voice_credits.version = ACCOUNT_VERSION;
+        voice_credits.bump = ctx.bumps.voice_credits;
+    }
+
+    let remaining = voice_credits.allocated.saturating_sub(voice_credits.spent);
+    require!(credits <= remaining, AnectosError::InsufficientVoiceCredits);
+    stake.lock_until(epoch_end.max(proposal.voting_ends_at));
+
+    let is_new_vote = vote_record.voter == Pubkey::default();
+    if is_new_vote {
+        vote_record.proposal = proposal.key();
+        vote_record.voter = voter;
+        vote_record.approve = approve;
+        vote_record.credits_spent = 0;
+        vote_record.votes = 0;
+        vote_record.version = ACCOUNT_VERSION;
+        vote_record.bump = ctx.bumps.vote_record;
+    } else {
+        require!(vote_record.approve == approve, AnectosError::VoteDirectionMismatch);
+    }
+
+    let new_total = vote_record.credits_spent.checked_add(credits).ok_or(AnectosError::MathOverflow)?;
+    let votes_total = isqrt_u128(new_total as u128) as u64;
+    let votes_added = votes_total.saturating_sub(vote_record.votes);
+
+    voice_credits.spent = voice_credits.spent.checked_add(credits).ok_or(AnectosError::MathOverflow)?;
+    vote_record.credits_spent = new_total;
+    vote_record.votes = votes_total;
+
+    if approve {
+        proposal.yes_votes = proposal.yes_votes.checked_add(votes_added).ok_or(AnectosError::MathOverflow)?;
+    } else {
+        proposal.no_votes = proposal.no_votes.checked_add(votes_added).ok_or(AnectosError::MathOverflow)?;
+    }
+    if is_new_vote {
+        proposal.voter_count = proposal.voter_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+    }
+
+    emit_cpi!(QuadraticVoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
+        proposal: proposal.key(),
+        voter,
+        approve,
+        credits_spent: credits,
+        votes_added,
+        total_votes: votes_total,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{GovernanceConfig, Proposal, ProposalStatus, VoteCast, VoteRecord, VoterStake, VotingMode, ProtocolConfig};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_GOVERNANCE};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// One vote per wallet per proposal: seeds = [b"vote", proposal, voter]
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        mut,
+        has_one = voter,
+        seeds = [b"voter_stake", voter.key().as_ref()],
+        bump = voter_stake.bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records an ACTS-weighted vote. Weight is the voter's escrowed stake as of the
+/// proposal's opening, which stays locked until voting ends.
+pub fn handler(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE, None)?;
+    let proposal = &mut ctx.accounts.proposal;
+    let voter = ctx.accounts.voter.key();
+
+    require!(proposal.status == ProposalStatus::Voting, AnectosError::ProposalAlreadyFinalized);
+    require!(proposal.voting_mode == VotingMode::TokenWeighted, AnectosError::WrongVotingMode);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < proposal.voting_ends_at, AnectosError::VotingClosed);
+
+    let stake = &mut ctx.accounts.voter_stake;
+    let weight = stake.weight_at(proposal.voting_starts_at);
+    require!(weight > 0, AnectosError::InsufficientGovernanceTokens);
+    stake.lock_until(proposal.voting_ends_at);
+
+    if approve {
+        proposal.yes_votes = proposal.yes_votes.checked_add(weight).ok_or(AnectosError::MathOverflow)?;
+    } else {
+        proposal.no_votes = proposal.no_votes.checked_add(weight).ok_or(AnectosError::MathOverflow)?;
+    }
+    proposal.voter_count = proposal.voter_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = voter;
+    vote_record.approve = approve;
+    vote_record.weight = weight;
+    vote_record.version = ACCOUNT_VERSION;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit_cpi!(VoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
+        proposal: proposal.key(),
+        voter,
+        approve,
+        weight,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, MAX_URI_LEN, MILESTONE_CHALLENGE_BOND, PAUSE_MILESTONES},
+    error::AnectosError,
+    state::{
+        ChallengeStatus, Contribution, MilestoneChallenge, MilestoneChallenged, MilestoneReview,
+        MilestoneReviewStatus, Project, ProtocolConfig,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct ChallengeMilestone<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"milestone_review", project.key().as_ref(), &[milestone_index]],
+        bump = milestone_review.bump
+    )]
+    pub milestone_review: Account<'info, MilestoneReview>,
+    /// Only contributors to the project can challenge
+    #[account(
+        has_one = project,
+        constraint = contribution.contributor == challenger.key() @ AnectosError::Unauthorized,
+        seeds = [b"contrib", project.key().as_ref(), challenger.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + MilestoneChallenge::INIT_SPACE,
+        seeds = [b"challenge", milestone_review.key().as_ref(), &[milestone_review.attempt]],
+        bump
+    )]
+    pub challenge: Account<'info, MilestoneChallenge>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Disputes an approved milestone by escrowing MILESTONE_CHALLENGE_BOND. The milestone
+/// amount stays locked in the project vault until the dispute is resolved.
+pub fn handler(ctx: Context<ChallengeMilestone>, milestone_index: u8, evidence_uri: String) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, None)?;
+    let project = &mut ctx.accounts.project;
+    let review = &mut ctx.accounts.milestone_review;
+
+    require!(evidence_uri.len() <= MAX_URI_LEN as usize, AnectosError::EvidenceUriTooLong);
+    require!(review.status == MilestoneReviewStatus::Approved, AnectosError::MilestoneNotInReview);
+    require!(!review.challenge_open, AnectosError::ChallengeAlreadyOpen);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < review.challenge_ends_at, AnectosError::ChallengeWindowClosed);
+    require!(
+        !ctx.accounts.contribution.is_excluded && ctx.accounts.contribution.total_contributed > 0,
+        AnectosError::ContributionNotEligible
+    );
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.challenger.to_account_info(),
+                to: ctx.accounts.challenge.to_account_info(),
+            },
+        ),
+        MILESTONE_CHALLENGE_BOND,
+    )?;
+
+    let milestone_amount = project.milestones[milestone_index as usize].amount;
+    project.disputed_amount = project.disputed_amount.checked_add(milestone_amount).ok_or(AnectosError::MathOverflow)?;
+    project.milestones[milestone_index as usize].released_at = i64::MAX;
+    review.challenge_open = true;
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.project = project.key();
+    challenge.review = review.key();
+    challenge.milestone_index = milestone_index;
+    challenge.challenger = ctx.accounts.challenger.key();
+    challenge.bond = MILESTONE_CHALLENGE_BOND;
+    challenge.evidence_uri = evidence_uri.clone();
+    challenge.status = ChallengeStatus::Open;
+    challenge.created_at = now;
+    challenge.resolved_at = 0;
+    challenge.resolved_by = Pubkey::default();
+    challenge.version = ACCOUNT_VERSION;
+    challenge.bump = ctx.bumps.challenge;
+
+    emit_cpi!(MilestoneChallenged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        milestone_index,
+        challenger: challenge.challenger,
+        bond: challenge.bond,
+        evidence_uri,
+        timestamp: now,
+    });
+
+    Ok(())
+}
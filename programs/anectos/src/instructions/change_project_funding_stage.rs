@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProjectMeta, Project, FundingRound};
-use crate::{error::AnectosError, FundingRoundMeta, FundingStage};
+use crate::state::{ProjectMeta, Project, FundingRound, FundingStageChanged};
+use crate::{constants::EVENT_SCHEMA_VERSION, error::AnectosError, FundingRoundMeta, FundingStage};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeProjectFundingStage<'info> {
 	#[account(mut)]
@@ -14,7 +15,7 @@ pub struct ChangeProjectFundingStage<'info> {
     pub project_meta: Account<'info, ProjectMeta>,
     #[account(
         mut,
-        seeds = [b"project", project.owner.key().as_ref()],
+        seeds = [b"project", project.creator.as_ref()],
         bump
     )]
     pub project: Account<'info, Project>,
@@ -34,12 +35,34 @@ pub fn handler(ctx: Context<ChangeProjectFundingStage>, funding_stage: FundingSt
     let round = &mut ctx.accounts.funding_round;
     let _round_meta = &mut ctx.accounts.funding_round_metadata;
 
+    let previous_stage = apply_funding_stage(project_meta, project, round, funding_stage);
+
+    emit_cpi!(FundingStageChanged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        previous_stage,
+        new_stage: funding_stage,
+        is_whitelisted: project.is_whitelisted,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+	Ok(())
+}
+
+/// Shared stage transition, also used when a governance proposal is executed.
+/// Returns the stage the project was in before.
+pub fn apply_funding_stage(
+    project_meta: &mut ProjectMeta,
+    project: &mut Project,
+    round: &mut FundingRound,
+    funding_stage: FundingStage,
+) -> FundingStage {
+    let previous_stage = project_meta.funding_stage;
     project_meta.funding_stage = funding_stage;
 
     if project_meta.funding_stage == FundingStage::Active {
         project.is_whitelisted = true;
         round.is_active = true;
     }
-
-	Ok(())
+    previous_stage
 }
\ No newline at end of file
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::EVENT_SCHEMA_VERSION,
+    error::AnectosError,
+    math::pro_rata,
+    state::{record_ledger_entry, Contribution, FundingRound, LedgerEntry, Project, RefundClaimed},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    #[account(mut)]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = project,
+        has_one = contributor,
+        seeds = [b"contrib", project.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns the contributor's pro-rata share of the refund pool of a project whose
+/// milestone was rejected too many times.
+pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let contribution = &mut ctx.accounts.contribution;
+
+    require!(project.refunds_enabled, AnectosError::RefundsNotEnabled);
+    require!(!contribution.refunded, AnectosError::AlreadyRefunded);
+    require!(project.ledger.donations_received > 0, AnectosError::RefundsNotEnabled);
+
+    // share = total_contributed * refund_pool / donations_received
+    let amount = pro_rata(
+        contribution.total_contributed,
+        project.refund_pool,
+        project.ledger.donations_received,
+    )?;
+    contribution.refunded = true;
+
+    if amount > 0 {
+        let creator = project.creator;
+        let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.contributor.to_account_info(),
+                },
+                &[&signer_seeds[..]],
+            ),
+            amount,
+        )?;
+        record_ledger_entry(project, &mut ctx.accounts.funding_round, LedgerEntry::RefundPaid(amount))?;
+    }
+
+    emit_cpi!(RefundClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        contributor: contribution.contributor,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{record_ledger_entry, FundingRound, LedgerEntry, MatchingClaimed, Project, ProtocolConfig};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_WITHDRAWALS};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraws all matching vested so far and not yet withdrawn, up to what approved
+/// milestones past their challenge window release.
+pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(&ctx.accounts.funding_round))?;
+    let project = &mut ctx.accounts.project;
+    let funding_round = &mut ctx.accounts.funding_round;
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+    require!(!project.has_payout_splits, AnectosError::PayoutSplitsConfigured);
+    require!(!project.has_team, AnectosError::TeamApprovalRequired);
+
+    let now = Clock::get()?.unix_timestamp;
+    let claimable = project.claimable_matching(now);
+    require!(claimable > 0, AnectosError::NothingToClaim);
+    // Disputed and unreleased milestones lock funds across donations and matching alike
+    let amount = claimable.min(project.releasable(now));
+    require!(amount > 0, AnectosError::MilestoneNotReleased);
+    require!(ctx.accounts.vault.lamports() >= amount, AnectosError::InsufficientProjectVaultFunds);
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    record_ledger_entry(project, funding_round, LedgerEntry::MatchingWithdrawn(amount))?;
+
+    emit_cpi!(MatchingClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        amount,
+        vested_total: project.vested_matching(now),
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{
+    record_ledger_entry, FundingRound, LedgerEntry, MatchingClawedBack, Project, ProtocolConfig,
+};
+use crate::constants::{EVENT_SCHEMA_VERSION, MILESTONE_GRACE_PERIOD, PAUSE_SETTLEMENT};
+use crate::error::AnectosError;
+use crate::math::pro_rata;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClawbackExpiredMilestones<'info> {
+    /// Permissionless crank
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub funding_round: Account<'info, FundingRound>,
+    /// round vault PDA
+    #[account(
+        mut,
+        seeds = [b"round_vault", funding_round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+    /// project vault PDA (seeds = [b"vault", project.creator])
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub project_vault: SystemAccount<'info>,
+    /// Required when `funding_round.rollover_round` is set
+    #[account(mut)]
+    pub rollover_round: Option<Account<'info, FundingRound>>,
+    /// Round vault PDA of `rollover_round`
+    #[account(mut)]
+    pub rollover_vault: Option<SystemAccount<'info>>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Returns the matching share of every unachieved milestone whose deadline plus
+/// MILESTONE_GRACE_PERIOD has passed. A milestone's share is
+/// matching_allocated * milestone.amount / target_amount, capped by what is still held.
+pub fn handler(ctx: Context<ClawbackExpiredMilestones>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_SETTLEMENT, Some(&ctx.accounts.funding_round))?;
+    let round = &mut ctx.accounts.funding_round;
+    let project = &mut ctx.accounts.project;
+    let now = Clock::get()?.unix_timestamp;
+
+    let destination_round = if round.rollover_round != Pubkey::default() {
+        let rollover = ctx.accounts.rollover_round.as_ref().ok_or(AnectosError::InvalidRolloverRound)?;
+        let vault = ctx.accounts.rollover_vault.as_ref().ok_or(AnectosError::InvalidRolloverRound)?;
+        require_keys_eq!(rollover.key(), round.rollover_round, AnectosError::InvalidRolloverRound);
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[b"round_vault", rollover.key().as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(expected_vault, vault.key(), AnectosError::InvalidRolloverRound);
+        rollover.key()
+    } else {
+        round.key()
+    };
+    let destination_vault = match ctx.accounts.rollover_vault.as_ref() {
+        Some(vault) if destination_round != round.key() => vault.to_account_info(),
+        _ => ctx.accounts.round_vault.to_account_info(),
+    };
+
+    let mut total_clawed: u64 = 0;
+
+    for index in 0..project.milestones.len() {
+        let milestone = &project.milestones[index];
+        if milestone.is_achieved
+            || milestone.matching_clawed_back
+            || milestone.due_date == 0
+            || now <= milestone.due_date.saturating_add(MILESTONE_GRACE_PERIOD)
+        {
+            continue;
+        }
+
+        let share = pro_rata(project.ledger.matching_allocated, milestone.amount, project.target_amount)?;
+        let amount = share.min(project.ledger.matching_held());
+        project.milestones[index].matching_clawed_back = true;
+        if amount == 0 {
+            continue;
+        }
+
+        let creator = project.creator;
+        let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.project_vault]];
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.project_vault.to_account_info(),
+                    to: destination_vault.clone(),
+                },
+                &[&signer_seeds[..]],
+            ),
+            amount,
+        )?;
+        record_ledger_entry(project, round, LedgerEntry::MatchingReturned(amount))?;
+        total_clawed = total_clawed.checked_add(amount).ok_or(AnectosError::LedgerOverflow)?;
+
+        emit_cpi!(MatchingClawedBack {
+            schema_version: EVENT_SCHEMA_VERSION,
+            project: project.key(),
+            milestone_index: index as u8,
+            destination_round,
+            amount,
+            timestamp: now,
+        });
+    }
+
+    // Recycled matching grows the next round's declared pool
+    if total_clawed > 0 && destination_round != round.key() {
+        if let Some(rollover) = ctx.accounts.rollover_round.as_mut() {
+            rollover.matching_pool = rollover
+                .matching_pool
+                .checked_add(total_clawed)
+                .ok_or(AnectosError::LedgerOverflow)?;
+        }
+    }
+
+    Ok(())
+}
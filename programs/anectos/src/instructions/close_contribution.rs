@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::EVENT_SCHEMA_VERSION,
+    error::AnectosError,
+    state::{Contribution, ContributionClosed, FundingRound, Project, ProjectMeta},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseContribution<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    #[account(
+        mut,
+        close = contributor,
+        has_one = contributor,
+        seeds = [b"contrib", contribution.project.as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    /// CHECK: the contribution's project, possibly already closed; read via `load_if_open`
+    #[account(address = contribution.project)]
+    pub project: UncheckedAccount<'info>,
+    /// CHECK: the project's metadata, possibly already closed; read via `load_if_open`
+    #[account(
+        seeds = [b"project_metadata", contribution.project.as_ref()],
+        bump
+    )]
+    pub project_meta: UncheckedAccount<'info>,
+    /// CHECK: the project's round, possibly already closed; matched against `Project::round`
+    pub funding_round: UncheckedAccount<'info>,
+}
+
+/// Deserializes a program account, or returns `None` once it has been closed.
+pub fn load_if_open<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(T::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Returns the record's rent to the donor once nothing can depend on it any more: the
+/// round is closed or inactive, the project Completed or Rejected and any refund
+/// claimed. Records of closed projects can always be closed, since a project only
+/// closes with an empty vault.
+pub fn handler(ctx: Context<CloseContribution>) -> Result<()> {
+    if let Some(project) = load_if_open::<Project>(&ctx.accounts.project)? {
+        require_keys_eq!(ctx.accounts.funding_round.key(), project.round, AnectosError::Unauthorized);
+        let round_active = load_if_open::<FundingRound>(&ctx.accounts.funding_round)?
+            .is_some_and(|round| round.is_active);
+        require!(!round_active, AnectosError::RoundNotFinalized);
+
+        let meta = load_if_open::<ProjectMeta>(&ctx.accounts.project_meta)?
+            .ok_or(AnectosError::ProjectNotFinalized)?;
+        require!(meta.funding_stage.is_final(), AnectosError::ProjectNotFinalized);
+        require!(
+            !project.refunds_enabled || ctx.accounts.contribution.refunded,
+            AnectosError::RefundOutstanding
+        );
+    }
+
+    let contribution = &ctx.accounts.contribution;
+    emit_cpi!(ContributionClosed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: contribution.project,
+        contributor: contribution.contributor,
+        rent_reclaimed: contribution.to_account_info().lamports(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_WITHDRAWALS},
+    error::AnectosError,
+    instructions::close_contribution::load_if_open,
+    state::{FundingRound, Project, ProjectClosed, ProjectMeta, ProjectTombstone, ProtocolConfig},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseProject<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        close = creator,
+        has_one = owner,
+        has_one = creator,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump = project_meta.bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: paid for the project accounts and receives their rent; checked by `has_one`
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectTombstone::INIT_SPACE,
+        seeds = [b"project_tombstone", project.creator.as_ref()],
+        bump
+    )]
+    pub tombstone: Account<'info, ProjectTombstone>,
+    /// CHECK: the project's round, possibly already closed; read via `load_if_open`
+    #[account(address = project.round @ AnectosError::Unauthorized)]
+    pub funding_round: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes a Completed or Rejected project whose vault holds no more than its rent
+/// reserve and forfeited challenge bonds. Account rent goes to the creator who paid it, the reserve to the owner.
+/// The owner pays for a tombstone that keeps the project address from being reused.
+pub fn handler(ctx: Context<CloseProject>) -> Result<()> {
+    // The project's round may already be closed, so only the protocol-wide pause applies
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, None)?;
+    let project = &ctx.accounts.project;
+    require!(!project.has_team, AnectosError::TeamApprovalRequired);
+    require!(ctx.accounts.project_meta.funding_stage.is_final(), AnectosError::ProjectNotFinalized);
+    require!(project.disputed_amount == 0, AnectosError::FundsLockedByDispute);
+    // Matching still budgeted in an open round could be settled into the vault later
+    if load_if_open::<FundingRound>(&ctx.accounts.funding_round)?.is_some() {
+        require!(
+            project.ledger.matching_allocated >= project.matching_pool,
+            AnectosError::MatchingUnsettled
+        );
+    }
+
+    // Refund snapshots keep the vault's rent reserve back, so that much may remain,
+    // along with forfeited challenge bonds
+    let vault_swept = ctx.accounts.vault.lamports();
+    require!(
+        vault_swept <= Rent::get()?.minimum_balance(0).saturating_add(project.bonds_forfeited),
+        AnectosError::VaultNotEmpty
+    );
+    if vault_swept > 0 {
+        let creator = project.creator;
+        let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+                &[&signer_seeds[..]],
+            ),
+            vault_swept,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.version = ACCOUNT_VERSION;
+    tombstone.project = project.key();
+    tombstone.closed_at = now;
+    tombstone.bump = ctx.bumps.tombstone;
+
+    emit_cpi!(ProjectClosed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        owner: project.owner,
+        creator: project.creator,
+        rent_reclaimed: project.to_account_info().lamports() + ctx.accounts.project_meta.to_account_info().lamports(),
+        vault_swept,
+        timestamp: now,
+    });
+
+    Ok(())
+}
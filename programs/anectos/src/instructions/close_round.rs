@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::FundingRound;
+use crate::state::{FundingRound, RoundClosed};
+use crate::constants::EVENT_SCHEMA_VERSION;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseRound<'info> {
 	#[account(mut)]
@@ -12,5 +14,16 @@ pub struct CloseRound<'info> {
 pub fn handler(ctx: Context<CloseRound>) -> Result<()> {
 	let round = &mut ctx.accounts.funding_round;
 	round.is_active = false;
+
+	emit_cpi!(RoundClosed {
+		schema_version: EVENT_SCHEMA_VERSION,
+		round: round.key(),
+		owner: round.owner,
+		total_donations: round.total_donations,
+		matching_pool: round.matching_pool,
+		pool_distributed: round.pool_distributed,
+		lamports_reclaimed: round.to_account_info().lamports(),
+		timestamp: Clock::get()?.unix_timestamp,
+	});
 	Ok(())
 }
\ No newline at end of file
@@ -1,34 +1,122 @@
 use anchor_lang::prelude::*;
-use crate::{error::AnectosError, state::{Milestone, Project, MilestoneCompleted}};
+use crate::{
+	constants::{BPS_DENOMINATOR, EVENT_SCHEMA_VERSION, MAX_MILESTONE_FAILURES, MILESTONE_APPROVAL_THRESHOLD_BPS, MILESTONE_CHALLENGE_PERIOD, PAUSE_MILESTONES},
+	error::AnectosError,
+	state::{FundingStage, MilestoneCompleted, MilestoneRejected, MilestoneReview, MilestoneReviewStatus, Project, ProjectMeta, ProtocolConfig},
+};
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(milestone_index: u8)]
 pub struct CompleteMilestone<'info> {
-	#[account(mut)]
-	pub owner: Signer<'info>,
+	/// Anyone can finalize a review once its voting window has closed
+	pub authority: Signer<'info>,
 	#[account(
 		mut,
-		has_one = owner,
-		seeds = [b"project", owner.key().as_ref()],
+		seeds = [b"project", project.creator.as_ref()],
 		bump
 	)]
 	pub project: Account<'info, Project>,
+	#[account(
+		mut,
+		seeds = [b"project_metadata", project.key().as_ref()],
+		bump
+	)]
+	pub project_meta: Account<'info, ProjectMeta>,
+	#[account(
+		mut,
+		has_one = project,
+		seeds = [b"milestone_review", project.key().as_ref(), &[milestone_index]],
+		bump = milestone_review.bump
+	)]
+	pub milestone_review: Account<'info, MilestoneReview>,
+	/// project vault PDA (seeds = [b"vault", project.creator])
+	#[account(
+		seeds = [b"vault", project.creator.as_ref()],
+		bump
+	)]
+	pub vault: SystemAccount<'info>,
+	#[account(
+		seeds = [b"protocol_config"],
+		bump = protocol_config.bump
+	)]
+	pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
-pub fn handler(ctx: Context<CompleteMilestone>, milestone_index: u8, current_funding: u64) -> Result<()> {
+/// Tallies the contributor vote on a milestone. Approval marks the milestone achieved;
+/// rejection lets the owner resubmit, and after MAX_MILESTONE_FAILURES opens refunds.
+pub fn handler(ctx: Context<CompleteMilestone>, milestone_index: u8) -> Result<()> {
+	ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, None)?;
 	let project = &mut ctx.accounts.project;
-	
+	let review = &mut ctx.accounts.milestone_review;
+
 	require!((milestone_index as usize) < project.milestones.len(), AnectosError::MilestoneIndexOutOfBounds);
-    require!(project.milestones[milestone_index as usize].amount <= current_funding, AnectosError::InsufficientProjectVaultFunds);
 	require!(!project.milestones[milestone_index as usize].is_achieved, AnectosError::MilestoneAlreadyCompleted);
-	
-	project.milestones[milestone_index as usize].is_achieved = true;
-	
-	emit!(MilestoneCompleted {
-		project: project.key(),
-		milestone_index,
-		milestone_amount: project.milestones[milestone_index as usize].amount,
-		timestamp: Clock::get()?.unix_timestamp,
-	});
-	
-    Ok(())
+	require!(review.status == MilestoneReviewStatus::Voting, AnectosError::MilestoneNotInReview);
+	let now = Clock::get()?.unix_timestamp;
+	require!(now >= review.voting_ends_at, AnectosError::VotingStillOpen);
+
+	let total = review.approve_weight.saturating_add(review.reject_weight);
+	let approved = total > 0
+		&& review.approve_weight.saturating_mul(BPS_DENOMINATOR as u128)
+			> total.saturating_mul(MILESTONE_APPROVAL_THRESHOLD_BPS as u128);
+
+	if approved {
+		review.status = MilestoneReviewStatus::Approved;
+		review.challenge_ends_at = now + MILESTONE_CHALLENGE_PERIOD;
+		project.milestones[milestone_index as usize].is_achieved = true;
+		project.milestones[milestone_index as usize].released_at = review.challenge_ends_at;
+
+		emit_cpi!(MilestoneCompleted {
+			schema_version: EVENT_SCHEMA_VERSION,
+			project: project.key(),
+			milestone_index,
+			milestone_amount: project.milestones[milestone_index as usize].amount,
+			timestamp: now,
+		});
+		return Ok(());
+	}
+
+	let rejected = record_milestone_failure(
+		project,
+		&mut ctx.accounts.project_meta,
+		review,
+		ctx.accounts.vault.lamports(),
+		now,
+	)?;
+	emit_cpi!(rejected);
+
+	Ok(())
+}
+
+/// Marks the review rejected so the owner can resubmit. After MAX_MILESTONE_FAILURES
+/// the project is rejected and the donations left in the vault open for refunds.
+/// Returns the event for the caller to emit.
+pub fn record_milestone_failure(
+	project: &mut Project,
+	project_meta: &mut ProjectMeta,
+	review: &mut MilestoneReview,
+	vault_lamports: u64,
+	now: i64,
+) -> Result<MilestoneRejected> {
+	review.status = MilestoneReviewStatus::Rejected;
+	review.failed_attempts = review.failed_attempts.saturating_add(1);
+
+	if review.failed_attempts >= MAX_MILESTONE_FAILURES && !project.refunds_enabled {
+		// Snapshot what is left of donations in the vault; contributors claim pro-rata
+		let rent_floor = Rent::get()?.minimum_balance(0);
+		let available = vault_lamports.saturating_sub(rent_floor);
+		project.refunds_enabled = true;
+		project.refund_pool = available.min(project.current_funding);
+		project_meta.funding_stage = FundingStage::Rejected;
+	}
+
+	Ok(MilestoneRejected {
+		schema_version: EVENT_SCHEMA_VERSION,
+		project: review.project,
+		milestone_index: review.milestone_index,
+		failed_attempts: review.failed_attempts,
+		refunds_enabled: project.refunds_enabled,
+		timestamp: now,
+	})
 }
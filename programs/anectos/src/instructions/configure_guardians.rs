@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{GuardiansUpdated, ProtocolConfig};
+use crate::constants::EVENT_SCHEMA_VERSION;
+use crate::instructions::initialize_protocol_config::validate_guardians;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureGuardians<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    // remaining_accounts: signers from the *current* guardian set
+}
+
+/// Rotates the guardian multisig. Requires approval from the current guardians.
+pub fn handler(
+    ctx: Context<ConfigureGuardians>,
+    guardians: Vec<Pubkey>,
+    guardian_threshold: u8,
+) -> Result<()> {
+    validate_guardians(&guardians, guardian_threshold)?;
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.require_guardian_approval(ctx.remaining_accounts)?;
+    let previous_threshold = config.guardian_threshold;
+    config.guardians = guardians;
+    config.guardian_threshold = guardian_threshold;
+
+    emit_cpi!(GuardiansUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        guardians: config.guardians.clone(),
+        previous_threshold,
+        guardian_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
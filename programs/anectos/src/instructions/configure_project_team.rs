@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, MAX_TEAM_MEMBERS},
+    error::AnectosError,
+    state::{Project, ProjectTeam, ProjectTeamConfigured},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureProjectTeam<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectTeam::INIT_SPACE,
+        seeds = [b"project_team", project.key().as_ref()],
+        bump
+    )]
+    pub project_team: Account<'info, ProjectTeam>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 1 to `MAX_TEAM_MEMBERS` distinct, non-default members and `1 <= threshold <= members`.
+pub fn validate_team(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty()
+            && members.len() <= MAX_TEAM_MEMBERS as usize
+            && threshold >= 1
+            && threshold as usize <= members.len(),
+        AnectosError::InvalidTeamConfig
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            *member != Pubkey::default() && !members[..i].contains(member),
+            AnectosError::InvalidTeamConfig
+        );
+    }
+    Ok(())
+}
+
+/// Hands control of the project's sensitive actions to an M-of-N team. One-way: the
+/// owner's signature no longer suffices afterwards, and the team changes its own
+/// membership through an approved `UpdateTeam` action.
+pub fn handler(ctx: Context<ConfigureProjectTeam>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    validate_team(&members, threshold)?;
+
+    let project = &mut ctx.accounts.project;
+    project.has_team = true;
+
+    let team = &mut ctx.accounts.project_team;
+    team.version = ACCOUNT_VERSION;
+    team.project = project.key();
+    team.members = members.clone();
+    team.threshold = threshold;
+    team.action_count = 0;
+    team.bump = ctx.bumps.project_team;
+
+    emit_cpi!(ProjectTeamConfigured {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: team.project,
+        members,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
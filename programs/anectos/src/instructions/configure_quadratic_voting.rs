@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{GovernanceConfig, GovernanceConfigUpdated};
+use crate::constants::EVENT_SCHEMA_VERSION;
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureQuadraticVoting<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+}
+
+/// Sets the voice credit budget and epoch length for quadratic proposals.
+/// Setting `voice_credits_per_epoch` to 0 disables new quadratic proposals.
+/// Each wallet's budget needs `qv_min_stake` ACTS locked, so splitting tokens across
+/// wallets to multiply budgets costs a full stake per wallet.
+pub fn handler(
+    ctx: Context<ConfigureQuadraticVoting>,
+    voice_credits_per_epoch: u64,
+    qv_epoch_duration: i64,
+    qv_quorum: u64,
+    qv_min_stake: u64,
+) -> Result<()> {
+    require!(
+        voice_credits_per_epoch == 0 || (qv_epoch_duration > 0 && qv_min_stake > 0),
+        AnectosError::InvalidGovernanceConfig
+    );
+
+    let governance = &mut ctx.accounts.governance;
+    governance.voice_credits_per_epoch = voice_credits_per_epoch;
+    governance.qv_epoch_duration = qv_epoch_duration;
+    governance.qv_quorum = qv_quorum;
+    governance.qv_min_stake = qv_min_stake;
+
+    emit_cpi!(GovernanceConfigUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        admin: governance.admin,
+        acts_mint: governance.acts_mint,
+        proposal_threshold: governance.proposal_threshold,
+        voting_period: governance.voting_period,
+        quorum: governance.quorum,
+        approval_threshold_bps: governance.approval_threshold_bps,
+        voice_credits_per_epoch: governance.voice_credits_per_epoch,
+        qv_epoch_duration: governance.qv_epoch_duration,
+        qv_quorum: governance.qv_quorum,
+        qv_min_stake: governance.qv_min_stake,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
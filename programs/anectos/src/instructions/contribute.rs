@@ -1,27 +1,36 @@
 use anchor_lang::prelude::*;
-use crate::state::{FundingRound, Project, Contribution};
+use crate::state::{record_ledger_entry, Contribution, FundingRound, LedgerEntry, Project, ProtocolConfig};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_CONTRIBUTIONS};
 use crate::error::AnectosError;
+use crate::math::area_delta;
 use anchor_lang::system_program::{transfer, Transfer};
 use crate::state::ContributionMade;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Contribute<'info> {
 	#[account(mut)]
 	pub funding_round: Account<'info, FundingRound>,
 	#[account(
         mut,
-        seeds = [b"project", project.owner.key().as_ref()],
-        bump
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
     )]
 	pub project: Account<'info, Project>,
         #[account(
         mut,
-        seeds = [b"vault", project.owner.key().as_ref()],
+        seeds = [b"vault", project.creator.as_ref()],
         bump
     )]
     pub vault: SystemAccount<'info>,
     #[account(mut)]
 	pub user: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>,
     /// Per-project-per-contributor record: seeds = [b"contrib", project, user]
     #[account(
@@ -35,12 +44,15 @@ pub struct Contribute<'info> {
 }
 
 pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
-	require!(amount > 0, AnectosError::InvalidContributionAmount);
-	
-	let funding_round = &mut ctx.accounts.funding_round;
-	let project = &mut ctx.accounts.project;
-	
-	require!(funding_round.is_active, AnectosError::FundingRoundInactive);
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CONTRIBUTIONS, Some(&ctx.accounts.funding_round))?;
+    let event = record_contribution(
+        &mut ctx.accounts.funding_round,
+        &mut ctx.accounts.project,
+        &mut ctx.accounts.contribution,
+        ctx.accounts.user.key(),
+        ctx.bumps.contribution,
+        amount,
+    )?;
 
     transfer(
         CpiContext::new(
@@ -53,43 +65,54 @@ pub fn handler(ctx: Context<Contribute>, amount: u64) -> Result<()> {
         amount,
     )?;
 
-    project.current_funding = project
-        .current_funding
-        .checked_add(amount)
-        .ok_or(AnectosError::InvalidContributionAmount)?;
+    emit_cpi!(event);
+	Ok(())
+}
+
+/// Validates a contribution of `amount` and books it: ledger, the contributor's record
+/// and QF area. The caller moves the lamports into the project vault and emits the
+/// returned event. Shared with `contribute_batch`.
+pub fn record_contribution(
+    funding_round: &mut Account<FundingRound>,
+    project: &mut Account<Project>,
+    contrib: &mut Contribution,
+    contributor: Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<ContributionMade> {
+	require!(amount > 0, AnectosError::InvalidContributionAmount);
+	require!(funding_round.is_active, AnectosError::FundingRoundInactive);
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+
+    record_ledger_entry(project, funding_round, LedgerEntry::DonationReceived(amount))?;
 
-    // Integer sqrt for deterministic quadratic funding accumulation
-    fn isqrt_u128(x: u128) -> u128 {
-        if x == 0 { return 0; }
-        let mut z = (x + 1) >> 1;
-        let mut y = x;
-        while z < y {
-            y = z;
-            z = (x / z + z) >> 1;
-        }
-        y
-    }
     // True QF: area accumulates sqrt(total_per_contributor). Add delta = sqrt(prev+amt) - sqrt(prev)
-    let contrib = &mut ctx.accounts.contribution;
-    let prev = contrib.total_contributed as u128;
-    let new_total = prev.checked_add(amount as u128).unwrap();
-    let delta = isqrt_u128(new_total).saturating_sub(isqrt_u128(prev));
-    contrib.total_contributed = (new_total as u64);
-    project.area = project.area.checked_add(delta).unwrap();
-    funding_round.area = funding_round.area.checked_add(delta).unwrap();
+    if contrib.contributor == Pubkey::default() {
+        contrib.project = project.key();
+        contrib.contributor = contributor;
+        contrib.version = ACCOUNT_VERSION;
+        contrib.bump = bump;
+    }
+    require!(!contrib.is_excluded, AnectosError::ContributionAlreadyExcluded);
+    let prev = contrib.total_contributed;
+    let new_total = prev.checked_add(amount).ok_or(AnectosError::MathOverflow)?;
+    let delta = area_delta(prev as u128, new_total as u128);
+    contrib.total_contributed = new_total;
+    project.area = project.area.checked_add(delta).ok_or(AnectosError::MathOverflow)?;
+    funding_round.area = funding_round.area.checked_add(delta).ok_or(AnectosError::MathOverflow)?;
 
     // Note: matching_pool represents total pool size and should NOT be recomputed here
-	funding_round.total_donations = funding_round.total_donations.checked_add(amount).unwrap();
-
-	funding_round.contributor_count = funding_round.contributor_count.checked_add(1).unwrap();
+    // A record counts once, and again after it was fully withdrawn
+    if prev == 0 {
+        funding_round.contributor_count = funding_round.contributor_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+    }
 
-    emit!(ContributionMade {
+    Ok(ContributionMade {
+        schema_version: EVENT_SCHEMA_VERSION,
         project: project.key(),
-        contributor: ctx.accounts.user.key(),
+        contributor,
         amount,
         new_total_funding: project.current_funding,
         timestamp: Clock::get()?.unix_timestamp,
-    });
-    
-	Ok(())
+    })
 }
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use crate::{
+    constants::{MAX_BATCH_CONTRIBUTIONS, PAUSE_CONTRIBUTIONS},
+    error::AnectosError,
+    instructions::contribute::record_contribution,
+    state::{Contribution, FundingRound, Project, ProtocolConfig},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ContributeBatch<'info> {
+    #[account(mut)]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: one writable (project, vault, contribution) triple per amount
+}
+
+/// Contributes `amounts[i]` to the i-th project in one instruction, with the same
+/// checks and QF accounting as `contribute`. Any failing leg fails the whole batch.
+/// No protocol fee is taken (`FundingRound::fee` is always 0), so each leg moves
+/// exactly its amount into the project vault.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ContributeBatch<'info>>, amounts: Vec<u64>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CONTRIBUTIONS, Some(&ctx.accounts.funding_round))?;
+    let legs = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        !amounts.is_empty()
+            && amounts.len() <= MAX_BATCH_CONTRIBUTIONS as usize
+            && legs.remainder().is_empty()
+            && legs.len() == amounts.len(),
+        AnectosError::InvalidContributionBatch
+    );
+
+    let user = ctx.accounts.user.key();
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(amounts.len());
+    for (leg, &amount) in legs.zip(amounts.iter()) {
+        let mut project: Account<Project> = Account::try_from(&leg[0])?;
+        let (vault, contribution_info) = (&leg[1], &leg[2]);
+        let project_key = project.key();
+        require!(!seen.contains(&project_key), AnectosError::InvalidContributionBatch);
+        seen.push(project_key);
+
+        let expected_project =
+            Pubkey::create_program_address(&[b"project", project.creator.as_ref(), &[project.bump]], &crate::ID)
+                .map_err(|_| AnectosError::BatchAccountMismatch)?;
+        require_keys_eq!(project_key, expected_project, AnectosError::BatchAccountMismatch);
+        require_keys_eq!(project.round, ctx.accounts.funding_round.key(), AnectosError::Unauthorized);
+        let (expected_vault, _) = Pubkey::find_program_address(&[b"vault", project.creator.as_ref()], ctx.program_id);
+        require_keys_eq!(vault.key(), expected_vault, AnectosError::BatchAccountMismatch);
+        let (expected_contribution, bump) =
+            Pubkey::find_program_address(&[b"contrib", project_key.as_ref(), user.as_ref()], ctx.program_id);
+        require_keys_eq!(contribution_info.key(), expected_contribution, AnectosError::BatchAccountMismatch);
+
+        if contribution_info.owner != &crate::ID {
+            let seeds: &[&[u8]] = &[b"contrib", project_key.as_ref(), user.as_ref(), &[bump]];
+            create_contribution_account(&ctx.accounts.user, contribution_info, &ctx.accounts.system_program, seeds)?;
+        }
+        let mut contribution = Account::<Contribution>::try_from(contribution_info)?;
+
+        let event = record_contribution(
+            &mut ctx.accounts.funding_round,
+            &mut project,
+            &mut contribution,
+            user,
+            bump,
+            amount,
+        )?;
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: vault.clone(),
+                },
+            ),
+            amount,
+        )?;
+        project.exit(&crate::ID)?;
+        contribution.exit(&crate::ID)?;
+        emit_cpi!(event);
+    }
+
+    Ok(())
+}
+
+/// Creates a contributor record the way `init_if_needed` does in `contribute`, leaving
+/// it zeroed behind the discriminator so it loads as a fresh `Contribution`.
+fn create_contribution_account<'info>(
+    payer: &Signer<'info>,
+    contribution: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let space = 8 + Contribution::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let system_program = system_program.to_account_info();
+    let current = contribution.lamports();
+    if current == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount { from: payer.to_account_info(), to: contribution.clone() },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        // Someone pre-funded the address; top it up instead of failing the batch
+        if rent > current {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer { from: payer.to_account_info(), to: contribution.clone() },
+                ),
+                rent - current,
+            )?;
+        }
+        allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate { account_to_allocate: contribution.clone() },
+                &[seeds],
+            ),
+            space as u64,
+        )?;
+        assign(
+            CpiContext::new_with_signer(system_program, Assign { account_to_assign: contribution.clone() }, &[seeds]),
+            &crate::ID,
+        )?;
+    }
+    contribution.try_borrow_mut_data()?[..8].copy_from_slice(Contribution::DISCRIMINATOR);
+    Ok(())
+}
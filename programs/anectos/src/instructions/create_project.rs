@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::{state::{Milestone, Project, ProjectCreated}, FundingStage, ProjectMeta, SDGGoals};
+use crate::{state::{Ledger, Milestone, Project, ProjectCreated}, FundingStage, ProjectMeta, SDGGoals};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION};
+use crate::error::AnectosError;
+use crate::math::milestone_amount;
+use crate::validation::{validate_milestone_count, validate_project_metadata, validate_target_amount};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateProject<'info> {
     #[account(mut)]
@@ -22,31 +27,28 @@ pub struct CreateProject<'info> {
         bump
     )]
     pub project_metadata: Account<'info, ProjectMeta>,
+    /// CHECK: written by `close_project`; must not exist, so a closed project's address is never reused
+    #[account(
+        seeds = [b"project_tombstone", owner.key().as_ref()],
+        bump,
+        constraint = project_tombstone.data_is_empty() @ AnectosError::ProjectAddressRetired
+    )]
+    pub project_tombstone: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 impl <'info> CreateProject<'info> {
     pub fn calculate_milestones(target_amount: u64, milestone_count: u8) -> Vec<Milestone> {
-        let n = milestone_count as u64;
-        let step = if n > 0 { target_amount / (n * (n + 1) / 2) } else { 0 };
-        let mut milestones = Vec::new();
-        let mut sum: u64 = 0;
-        for i in 1..=n {
-            let amount = step.saturating_mul(i);
-            milestones.push(Milestone {
-                amount,
+        (0..milestone_count)
+            .map(|index| Milestone {
+                // index < milestone_count, so this never fails
+                amount: milestone_amount(target_amount, milestone_count, index).unwrap_or(0),
                 is_achieved: false,
-            });
-            sum = sum.saturating_add(amount);
-        }
-
-        if sum != target_amount {
-            let diff = target_amount.saturating_sub(sum);
-            if let Some(last) = milestones.last_mut() {
-                last.amount = last.amount.saturating_add(diff);
-            }
-        }
-        milestones
+                due_date: 0,
+                matching_clawed_back: false,
+                released_at: 0,
+            })
+            .collect()
     }
 }
 
@@ -60,11 +62,15 @@ pub fn handler(
     sdg_goals: Vec<SDGGoals>,
     project_image_metadata_uri: String,
 ) -> Result<()> {
+    validate_target_amount(target_amount)?;
+    validate_milestone_count(milestone_count)?;
+    let sdg_goals = validate_project_metadata(&title, &description, &project_image_metadata_uri, sdg_goals)?;
     let milestones = CreateProject::calculate_milestones(target_amount, milestone_count);
 
     let project = &mut ctx.accounts.project;
     let project_metadata = &mut ctx.accounts.project_metadata;
 
+    project.version = ACCOUNT_VERSION;
     project.project_id = project.key();
     project.round = round;
     project.owner = ctx.accounts.owner.key();
@@ -78,8 +84,17 @@ pub fn handler(
     project.has_withdrawn = false;
     project.milestone_count = milestone_count;
     project.milestones = milestones;
+    project.ledger = Ledger::default();
+    project.refunds_enabled = false;
+    project.refund_pool = 0;
+    project.disputed_amount = 0;
+    project.vesting_start = 0;
+    project.vesting_cliff = 0;
+    project.vesting_end = 0;
     project.bump = ctx.bumps.project;
+    project.creator = ctx.accounts.owner.key();
 
+    project_metadata.version = ACCOUNT_VERSION;
     project_metadata.project = project.key();
     project_metadata.title = title;
     project_metadata.description = description;
@@ -88,7 +103,8 @@ pub fn handler(
     project_metadata.image_metadata_uri = project_image_metadata_uri;
     project_metadata.bump = ctx.bumps.project_metadata;
 
-    emit!(ProjectCreated {
+    emit_cpi!(ProjectCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
         project: project.key(),
         owner: ctx.accounts.owner.key(),
         target_amount,
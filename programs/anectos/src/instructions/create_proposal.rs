@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+use crate::state::{GovernanceConfig, Proposal, ProposalAction, ProposalCreated, ProposalStatus, VotingMode, ProtocolConfig};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, MAX_URI_LEN, PAUSE_GOVERNANCE};
+use crate::error::AnectosError;
+
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PQnBqCXEpPxuEb");
+/// Size of the base SPL token account layout (mint, owner, amount, ...)
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Reads the ACTS balance held by `owner` in an SPL token (or Token-2022) account.
+/// The account layout is parsed directly: mint @0, owner @32, amount @64.
+pub fn read_acts_balance(token_account: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<u64> {
+    require!(
+        *token_account.owner == TOKEN_PROGRAM_ID || *token_account.owner == TOKEN_2022_PROGRAM_ID,
+        AnectosError::InvalidGovernanceTokenAccount
+    );
+    let data = token_account.try_borrow_data()?;
+    require!(data.len() >= TOKEN_ACCOUNT_LEN, AnectosError::InvalidGovernanceTokenAccount);
+    require!(&data[0..32] == mint.as_ref(), AnectosError::InvalidGovernanceTokenAccount);
+    require!(&data[32..64] == owner.as_ref(), AnectosError::InvalidGovernanceTokenAccount);
+
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(&data[64..72]);
+    Ok(u64::from_le_bytes(amount))
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: proposer's ACTS token account, validated in `read_acts_balance`
+    pub proposer_token_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    action: ProposalAction,
+    description_uri: String,
+    voting_mode: VotingMode,
+) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE, None)?;
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(
+        description_uri.len() <= MAX_URI_LEN as usize,
+        AnectosError::InvalidGovernanceConfig
+    );
+    let balance = read_acts_balance(
+        &ctx.accounts.proposer_token_account.to_account_info(),
+        &governance.acts_mint,
+        &proposer,
+    )?;
+    require!(
+        balance >= governance.proposal_threshold,
+        AnectosError::InsufficientGovernanceTokens
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let epoch = match voting_mode {
+        VotingMode::TokenWeighted => 0,
+        VotingMode::Quadratic => {
+            require!(
+                governance.voice_credits_per_epoch > 0 && governance.qv_epoch_duration > 0,
+                AnectosError::QuadraticVotingDisabled
+            );
+            (now / governance.qv_epoch_duration) as u64
+        }
+    };
+
+    proposal.id = governance.proposal_count;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.description_uri = description_uri;
+    proposal.voting_mode = voting_mode;
+    proposal.epoch = epoch;
+    proposal.yes_votes = 0;
+    proposal.no_votes = 0;
+    proposal.voter_count = 0;
+    proposal.voting_starts_at = now;
+    proposal.voting_ends_at = now
+        .checked_add(governance.voting_period)
+        .ok_or(AnectosError::InvalidGovernanceConfig)?;
+    proposal.status = ProposalStatus::Voting;
+    proposal.version = ACCOUNT_VERSION;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance.proposal_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+
+    emit_cpi!(ProposalCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        proposal: proposal.key(),
+        proposer,
+        id: proposal.id,
+        action,
+        voting_ends_at: proposal.voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
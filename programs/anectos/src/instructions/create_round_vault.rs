@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, create_account, CreateAccount};
-use crate::state::FundingRound;
+use crate::state::{FundingRound, RoundVaultCreated};
+use crate::constants::EVENT_SCHEMA_VERSION;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateRoundVault<'info> {
     #[account(mut)]
@@ -42,5 +44,13 @@ pub fn handler(ctx: Context<CreateRoundVault>) -> Result<()> {
 
     round.project_vault = ctx.accounts.round_vault.key(); // repurpose field as round_vault
     round.vault_bump = bump;
+
+    emit_cpi!(RoundVaultCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round_key,
+        round_vault: round.project_vault,
+        rent_lamports: lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
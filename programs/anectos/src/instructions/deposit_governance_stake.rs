@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::{GovernanceConfig, ProtocolConfig, VoterStake, VoterStakeUpdated};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_GOVERNANCE};
+use crate::error::AnectosError;
+use crate::instructions::create_proposal::read_acts_balance;
+
+/// SPL token `Transfer` instruction tag; Token-2022 keeps it for mints without extensions
+const TOKEN_TRANSFER: u8 = 3;
+
+/// Moves `amount` ACTS between two token accounts of `token_program`.
+pub fn transfer_acts<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    require_keys_eq!(*from.owner, token_program.key(), AnectosError::InvalidGovernanceTokenAccount);
+    require_keys_eq!(*to.owner, token_program.key(), AnectosError::InvalidGovernanceTokenAccount);
+    let mut data = vec![TOKEN_TRANSFER];
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = Instruction {
+        program_id: token_program.key(),
+        accounts: vec![
+            AccountMeta::new(from.key(), false),
+            AccountMeta::new(to.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[from.clone(), to.clone(), authority.clone(), token_program.clone()],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositGovernanceStake<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoterStake::INIT_SPACE,
+        seeds = [b"voter_stake", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+    /// CHECK: voter's ACTS token account, validated in `read_acts_balance`
+    #[account(mut)]
+    pub voter_token_account: UncheckedAccount<'info>,
+    /// CHECK: ACTS token account owned by `escrow_authority`, validated in `read_acts_balance`
+    #[account(mut)]
+    pub escrow_token_account: UncheckedAccount<'info>,
+    /// CHECK: PDA owning the escrowed ACTS: seeds = [b"governance_escrow"]
+    #[account(seeds = [b"governance_escrow"], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    /// CHECK: must own both token accounts, checked in `transfer_acts`
+    pub token_program: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Locks ACTS in the governance escrow, where they count as voting weight.
+/// A deposit restarts the stake's snapshot time, so it only counts on votes opened later.
+pub fn handler(ctx: Context<DepositGovernanceStake>, amount: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE, None)?;
+    require!(amount > 0, AnectosError::InsufficientGovernanceTokens);
+
+    let voter = ctx.accounts.voter.key();
+    let acts_mint = ctx.accounts.governance.acts_mint;
+    let balance = read_acts_balance(&ctx.accounts.voter_token_account.to_account_info(), &acts_mint, &voter)?;
+    require!(balance >= amount, AnectosError::InsufficientGovernanceTokens);
+    read_acts_balance(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &acts_mint,
+        &ctx.accounts.escrow_authority.key(),
+    )?;
+
+    transfer_acts(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.voter_token_account.to_account_info(),
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.voter.to_account_info(),
+        amount,
+        &[],
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let stake = &mut ctx.accounts.voter_stake;
+    if stake.voter == Pubkey::default() {
+        stake.voter = voter;
+        stake.version = ACCOUNT_VERSION;
+        stake.bump = ctx.bumps.voter_stake;
+    }
+    stake.amount = stake.amount.checked_add(amount).ok_or(AnectosError::MathOverflow)?;
+    stake.deposited_at = now;
+
+    emit_cpi!(VoterStakeUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        voter,
+        deposited: true,
+        amount,
+        total_staked: stake.amount,
+        locked_until: stake.locked_until,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use anchor_lang::{prelude::*};
-use crate::{error::AnectosError, state::{Milestone, Project}, FundingRound};
+use crate::{constants::{EVENT_SCHEMA_VERSION, PAUSE_WITHDRAWALS}, error::AnectosError, state::{record_ledger_entry, FundsWithdrawn, LedgerEntry, Milestone, Project, ProtocolConfig}, FundingRound};
 use anchor_lang::system_program::{transfer, Transfer};
 
-
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DistributeFundsToOwner<'info> {
 	#[account(mut)]
@@ -10,7 +10,7 @@ pub struct DistributeFundsToOwner<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", project.creator.as_ref()],
         bump
     )]
     pub vault: SystemAccount<'info>,
@@ -18,60 +18,94 @@ pub struct DistributeFundsToOwner<'info> {
     #[account(
         mut,
         has_one = owner,
-        seeds = [b"project", owner.key().as_ref()],
+        seeds = [b"project", project.creator.as_ref()],
         bump
     )]
 	pub project: Account<'info, Project>,
 
     #[account(
         mut,
-        has_one = owner,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
     )]
     pub funding_round: Account<'info, FundingRound>,
 
 
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>
 }
 
+/// Books `amount` leaving the project vault against donations first, then against
+/// unlocked matching. Callers check `amount` against `Project::releasable` beforehand.
+/// Returns the (donations, matching) parts.
+pub fn record_withdrawal(project: &mut Project, round: &mut FundingRound, amount: u64) -> Result<(u64, u64)> {
+    let from_donations = amount.min(project.current_funding);
+    let from_matching = amount - from_donations;
+    if from_donations > 0 {
+        record_ledger_entry(project, round, LedgerEntry::DonationWithdrawn(from_donations))?;
+    }
+    if from_matching > 0 {
+        record_ledger_entry(project, round, LedgerEntry::MatchingWithdrawn(from_matching))?;
+    }
+    Ok((from_donations, from_matching))
+}
+
 pub fn handler(ctx: Context<DistributeFundsToOwner>, amount: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(&ctx.accounts.funding_round))?;
     let project = &mut ctx.accounts.project;
     let funding_round = &mut ctx.accounts.funding_round;
     require!(project.owner == ctx.accounts.owner.key(), AnectosError::Unauthorized);
-    // Total withdrawable is contribution funds + unlocked matching
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+    require!(!project.has_payout_splits, AnectosError::PayoutSplitsConfigured);
+    require!(!project.has_team, AnectosError::TeamApprovalRequired);
+    // Total withdrawable is contribution funds + vested, unwithdrawn matching
+    let now = Clock::get()?.unix_timestamp;
+    let claimable_matching = project.claimable_matching(now);
     let total_withdrawable = (project.current_funding as u128)
-        .saturating_add(project.matching_unlocked as u128) as u64;
+        .saturating_add(claimable_matching as u128) as u64;
     require!(total_withdrawable >= amount, AnectosError::InsufficientProjectVaultFunds);
+    require!(
+        total_withdrawable.saturating_sub(project.disputed_amount) >= amount,
+        AnectosError::FundsLockedByDispute
+    );
+    require!(project.releasable(now) >= amount, AnectosError::MilestoneNotReleased);
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
 
-    // if project.milestones.get(0).map_or(false, |m| m.is_achieved) {
-        let bindings = ctx.accounts.owner.key();
-        let signer_seeds = [b"vault", bindings.as_ref(), &[ctx.bumps.vault]];
+    let vault_balance = ctx.accounts.vault.lamports();
+    require!(vault_balance >= amount, AnectosError::InsufficientProjectVaultFunds);
 
-        let vault_balance = ctx.accounts.vault.lamports();
-        require!(vault_balance >= amount, AnectosError::InsufficientProjectVaultFunds);
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        amount,
+    )?;
+    let current_funding_before = project.current_funding;
+    let matching_unlocked_before = project.matching_unlocked;
+    let (take_from_current, remaining) = record_withdrawal(project, funding_round, amount)?;
 
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.owner.to_account_info(),
-                },
-                &[&signer_seeds[..]],
-            ),
-            amount,
-        )?;
-        // Deduct from current_funding first, then from matching_unlocked
-        let mut remaining = amount;
-        let take_from_current = remaining.min(project.current_funding);
-        project.current_funding = project.current_funding.saturating_sub(take_from_current);
-        funding_round.matching_pool = funding_round.matching_pool
-            .saturating_sub(take_from_current);
-        remaining = remaining.saturating_sub(take_from_current);
-        if remaining > 0 {
-            // remaining must be <= matching_unlocked due to earlier require
-            project.matching_unlocked = project.matching_unlocked
-                .saturating_sub(remaining);
-        }
-    // }
+    emit_cpi!(FundsWithdrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        owner: project.owner,
+        amount,
+        from_donations: take_from_current,
+        from_matching: remaining,
+        current_funding_before,
+        current_funding_after: project.current_funding,
+        matching_unlocked_before,
+        matching_unlocked_after: project.matching_unlocked,
+        timestamp: now,
+    });
     Ok(())
 }
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{BPS_DENOMINATOR, EVENT_SCHEMA_VERSION, PAUSE_WITHDRAWALS},
+    error::AnectosError,
+    instructions::distribute_funds_to_owner::record_withdrawal,
+    math::pro_rata,
+    state::{FundingRound, PayoutConfig, PayoutDistributed, PayoutRecipient, Project, ProtocolConfig},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributePayout<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        address = project.round @ AnectosError::Unauthorized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"payout_config", project.key().as_ref()],
+        bump = payout_config.bump
+    )]
+    pub payout_config: Account<'info, PayoutConfig>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: the payout recipients, writable, in `payout_config` order
+}
+
+/// Splits `amount` by basis-point share; the last recipient takes the rounding dust so
+/// the parts always add up to `amount`.
+pub fn payout_shares(amount: u64, recipients: &[PayoutRecipient]) -> Result<Vec<u64>> {
+    let mut shares = Vec::with_capacity(recipients.len());
+    let mut allocated = 0u64;
+    for (i, split) in recipients.iter().enumerate() {
+        let share = if i + 1 == recipients.len() {
+            amount - allocated
+        } else {
+            pro_rata(amount, split.share_bps as u64, BPS_DENOMINATOR as u64)?
+        };
+        allocated += share;
+        shares.push(share);
+    }
+    Ok(shares)
+}
+
+/// Releases everything currently withdrawable from the project vault to the payout
+/// recipients. Permissionless: the amounts follow from the project's accounting and
+/// the recipients from its payout config.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, DistributePayout<'info>>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(&ctx.accounts.funding_round))?;
+    let project = &mut ctx.accounts.project;
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+
+    let recipients = &ctx.accounts.payout_config.recipients;
+    require!(
+        ctx.remaining_accounts.len() == recipients.len()
+            && ctx.remaining_accounts.iter().zip(recipients.iter()).all(|(account, split)| account.key() == split.recipient),
+        AnectosError::PayoutRecipientMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let total = project.releasable(now).min(ctx.accounts.vault.lamports());
+    require!(total > 0, AnectosError::NothingToClaim);
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+    let shares = payout_shares(total, recipients)?;
+    for ((account, split), amount) in ctx.remaining_accounts.iter().zip(recipients.iter()).zip(shares) {
+        if amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: account.clone(),
+                    },
+                    &[&signer_seeds[..]],
+                ),
+                amount,
+            )?;
+        }
+        emit_cpi!(PayoutDistributed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            project: project.key(),
+            recipient: split.recipient,
+            share_bps: split.share_bps,
+            amount,
+            total_released: total,
+            timestamp: now,
+        });
+    }
+
+    record_withdrawal(project, &mut ctx.accounts.funding_round, total)?;
+
+    Ok(())
+}
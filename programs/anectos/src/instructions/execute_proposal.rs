@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    FundingRound, FundingStage, FundingStageChanged, GovernanceConfig, Project, ProjectMeta,
+    Proposal, ProposalAction, ProposalFinalized, ProposalStatus, VotingMode, ProtocolConfig,
+};
+use crate::constants::{BPS_DENOMINATOR, EVENT_SCHEMA_VERSION, PAUSE_GOVERNANCE};
+use crate::error::AnectosError;
+use crate::instructions::change_project_funding_stage::apply_funding_stage;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// Required for `WhitelistProject`
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+    /// Required for `WhitelistProject`
+    #[account(mut)]
+    pub project_meta: Option<Account<'info, ProjectMeta>>,
+    /// Required for `WhitelistProject`
+    #[account(mut)]
+    pub funding_round: Option<Account<'info, FundingRound>>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Finalizes a proposal once voting has ended. Passing proposals have their action applied.
+/// Permissionless: anyone can crank a finished proposal.
+pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_GOVERNANCE, None)?;
+    let governance = &ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(proposal.status == ProposalStatus::Voting, AnectosError::ProposalAlreadyFinalized);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= proposal.voting_ends_at, AnectosError::VotingStillOpen);
+
+    let quorum = match proposal.voting_mode {
+        VotingMode::TokenWeighted => governance.quorum,
+        VotingMode::Quadratic => governance.qv_quorum,
+    };
+    let total = (proposal.yes_votes as u128) + (proposal.no_votes as u128);
+    let passed = total > 0
+        && total >= quorum as u128
+        && (proposal.yes_votes as u128) * (BPS_DENOMINATOR as u128)
+            >= total * (governance.approval_threshold_bps as u128);
+
+    if passed {
+        match proposal.action {
+            ProposalAction::WhitelistProject { round, project } => {
+                let (Some(project_acc), Some(project_meta), Some(funding_round)) = (
+                    ctx.accounts.project.as_mut(),
+                    ctx.accounts.project_meta.as_mut(),
+                    ctx.accounts.funding_round.as_mut(),
+                ) else {
+                    return err!(AnectosError::ProposalAccountsMismatch);
+                };
+                require_keys_eq!(project_acc.key(), project, AnectosError::ProposalAccountsMismatch);
+                require_keys_eq!(funding_round.key(), round, AnectosError::ProposalAccountsMismatch);
+                require_keys_eq!(project_acc.round, round, AnectosError::ProposalAccountsMismatch);
+                require_keys_eq!(project_meta.project, project, AnectosError::ProposalAccountsMismatch);
+
+                let previous_stage =
+                    apply_funding_stage(project_meta, project_acc, funding_round, FundingStage::Active);
+                emit_cpi!(FundingStageChanged {
+                    schema_version: EVENT_SCHEMA_VERSION,
+                    project,
+                    previous_stage,
+                    new_stage: FundingStage::Active,
+                    is_whitelisted: project_acc.is_whitelisted,
+                    timestamp: now,
+                });
+            }
+        }
+        proposal.status = ProposalStatus::Executed;
+    } else {
+        proposal.status = ProposalStatus::Defeated;
+    }
+
+    emit_cpi!(ProposalFinalized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        proposal: proposal.key(),
+        status: proposal.status,
+        yes_votes: proposal.yes_votes,
+        no_votes: proposal.no_votes,
+        timestamp: now,
+    });
+
+    Ok(())
+}
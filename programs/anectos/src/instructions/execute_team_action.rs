@@ -0,0 +1,254 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{EVENT_SCHEMA_VERSION, PAUSE_MILESTONES, PAUSE_WITHDRAWALS},
+    error::AnectosError,
+    instructions::{
+        configure_project_team::validate_team, distribute_funds_to_owner::record_withdrawal,
+        set_payout_splits::apply_payout_splits, submit_milestone::open_milestone_review,
+        update_project_meta::apply_meta_update,
+    },
+    state::{
+        FundingRound, FundingRoundMeta, FundsWithdrawn, MilestoneReview, PayoutConfig, Project, ProjectMeta, ProjectTeam,
+        ProjectTeamConfigured, ProtocolConfig, TeamAction, TeamActionExecuted, TeamActionProposal,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteTeamAction<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"project_team", project.key().as_ref()],
+        bump = project_team.bump
+    )]
+    pub project_team: Account<'info, ProjectTeam>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = project,
+        has_one = proposer,
+        seeds = [b"team_action", project.key().as_ref(), team_action.id.to_le_bytes().as_ref()],
+        bump = team_action.bump
+    )]
+    pub team_action: Account<'info, TeamActionProposal>,
+    /// CHECK: receives the proposal's rent; checked by `has_one` on `team_action`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// Required for `Withdraw` and `UpdateMeta`; lets `SubmitMilestone` open before the cutoff
+    #[account(
+        mut,
+        address = project.round @ AnectosError::TeamActionAccountsMismatch
+    )]
+    pub funding_round: Option<Account<'info, FundingRound>>,
+    /// Required for `Withdraw`
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: Option<SystemAccount<'info>>,
+    /// CHECK: required for `Withdraw`; must be the action's recipient
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+    /// Required for `SubmitMilestone`
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + MilestoneReview::INIT_SPACE,
+        seeds = [b"milestone_review", project.key().as_ref(), &[team_action.action.milestone_index()]],
+        bump
+    )]
+    pub milestone_review: Option<Account<'info, MilestoneReview>>,
+    /// Required for `SubmitMilestone`
+    #[account(
+        seeds = [b"funding_round_metadata", project.round.as_ref()],
+        bump
+    )]
+    pub funding_round_metadata: Option<Account<'info, FundingRoundMeta>>,
+    /// Required for `SetPayoutSplits` and `UpdateMeta`
+    #[account(
+        mut,
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump = project_meta.bump
+    )]
+    pub project_meta: Option<Account<'info, ProjectMeta>>,
+    /// Required for `SetPayoutSplits`
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + PayoutConfig::INIT_SPACE,
+        seeds = [b"payout_config", project.key().as_ref()],
+        bump
+    )]
+    pub payout_config: Option<Account<'info, PayoutConfig>>,
+    /// Round owner's co-signature for `UpdateMeta` once the project left Planning
+    pub round_owner: Option<Signer<'info>>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Executes an action once `threshold` current members approved it, then closes the
+/// proposal. Any member may execute; the checks of the matching owner instruction apply.
+pub fn handler(ctx: Context<ExecuteTeamAction>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let team = &ctx.accounts.project_team;
+    require!(team.is_member(&member), AnectosError::NotTeamMember);
+    let approvals = ctx.accounts.team_action.current_approvals(team);
+    require!(approvals >= team.threshold, AnectosError::TeamThresholdNotMet);
+
+    let action = ctx.accounts.team_action.action.clone();
+    match action.clone() {
+        TeamAction::Withdraw { amount, recipient } => {
+            let event = release_funds(ctx.accounts, ctx.bumps.vault, amount, recipient)?;
+            emit_cpi!(event);
+        }
+        TeamAction::SubmitMilestone { milestone_index } => {
+            ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, None)?;
+            let (Some(review), Some(bump), Some(round_meta)) = (
+                ctx.accounts.milestone_review.as_mut(),
+                ctx.bumps.milestone_review,
+                ctx.accounts.funding_round_metadata.as_ref(),
+            ) else {
+                return err!(AnectosError::TeamActionAccountsMismatch);
+            };
+            let round = ctx.accounts.funding_round.as_deref();
+            let event =
+                open_milestone_review(&ctx.accounts.project, review, round, round_meta, milestone_index, bump)?;
+            emit_cpi!(event);
+        }
+        TeamAction::SetPayoutSplits { recipients } => {
+            let (Some(project_meta), Some(config), Some(bump)) = (
+                ctx.accounts.project_meta.as_ref(),
+                ctx.accounts.payout_config.as_mut(),
+                ctx.bumps.payout_config,
+            ) else {
+                return err!(AnectosError::TeamActionAccountsMismatch);
+            };
+            let event = apply_payout_splits(&mut ctx.accounts.project, project_meta, config, bump, recipients)?;
+            emit_cpi!(event);
+        }
+        TeamAction::UpdateMeta { title, description, image_metadata_uri, sdg_goals } => {
+            let (Some(project_meta), Some(funding_round)) =
+                (ctx.accounts.project_meta.as_mut(), ctx.accounts.funding_round.as_ref())
+            else {
+                return err!(AnectosError::TeamActionAccountsMismatch);
+            };
+            let event = apply_meta_update(
+                ctx.accounts.project.key(),
+                project_meta,
+                funding_round,
+                ctx.accounts.round_owner.as_ref().map(|signer| signer.key()),
+                title,
+                description,
+                image_metadata_uri,
+                sdg_goals,
+            )?;
+            emit_cpi!(event);
+        }
+        TeamAction::UpdateTeam { members, threshold } => {
+            validate_team(&members, threshold)?;
+            let team = &mut ctx.accounts.project_team;
+            team.members = members.clone();
+            team.threshold = threshold;
+            emit_cpi!(ProjectTeamConfigured {
+                schema_version: EVENT_SCHEMA_VERSION,
+                project: team.project,
+                members,
+                threshold,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+    }
+
+    let proposal = &ctx.accounts.team_action;
+    emit_cpi!(TeamActionExecuted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: proposal.project,
+        team_action: proposal.key(),
+        id: proposal.id,
+        executed_by: member,
+        action,
+        approvals,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Team counterpart of `distribute_funds_to_owner`, paying the approved recipient.
+fn release_funds(
+    accounts: &mut ExecuteTeamAction,
+    vault_bump: Option<u8>,
+    amount: u64,
+    recipient: Pubkey,
+) -> Result<FundsWithdrawn> {
+    let (Some(funding_round), Some(vault), Some(recipient_account), Some(vault_bump)) = (
+        accounts.funding_round.as_mut(),
+        accounts.vault.as_ref(),
+        accounts.recipient.as_ref(),
+        vault_bump,
+    ) else {
+        return err!(AnectosError::TeamActionAccountsMismatch);
+    };
+    require_keys_eq!(recipient_account.key(), recipient, AnectosError::TeamActionAccountsMismatch);
+    accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(funding_round))?;
+
+    let project = &mut accounts.project;
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+    require!(!project.has_payout_splits, AnectosError::PayoutSplitsConfigured);
+    let now = Clock::get()?.unix_timestamp;
+    let total_withdrawable = (project.current_funding as u128)
+        .saturating_add(project.claimable_matching(now) as u128) as u64;
+    require!(total_withdrawable >= amount, AnectosError::InsufficientProjectVaultFunds);
+    require!(
+        total_withdrawable.saturating_sub(project.disputed_amount) >= amount,
+        AnectosError::FundsLockedByDispute
+    );
+    require!(project.releasable(now) >= amount, AnectosError::MilestoneNotReleased);
+    require!(vault.lamports() >= amount, AnectosError::InsufficientProjectVaultFunds);
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[vault_bump]];
+    transfer(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: recipient_account.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    let current_funding_before = project.current_funding;
+    let matching_unlocked_before = project.matching_unlocked;
+    let (from_donations, from_matching) = record_withdrawal(project, funding_round, amount)?;
+    Ok(FundsWithdrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        owner: recipient,
+        amount,
+        from_donations,
+        from_matching,
+        current_funding_before,
+        current_funding_after: project.current_funding,
+        matching_unlocked_before,
+        matching_unlocked_after: project.matching_unlocked,
+        timestamp: now,
+    })
+}
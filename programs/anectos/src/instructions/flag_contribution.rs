@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{Contribution, ContributionFlagged, FundingRound, FundingRoundMeta, Project, ProtocolConfig};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_SETTLEMENT};
+use crate::error::AnectosError;
+use crate::math::isqrt_u128;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FlagContribution<'info> {
+    /// Round owner or the round's designated reviewer
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        seeds = [b"funding_round_metadata", funding_round.key().as_ref()],
+        bump
+    )]
+    pub funding_round_metadata: Account<'info, FundingRoundMeta>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"contrib", project.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Excludes a contribution from the matching calculation without refunding it.
+/// Only allowed after the round has ended and before settlement has started.
+pub fn handler(ctx: Context<FlagContribution>, reason_code: u8) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_SETTLEMENT, Some(&ctx.accounts.funding_round))?;
+    let round = &mut ctx.accounts.funding_round;
+    let project = &mut ctx.accounts.project;
+    let contribution = &mut ctx.accounts.contribution;
+    let authority = ctx.accounts.authority.key();
+
+    require!(
+        authority == round.owner
+            || (round.reviewer != Pubkey::default() && authority == round.reviewer),
+        AnectosError::Unauthorized
+    );
+    require!(!round.settlement_started, AnectosError::SettlementAlreadyStarted);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now > ctx.accounts.funding_round_metadata.end_time,
+        AnectosError::ReviewWindowNotOpen
+    );
+    require!(!contribution.is_excluded, AnectosError::ContributionAlreadyExcluded);
+
+    // The contributor's area weight is the sum of its sqrt deltas, i.e. sqrt(total)
+    let weight = isqrt_u128(contribution.total_contributed as u128);
+    project.area = project.area.saturating_sub(weight);
+    round.area = round.area.saturating_sub(weight);
+    contribution.is_excluded = true;
+
+    emit_cpi!(ContributionFlagged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        project: project.key(),
+        contributor: contribution.contributor,
+        flagged_by: authority,
+        weight_removed: weight,
+        reason_code,
+        timestamp: now,
+    });
+
+    Ok(())
+}
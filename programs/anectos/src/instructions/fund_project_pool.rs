@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{FundingRound, Project};
+use crate::state::{FundingRound, Project, ProjectPoolFunded, ProtocolConfig};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_CONTRIBUTIONS};
 use crate::error::AnectosError;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FundProjectPool<'info> {
     #[account(mut)]
@@ -13,10 +15,16 @@ pub struct FundProjectPool<'info> {
     pub project: Account<'info, Project>,
     #[account(mut)]
     pub round_vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<FundProjectPool>, amount: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CONTRIBUTIONS, Some(&ctx.accounts.funding_round))?;
     require!(amount > 0, AnectosError::InvalidContributionAmount);
     let round = &ctx.accounts.funding_round;
     let project = &mut ctx.accounts.project;
@@ -44,10 +52,22 @@ pub fn handler(ctx: Context<FundProjectPool>, amount: u64) -> Result<()> {
     )?;
 
     // Increase the project's own matching pool budget
+    let matching_pool_before = project.matching_pool;
     project.matching_pool = project
         .matching_pool
         .checked_add(amount)
         .ok_or(AnectosError::InvalidContributionAmount)?;
 
+    emit_cpi!(ProjectPoolFunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        project: project.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        matching_pool_before,
+        matching_pool_after: project.matching_pool,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
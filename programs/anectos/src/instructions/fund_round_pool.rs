@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::FundingRound;
+use crate::state::{FundingRound, ProtocolConfig, RoundPoolFunded};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_CONTRIBUTIONS};
 use crate::error::AnectosError;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FundRoundPool<'info> {
     #[account(mut)]
@@ -11,10 +13,16 @@ pub struct FundRoundPool<'info> {
     pub funding_round: Account<'info, FundingRound>,
     #[account(mut)]
     pub round_vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<FundRoundPool>, amount: u64) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_CONTRIBUTIONS, Some(&ctx.accounts.funding_round))?;
     require!(amount > 0, AnectosError::InvalidContributionAmount);
     let round = &mut ctx.accounts.funding_round;
 
@@ -38,10 +46,21 @@ pub fn handler(ctx: Context<FundRoundPool>, amount: u64) -> Result<()> {
     )?;
 
     // Increment declared pool to stay in sync with vault deposits
+    let matching_pool_before = round.matching_pool;
     round.matching_pool = round
         .matching_pool
         .checked_add(amount)
         .ok_or(AnectosError::InvalidContributionAmount)?;
 
+    emit_cpi!(RoundPoolFunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        matching_pool_before,
+        matching_pool_after: round.matching_pool,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
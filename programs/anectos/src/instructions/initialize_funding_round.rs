@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::{state::FundingRound, FundingRoundMeta};
+use crate::{constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION}, state::{FundingRound, Ledger, RoundInitialized}, validation::{validate_round_window, validate_uri}, FundingRoundMeta};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeFundingRound<'info> {
 	#[account(mut)]
@@ -29,9 +30,12 @@ pub fn handler(
 	end_time: i64,
 	nft_metadata_uri: String,
 ) -> Result<()> {
+	validate_uri(&nft_metadata_uri)?;
+	validate_round_window(start_time, end_time, Clock::get()?.unix_timestamp)?;
 	let round = &mut ctx.accounts.funding_round;
 	let round_meta = &mut ctx.accounts.funding_round_metadata;
 
+	round.version = ACCOUNT_VERSION;
 	round.owner = ctx.accounts.owner.key();
 	round.matching_pool = matching_pool;
 	round.total_donations = 0;
@@ -43,10 +47,29 @@ pub fn handler(
 	round.is_active = true;
 	round.fee = 0;
     round.pool_distributed = 0;
+    round.ledger = Ledger::default();
+    round.reviewer = Pubkey::default();
+    round.arbitrator = Pubkey::default();
+    round.settlement_started = false;
+    round.paused_groups = 0;
+    round.rollover_round = Pubkey::default();
+    round.vesting_cliff_secs = 0;
+    round.vesting_duration_secs = 0;
 
+	round_meta.version = ACCOUNT_VERSION;
 	round_meta.nft_metadata_uri = nft_metadata_uri;
 	round_meta.start_time = start_time;
 	round_meta.end_time = end_time;
 
+	emit_cpi!(RoundInitialized {
+		schema_version: EVENT_SCHEMA_VERSION,
+		round: round.key(),
+		owner: round.owner,
+		matching_pool,
+		start_time,
+		end_time,
+		timestamp: Clock::get()?.unix_timestamp,
+	});
+
 	Ok(())
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use crate::state::{GovernanceConfig, GovernanceConfigUpdated};
+use crate::constants::{ACCOUNT_VERSION, BPS_DENOMINATOR, EVENT_SCHEMA_VERSION};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// Singleton governance config: seeds = [b"governance"]
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GovernanceConfig::INIT_SPACE,
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    /// Only the program's upgrade authority may create the config
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ AnectosError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeGovernance>,
+    acts_mint: Pubkey,
+    proposal_threshold: u64,
+    voting_period: i64,
+    quorum: u64,
+    approval_threshold_bps: u16,
+) -> Result<()> {
+    require!(voting_period > 0, AnectosError::InvalidGovernanceConfig);
+    require!(
+        approval_threshold_bps > 0 && approval_threshold_bps <= BPS_DENOMINATOR,
+        AnectosError::InvalidGovernanceConfig
+    );
+
+    let governance = &mut ctx.accounts.governance;
+    governance.admin = ctx.accounts.admin.key();
+    governance.acts_mint = acts_mint;
+    governance.proposal_threshold = proposal_threshold;
+    governance.voting_period = voting_period;
+    governance.quorum = quorum;
+    governance.approval_threshold_bps = approval_threshold_bps;
+    governance.proposal_count = 0;
+    governance.voice_credits_per_epoch = 0;
+    governance.qv_epoch_duration = 0;
+    governance.qv_quorum = 0;
+    governance.qv_min_stake = 0;
+    governance.version = ACCOUNT_VERSION;
+    governance.bump = ctx.bumps.governance;
+
+    emit_cpi!(GovernanceConfigUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        admin: governance.admin,
+        acts_mint: governance.acts_mint,
+        proposal_threshold: governance.proposal_threshold,
+        voting_period: governance.voting_period,
+        quorum: governance.quorum,
+        approval_threshold_bps: governance.approval_threshold_bps,
+        voice_credits_per_epoch: governance.voice_credits_per_epoch,
+        qv_epoch_duration: governance.qv_epoch_duration,
+        qv_quorum: governance.qv_quorum,
+        qv_min_stake: governance.qv_min_stake,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use crate::state::{GuardiansUpdated, ProtocolConfig};
+use crate::constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, MAX_GUARDIANS};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// Only the program's upgrade authority may create the config
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ AnectosError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Validates a guardian set: at most MAX_GUARDIANS unique keys and a reachable threshold.
+pub fn validate_guardians(guardians: &[Pubkey], guardian_threshold: u8) -> Result<()> {
+    require!(guardians.len() <= MAX_GUARDIANS as usize, AnectosError::InvalidPauseConfig);
+    require!(
+        guardian_threshold as usize <= guardians.len(),
+        AnectosError::InvalidPauseConfig
+    );
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(!guardians[..i].contains(guardian), AnectosError::InvalidPauseConfig);
+    }
+    Ok(())
+}
+
+pub fn handler(
+    ctx: Context<InitializeProtocolConfig>,
+    guardians: Vec<Pubkey>,
+    guardian_threshold: u8,
+) -> Result<()> {
+    validate_guardians(&guardians, guardian_threshold)?;
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.admin = ctx.accounts.admin.key();
+    config.paused_groups = 0;
+    config.guardians = guardians;
+    config.guardian_threshold = guardian_threshold;
+    config.version = ACCOUNT_VERSION;
+    config.bump = ctx.bumps.protocol_config;
+
+    emit_cpi!(GuardiansUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        guardians: config.guardians.clone(),
+        previous_threshold: 0,
+        guardian_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::AnectosError,
+    migration::LegacyLayout,
+    state::AccountMigrated,
+};
+
+/// Shared by every `migrate_*` instruction. Migration only changes the layout of
+/// an account, never its contents, so anyone willing to pay the extra rent may run it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: discriminator and legacy layout are verified by the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates an account whose new fields only depend on its own legacy contents.
+pub fn handler<L: LegacyLayout>(ctx: Context<MigrateAccount>) -> Result<()> {
+    let (_, event) = rewrite::<L>(
+        &ctx.accounts.payer,
+        &ctx.accounts.account,
+        &ctx.accounts.system_program,
+    )?;
+    emit_cpi!(event);
+    Ok(())
+}
+
+/// Rewrites an account stored in layout `L` into the current layout: grows it,
+/// tops up rent from the payer and fills the new fields with defaults. Returns the
+/// migrated account and the event for the caller to emit.
+pub fn rewrite<'info, L: LegacyLayout>(
+    payer: &Signer<'info>,
+    account: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<(L::Current, AccountMigrated)> {
+    let account = account.to_account_info();
+    let discriminator = L::Current::DISCRIMINATOR;
+    let current_size = discriminator.len() + L::Current::INIT_SPACE;
+
+    let migrated = {
+        let data = account.try_borrow_data()?;
+        require!(data.starts_with(discriminator), ErrorCode::AccountDiscriminatorMismatch);
+        require!(data.len() != current_size, AnectosError::AccountAlreadyMigrated);
+        require!(data.len() == L::account_size(), AnectosError::UnknownAccountLayout);
+        let legacy = L::deserialize(&mut &data[discriminator.len()..])
+            .map_err(|_| error!(AnectosError::UnknownAccountLayout))?;
+        L::Current::from(legacy)
+    };
+
+    let rent_topped_up = Rent::get()?
+        .minimum_balance(current_size)
+        .saturating_sub(account.lamports());
+    if rent_topped_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_topped_up,
+        )?;
+    }
+
+    account.realloc(current_size, true)?;
+    migrated.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    let event = AccountMigrated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        account: account.key(),
+        from_version: L::VERSION,
+        to_version: ACCOUNT_VERSION,
+        new_size: current_size as u32,
+        rent_topped_up,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    Ok((migrated, event))
+}
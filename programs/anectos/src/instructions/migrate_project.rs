@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{
+    error::AnectosError,
+    instructions::{close_contribution::load_if_open, migrate_account::rewrite},
+    migration::ProjectV0,
+    state::FundingRound,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateProject<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: discriminator and legacy layout are verified by the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+    /// CHECK: the project's round, possibly already closed; read via `load_if_open`.
+    /// An open round must be migrated first.
+    #[account(mut)]
+    pub funding_round: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a pre-versioning project and adds its opening ledger to its round, so the
+/// round ledger stays the sum of its projects' ledgers. Nothing is added to a round
+/// that was already closed.
+pub fn handler(ctx: Context<MigrateProject>) -> Result<()> {
+    let (project, event) = rewrite::<ProjectV0>(
+        &ctx.accounts.payer,
+        &ctx.accounts.account,
+        &ctx.accounts.system_program,
+    )?;
+    let round_info = ctx.accounts.funding_round.to_account_info();
+    require_keys_eq!(round_info.key(), project.round, AnectosError::Unauthorized);
+    if let Some(mut round) = load_if_open::<FundingRound>(&round_info)? {
+        round.ledger.absorb(&project.ledger)?;
+        round.refresh_balances();
+        round.try_serialize(&mut &mut round_info.try_borrow_mut_data()?[..])?;
+    }
+    emit_cpi!(event);
+    Ok(())
+}
@@ -11,6 +11,47 @@ pub mod set_matching_pool;
 pub mod fund_round_pool;
 pub mod fund_project_pool;
 pub mod set_area_max;
+pub mod set_round_reviewer;
+pub mod flag_contribution;
+pub mod initialize_governance;
+pub mod create_proposal;
+pub mod cast_vote;
+pub mod execute_proposal;
+pub mod configure_quadratic_voting;
+pub mod cast_quadratic_vote;
+pub mod submit_milestone;
+pub mod vote_milestone;
+pub mod claim_refund;
+pub mod set_round_arbitrator;
+pub mod challenge_milestone;
+pub mod resolve_challenge;
+pub mod audit_round;
+pub mod initialize_protocol_config;
+pub mod set_pause;
+pub mod configure_guardians;
+pub mod set_round_vesting;
+pub mod claim_vested;
+pub mod cancel_vesting;
+pub mod set_milestone_deadlines;
+pub mod set_round_rollover;
+pub mod clawback_expired_milestones;
+pub mod migrate_account;
+pub mod migrate_project;
+pub mod update_project_meta;
+pub mod propose_project_owner;
+pub mod accept_project_owner;
+pub mod set_payout_splits;
+pub mod distribute_payout;
+pub mod configure_project_team;
+pub mod propose_team_action;
+pub mod approve_team_action;
+pub mod execute_team_action;
+pub mod close_contribution;
+pub mod close_project;
+pub mod withdraw_contribution;
+pub mod contribute_batch;
+pub mod deposit_governance_stake;
+pub mod withdraw_governance_stake;
 
 pub use change_project_funding_stage::*;
 pub use complete_milestone::*;
@@ -25,3 +66,44 @@ pub use set_matching_pool::*;
 pub use fund_round_pool::*;
 pub use fund_project_pool::*;
 pub use set_area_max::*;
+pub use set_round_reviewer::*;
+pub use flag_contribution::*;
+pub use initialize_governance::*;
+pub use create_proposal::*;
+pub use cast_vote::*;
+pub use execute_proposal::*;
+pub use configure_quadratic_voting::*;
+pub use cast_quadratic_vote::*;
+pub use submit_milestone::*;
+pub use vote_milestone::*;
+pub use claim_refund::*;
+pub use set_round_arbitrator::*;
+pub use challenge_milestone::*;
+pub use resolve_challenge::*;
+pub use audit_round::*;
+pub use initialize_protocol_config::*;
+pub use set_pause::*;
+pub use configure_guardians::*;
+pub use set_round_vesting::*;
+pub use claim_vested::*;
+pub use cancel_vesting::*;
+pub use set_milestone_deadlines::*;
+pub use set_round_rollover::*;
+pub use clawback_expired_milestones::*;
+pub use migrate_account::*;
+pub use migrate_project::*;
+pub use update_project_meta::*;
+pub use propose_project_owner::*;
+pub use accept_project_owner::*;
+pub use set_payout_splits::*;
+pub use distribute_payout::*;
+pub use configure_project_team::*;
+pub use propose_team_action::*;
+pub use approve_team_action::*;
+pub use execute_team_action::*;
+pub use close_contribution::*;
+pub use close_project::*;
+pub use withdraw_contribution::*;
+pub use contribute_batch::*;
+pub use deposit_governance_stake::*;
+pub use withdraw_governance_stake::*;
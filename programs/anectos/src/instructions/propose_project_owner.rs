@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::AnectosError,
+    state::{Project, ProjectOwnerProposed, ProjectOwnerTransfer},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeProjectOwner<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ProjectOwnerTransfer::INIT_SPACE,
+        seeds = [b"owner_transfer", project.key().as_ref()],
+        bump
+    )]
+    pub owner_transfer: Account<'info, ProjectOwnerTransfer>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Nominates `new_owner`, who takes over once they accept. Proposing again replaces
+/// the nominee.
+pub fn handler(ctx: Context<ProposeProjectOwner>, new_owner: Pubkey) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    require!(!ctx.accounts.project.has_team, AnectosError::TeamApprovalRequired);
+    require!(
+        new_owner != owner && new_owner != Pubkey::default(),
+        AnectosError::InvalidNewOwner
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let transfer = &mut ctx.accounts.owner_transfer;
+    transfer.version = ACCOUNT_VERSION;
+    transfer.project = ctx.accounts.project.key();
+    transfer.proposed_by = owner;
+    transfer.new_owner = new_owner;
+    transfer.proposed_at = now;
+    transfer.bump = ctx.bumps.owner_transfer;
+
+    emit_cpi!(ProjectOwnerProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: transfer.project,
+        owner,
+        new_owner,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION},
+    error::AnectosError,
+    instructions::{configure_project_team::validate_team, set_payout_splits::validate_payout_splits},
+    state::{Project, ProjectTeam, TeamAction, TeamActionProposal, TeamActionProposed},
+    validation::validate_project_metadata,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeTeamAction<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    #[account(
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"project_team", project.key().as_ref()],
+        bump = project_team.bump
+    )]
+    pub project_team: Account<'info, ProjectTeam>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + TeamActionProposal::INIT_SPACE,
+        seeds = [b"team_action", project.key().as_ref(), project_team.action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub team_action: Account<'info, TeamActionProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Records `action` for the team to approve; proposing counts as the proposer's
+/// approval. Arguments are checked up front so the team never approves an action
+/// that cannot execute for static reasons.
+pub fn handler(ctx: Context<ProposeTeamAction>, action: TeamAction) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let team = &mut ctx.accounts.project_team;
+    require!(team.is_member(&member), AnectosError::NotTeamMember);
+
+    match &action {
+        TeamAction::Withdraw { amount, .. } => require!(*amount > 0, AnectosError::NothingToClaim),
+        TeamAction::SubmitMilestone { .. } => {}
+        TeamAction::SetPayoutSplits { recipients } => validate_payout_splits(recipients)?,
+        TeamAction::UpdateMeta { title, description, image_metadata_uri, sdg_goals } => {
+            validate_project_metadata(title, description, image_metadata_uri, sdg_goals.clone())?;
+        }
+        TeamAction::UpdateTeam { members, threshold } => validate_team(members, *threshold)?,
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.team_action;
+    proposal.version = ACCOUNT_VERSION;
+    proposal.project = team.project;
+    proposal.id = team.action_count;
+    proposal.proposer = member;
+    proposal.action = action.clone();
+    proposal.approvals = vec![member];
+    proposal.proposed_at = now;
+    proposal.bump = ctx.bumps.team_action;
+
+    team.action_count = team.action_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+
+    emit_cpi!(TeamActionProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: proposal.project,
+        team_action: proposal.key(),
+        id: proposal.id,
+        proposer: member,
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
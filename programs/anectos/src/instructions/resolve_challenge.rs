@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{CHALLENGE_REWARD_BPS, EVENT_SCHEMA_VERSION, PAUSE_MILESTONES},
+    error::AnectosError,
+    instructions::complete_milestone::record_milestone_failure,
+    math::bps_of,
+    state::{
+        record_ledger_entry, ChallengeResolved, ChallengeStatus, FundingRound, LedgerEntry,
+        MilestoneChallenge, MilestoneReview, Project, ProjectMeta, ProtocolConfig,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct ResolveChallenge<'info> {
+    /// Round owner or the round's arbitrator
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"milestone_review", project.key().as_ref(), &[milestone_index]],
+        bump = milestone_review.bump
+    )]
+    pub milestone_review: Account<'info, MilestoneReview>,
+    #[account(
+        mut,
+        close = challenger,
+        has_one = project,
+        has_one = challenger,
+        seeds = [b"challenge", milestone_review.key().as_ref(), &[milestone_review.attempt]],
+        bump = challenge.bump
+    )]
+    pub challenge: Account<'info, MilestoneChallenge>,
+    #[account(mut)]
+    pub challenger: SystemAccount<'info>,
+    /// project vault PDA (seeds = [b"vault", project.creator])
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Settles a milestone dispute. If upheld, the challenger gets the bond back plus a
+/// reward from the project vault and the milestone reverts to unachieved. Otherwise
+/// the bond is forfeited to the project vault and tracked in `bonds_forfeited`, apart
+/// from donations. Either way the challenge account is closed and its rent returned
+/// to the challenger.
+pub fn handler(ctx: Context<ResolveChallenge>, milestone_index: u8, upheld: bool) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, Some(&ctx.accounts.funding_round))?;
+    let round = &mut ctx.accounts.funding_round;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == round.owner
+            || (round.arbitrator != Pubkey::default() && authority == round.arbitrator),
+        AnectosError::Unauthorized
+    );
+
+    let project = &mut ctx.accounts.project;
+    let review = &mut ctx.accounts.milestone_review;
+    let challenge = &mut ctx.accounts.challenge;
+    require!(challenge.status == ChallengeStatus::Open, AnectosError::ChallengeNotOpen);
+
+    let now = Clock::get()?.unix_timestamp;
+    let milestone_amount = project.milestones[milestone_index as usize].amount;
+    project.disputed_amount = project.disputed_amount.saturating_sub(milestone_amount);
+    review.challenge_open = false;
+
+    // Release the escrowed bond from the program-owned challenge account
+    let bond = challenge.bond;
+    let bond_recipient = if upheld {
+        ctx.accounts.challenger.to_account_info()
+    } else {
+        ctx.accounts.vault.to_account_info()
+    };
+    **challenge.to_account_info().try_borrow_mut_lamports()? -= bond;
+    **bond_recipient.try_borrow_mut_lamports()? += bond;
+
+    let mut reward = 0u64;
+    if upheld {
+        let rent_floor = Rent::get()?.minimum_balance(0);
+        let available = ctx.accounts.vault.lamports().saturating_sub(rent_floor);
+        reward = bps_of(milestone_amount, CHALLENGE_REWARD_BPS)?
+            .min(available)
+            .min(project.current_funding);
+
+        if reward > 0 {
+            let creator = project.creator;
+            let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: ctx.accounts.challenger.to_account_info(),
+                    },
+                    &[&signer_seeds[..]],
+                ),
+                reward,
+            )?;
+            // The reward comes out of donations and is booked like a refund
+            record_ledger_entry(project, round, LedgerEntry::RefundPaid(reward))?;
+        }
+
+        project.milestones[milestone_index as usize].is_achieved = false;
+        project.milestones[milestone_index as usize].released_at = 0;
+        challenge.status = ChallengeStatus::Upheld;
+        let rejected = record_milestone_failure(
+            project,
+            &mut ctx.accounts.project_meta,
+            review,
+            ctx.accounts.vault.lamports(),
+            now,
+        )?;
+        emit_cpi!(rejected);
+    } else {
+        project.bonds_forfeited = project.bonds_forfeited.checked_add(bond).ok_or(AnectosError::LedgerOverflow)?;
+        project.milestones[milestone_index as usize].released_at = review.challenge_ends_at;
+        challenge.status = ChallengeStatus::Dismissed;
+    }
+
+    challenge.resolved_at = now;
+    challenge.resolved_by = authority;
+
+    emit_cpi!(ChallengeResolved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        milestone_index,
+        challenger: challenge.challenger,
+        upheld,
+        reward,
+        resolved_by: authority,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::FundingRound;
+use crate::state::{AreaMaxUpdated, FundingRound};
+use crate::constants::EVENT_SCHEMA_VERSION;
 use crate::error::AnectosError;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetAreaMax<'info> {
     #[account(mut)]
@@ -12,6 +14,15 @@ pub struct SetAreaMax<'info> {
 
 pub fn handler(ctx: Context<SetAreaMax>, area_max: u128) -> Result<()> {
     let round = &mut ctx.accounts.funding_round;
+    let area_max_before = round.area_max;
     round.area_max = area_max;
+
+    emit_cpi!(AreaMaxUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        area_max_before,
+        area_max_after: area_max,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
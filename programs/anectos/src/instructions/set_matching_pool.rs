@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::FundingRound;
+use crate::state::{FundingRound, MatchingPoolSynced};
+use crate::constants::EVENT_SCHEMA_VERSION;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetMatchingPool<'info> {
     #[account(mut)]
@@ -32,6 +34,15 @@ pub fn handler(ctx: Context<SetMatchingPool>) -> Result<()> {
     );
 
     let bal = ctx.accounts.round_vault.lamports();
-    round.matching_pool = bal as u64;
+    let matching_pool_before = round.matching_pool;
+    round.matching_pool = bal;
+
+    emit_cpi!(MatchingPoolSynced {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        matching_pool_before,
+        matching_pool_after: bal,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     Ok(())
 }
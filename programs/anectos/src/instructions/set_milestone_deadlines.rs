@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{FundingStage, MilestoneDeadlinesSet, Project, ProjectMeta};
+use crate::constants::EVENT_SCHEMA_VERSION;
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMilestoneDeadlines<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+}
+
+/// Sets a due date for every milestone. Only allowed while the project is in Planning,
+/// so sponsors know the schedule before the project is whitelisted.
+pub fn handler(ctx: Context<SetMilestoneDeadlines>, due_dates: Vec<i64>) -> Result<()> {
+    require!(!ctx.accounts.project.has_team, AnectosError::TeamApprovalRequired);
+    require!(
+        ctx.accounts.project_meta.funding_stage == FundingStage::Planning,
+        AnectosError::InvalidFundingStageStatus
+    );
+
+    let project = &mut ctx.accounts.project;
+    require!(
+        due_dates.len() == project.milestones.len(),
+        AnectosError::InvalidMilestoneDeadlines
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let mut previous = now;
+    for due_date in &due_dates {
+        require!(*due_date > previous, AnectosError::InvalidMilestoneDeadlines);
+        previous = *due_date;
+    }
+
+    for (milestone, due_date) in project.milestones.iter_mut().zip(due_dates.iter()) {
+        milestone.due_date = *due_date;
+    }
+
+    emit_cpi!(MilestoneDeadlinesSet {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        due_dates,
+        timestamp: now,
+    });
+    Ok(())
+}
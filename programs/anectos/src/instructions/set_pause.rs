@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{FundingRound, PauseUpdated, ProtocolConfig};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_ALL};
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// When supplied, the pause applies to this round only
+    #[account(mut)]
+    pub funding_round: Option<Account<'info, FundingRound>>,
+    // remaining_accounts: guardian signers when a guardian threshold is configured
+}
+
+/// Replaces the paused instruction groups (see `constants::PAUSE_*`) globally or for
+/// one round. Passing 0 unpauses everything in that scope.
+pub fn handler(ctx: Context<SetPause>, paused_groups: u8) -> Result<()> {
+    require!(paused_groups & !PAUSE_ALL == 0, AnectosError::InvalidPauseConfig);
+
+    let config = &mut ctx.accounts.protocol_config;
+    config.require_guardian_approval(ctx.remaining_accounts)?;
+
+    let (scope, previous_groups) = match ctx.accounts.funding_round.as_mut() {
+        Some(round) => {
+            let previous = round.paused_groups;
+            round.paused_groups = paused_groups;
+            (round.key(), previous)
+        }
+        None => {
+            let previous = config.paused_groups;
+            config.paused_groups = paused_groups;
+            (Pubkey::default(), previous)
+        }
+    };
+
+    emit_cpi!(PauseUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        scope,
+        previous_groups,
+        paused_groups,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, BPS_DENOMINATOR, EVENT_SCHEMA_VERSION, MAX_PAYOUT_RECIPIENTS},
+    error::AnectosError,
+    state::{FundingStage, PayoutConfig, PayoutRecipient, PayoutSplitsSet, Project, ProjectMeta},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPayoutSplits<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump = project_meta.bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + PayoutConfig::INIT_SPACE,
+        seeds = [b"payout_config", project.key().as_ref()],
+        bump
+    )]
+    pub payout_config: Account<'info, PayoutConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// 1 to `MAX_PAYOUT_RECIPIENTS` distinct, non-default recipients whose non-zero
+/// shares sum to `BPS_DENOMINATOR`.
+pub fn validate_payout_splits(recipients: &[PayoutRecipient]) -> Result<()> {
+    require!(
+        !recipients.is_empty() && recipients.len() <= MAX_PAYOUT_RECIPIENTS as usize,
+        AnectosError::InvalidPayoutSplits
+    );
+    let mut total: u32 = 0;
+    for (i, split) in recipients.iter().enumerate() {
+        require!(
+            split.share_bps > 0
+                && split.recipient != Pubkey::default()
+                && recipients[..i].iter().all(|other| other.recipient != split.recipient),
+            AnectosError::InvalidPayoutSplits
+        );
+        total += split.share_bps as u32;
+    }
+    require!(total == BPS_DENOMINATOR as u32, AnectosError::InvalidPayoutSplits);
+    Ok(())
+}
+
+/// Sets or replaces the project's payout splits. Once set, released funds only leave
+/// the vault through `distribute_payout`; the splits are fixed after Planning so donors
+/// know who is paid.
+pub fn handler(ctx: Context<SetPayoutSplits>, recipients: Vec<PayoutRecipient>) -> Result<()> {
+    require!(!ctx.accounts.project.has_team, AnectosError::TeamApprovalRequired);
+    let event = apply_payout_splits(
+        &mut ctx.accounts.project,
+        &ctx.accounts.project_meta,
+        &mut ctx.accounts.payout_config,
+        ctx.bumps.payout_config,
+        recipients,
+    )?;
+    emit_cpi!(event);
+    Ok(())
+}
+
+/// Validates and stores `recipients`. Shared with team-approved changes; the caller
+/// emits the returned event.
+pub fn apply_payout_splits(
+    project: &mut Account<Project>,
+    project_meta: &ProjectMeta,
+    config: &mut PayoutConfig,
+    bump: u8,
+    recipients: Vec<PayoutRecipient>,
+) -> Result<PayoutSplitsSet> {
+    require!(
+        project_meta.funding_stage == FundingStage::Planning,
+        AnectosError::PayoutSplitsLocked
+    );
+    validate_payout_splits(&recipients)?;
+
+    project.has_payout_splits = true;
+    config.version = ACCOUNT_VERSION;
+    config.project = project.key();
+    config.recipients = recipients.clone();
+    config.bump = bump;
+
+    Ok(PayoutSplitsSet {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: config.project,
+        recipients,
+        timestamp: Clock::get()?.unix_timestamp,
+    })
+}
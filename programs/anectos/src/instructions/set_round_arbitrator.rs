@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::FundingRound;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRoundArbitrator<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub funding_round: Account<'info, FundingRound>,
+}
+
+/// Delegates milestone dispute resolution for this round to `arbitrator`.
+/// Pass `Pubkey::default()` to leave disputes to the round owner only.
+pub fn handler(ctx: Context<SetRoundArbitrator>, arbitrator: Pubkey) -> Result<()> {
+    let round = &mut ctx.accounts.funding_round;
+    round.arbitrator = arbitrator;
+
+    emit_cpi!(round.settings_updated(round.key(), Clock::get()?.unix_timestamp));
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::FundingRound;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRoundReviewer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub funding_round: Account<'info, FundingRound>,
+}
+
+/// Delegates contribution review for this round to `reviewer`.
+/// Pass `Pubkey::default()` to leave reviewing to the round owner only.
+pub fn handler(ctx: Context<SetRoundReviewer>, reviewer: Pubkey) -> Result<()> {
+    let round = &mut ctx.accounts.funding_round;
+    round.reviewer = reviewer;
+
+    emit_cpi!(round.settings_updated(round.key(), Clock::get()?.unix_timestamp));
+    Ok(())
+}
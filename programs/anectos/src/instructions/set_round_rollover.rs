@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::state::FundingRound;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRoundRollover<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub funding_round: Account<'info, FundingRound>,
+}
+
+/// Routes matching clawed back from this round's projects into `rollover_round`'s pool.
+/// Pass `Pubkey::default()` to keep clawbacks in this round's vault.
+pub fn handler(ctx: Context<SetRoundRollover>, rollover_round: Pubkey) -> Result<()> {
+    let round = &mut ctx.accounts.funding_round;
+    round.rollover_round = rollover_round;
+
+    emit_cpi!(round.settings_updated(round.key(), Clock::get()?.unix_timestamp));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::FundingRound;
+use crate::error::AnectosError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRoundVesting<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut, has_one = owner)]
+    pub funding_round: Account<'info, FundingRound>,
+}
+
+/// Configures how settled matching vests for projects in this round.
+/// Must be set before settlement starts; a duration of 0 releases matching immediately.
+pub fn handler(
+    ctx: Context<SetRoundVesting>,
+    vesting_cliff_secs: i64,
+    vesting_duration_secs: i64,
+) -> Result<()> {
+    let round = &mut ctx.accounts.funding_round;
+    require!(!round.settlement_started, AnectosError::SettlementAlreadyStarted);
+    require!(
+        vesting_cliff_secs >= 0
+            && vesting_duration_secs >= 0
+            && vesting_cliff_secs <= vesting_duration_secs,
+        AnectosError::InvalidVestingSchedule
+    );
+
+    round.vesting_cliff_secs = vesting_cliff_secs;
+    round.vesting_duration_secs = vesting_duration_secs;
+
+    emit_cpi!(round.settings_updated(round.key(), Clock::get()?.unix_timestamp));
+    Ok(())
+}
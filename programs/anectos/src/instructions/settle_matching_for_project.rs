@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
-use crate::state::{FundingRound, Project};
+use crate::state::{record_ledger_entry, FundingRound, LedgerEntry, MatchingSettled, Project, ProtocolConfig};
+use crate::constants::{EVENT_SCHEMA_VERSION, PAUSE_SETTLEMENT};
 use crate::error::AnectosError;
+use crate::math::matching_allocation;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SettleMatchingForProject<'info> {
     #[account(mut)]
@@ -12,15 +15,29 @@ pub struct SettleMatchingForProject<'info> {
     /// round vault PDA
     #[account(mut)]
     pub round_vault: SystemAccount<'info>,
-    #[account(mut, has_one = owner)]
+    #[account(
+        mut,
+        has_one = owner,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
     pub project: Account<'info, Project>,
-    /// project vault PDA (seeds = [b"vault", owner])
-    #[account(mut)]
+    /// project vault PDA (seeds = [b"vault", project.creator])
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
     pub project_vault: SystemAccount<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<SettleMatchingForProject>) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_SETTLEMENT, Some(&ctx.accounts.funding_round))?;
     let round = &mut ctx.accounts.funding_round;
     let project = &mut ctx.accounts.project;
 
@@ -31,38 +48,24 @@ pub fn handler(ctx: Context<SettleMatchingForProject>) -> Result<()> {
     );
     require_keys_eq!(expected_vault, ctx.accounts.round_vault.key(), AnectosError::Unauthorized);
 
-    let round_area = round.area; // sum of sqrt contributions
-    let target_area = if round.area_max > 0 { round.area_max } else { round.area };
-    let denom_area = core::cmp::max(round_area, target_area);
-    let proj_area = project.area;
-    // Per-project matching: use the project's own matching pool budget
-    let pool_total = project.matching_pool as u128;
+    // Once settlement begins the contribution set is frozen (no more flagging)
+    round.settlement_started = true;
 
-    if round_area == 0 || proj_area == 0 || pool_total == 0 {
+    // Per-project matching: use the project's own matching pool budget
+    let alloc = matching_allocation(project.matching_pool, project.area, round.area, round.area_max)?;
+    if alloc == 0 {
         return Ok(()); // nothing to do
     }
 
-    // alloc = M * (area_i^2) / sum_j(area_j^2)
-    let num = proj_area
-        .checked_mul(proj_area)
-        .ok_or(AnectosError::InvalidContributionAmount)?;
-    let den = denom_area
-        .checked_mul(denom_area)
-        .ok_or(AnectosError::InvalidContributionAmount)?;
-    if den == 0 { return Ok(()); }
-    let alloc = pool_total
-        .checked_mul(num)
-        .ok_or(AnectosError::InvalidContributionAmount)?
-        / den;
-
-    let already = project.matching_unlocked as u128;
+    // Compare against everything ever settled, not what is still unwithdrawn
+    let already = project.ledger.matching_allocated as u128;
     if alloc <= already { return Ok(()); }
     let mut delta = alloc - already;
 
     // Clamp to vault balance and pool remaining
     let rv_balance = ctx.accounts.round_vault.lamports() as u128;
     let pool_remaining = (project.matching_pool as u128)
-        .saturating_sub(project.pool_distributed as u128);
+        .saturating_sub(project.ledger.matching_allocated as u128);
     delta = delta.min(rv_balance).min(pool_remaining);
     if delta == 0 { return Ok(()); }
 
@@ -85,7 +88,27 @@ pub fn handler(ctx: Context<SettleMatchingForProject>) -> Result<()> {
         delta as u64,
     )?;
 
-    project.matching_unlocked = project.matching_unlocked.saturating_add(delta as u64);
-    project.pool_distributed = project.pool_distributed.saturating_add(delta as u64);
+    // The vesting schedule is fixed by the project's first settlement
+    let now = Clock::get()?.unix_timestamp;
+    if project.vesting_start == 0 {
+        project.vesting_start = now;
+        project.vesting_cliff = now.saturating_add(round.vesting_cliff_secs);
+        project.vesting_end = now.saturating_add(round.vesting_duration_secs);
+    }
+
+    let matching_allocated_before = project.ledger.matching_allocated;
+    record_ledger_entry(project, round, LedgerEntry::MatchingAllocated(delta as u64))?;
+
+    emit_cpi!(MatchingSettled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round: round.key(),
+        project: project.key(),
+        amount: delta as u64,
+        matching_allocated_before,
+        matching_allocated_after: project.ledger.matching_allocated,
+        round_vault_before: rv_balance as u64,
+        round_vault_after: ctx.accounts.round_vault.lamports(),
+        timestamp: now,
+    });
     Ok(())
 }
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, MAX_MILESTONE_FAILURES, MILESTONE_VOTING_PERIOD, PAUSE_MILESTONES},
+    error::AnectosError,
+    instructions::{close_contribution::load_if_open, withdraw_contribution::withdrawals_open},
+    state::{
+        FundingRound, FundingRoundMeta, MilestoneReview, MilestoneReviewStatus, MilestoneSubmitted, Project,
+        ProtocolConfig,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct SubmitMilestone<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + MilestoneReview::INIT_SPACE,
+        seeds = [b"milestone_review", project.key().as_ref(), &[milestone_index]],
+        bump
+    )]
+    pub milestone_review: Account<'info, MilestoneReview>,
+    /// CHECK: the project's round, possibly already closed; read via `load_if_open`
+    #[account(address = project.round @ AnectosError::Unauthorized)]
+    pub funding_round: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"funding_round_metadata", project.round.as_ref()],
+        bump
+    )]
+    pub funding_round_metadata: Account<'info, FundingRoundMeta>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens (or reopens after a rejection) a contributor vote on a milestone.
+pub fn handler(ctx: Context<SubmitMilestone>, milestone_index: u8) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, None)?;
+    require!(!ctx.accounts.project.has_team, AnectosError::TeamApprovalRequired);
+    let round = load_if_open::<FundingRound>(&ctx.accounts.funding_round)?;
+    let event = open_milestone_review(
+        &ctx.accounts.project,
+        &mut ctx.accounts.milestone_review,
+        round.as_ref(),
+        &ctx.accounts.funding_round_metadata,
+        milestone_index,
+        ctx.bumps.milestone_review,
+    )?;
+    emit_cpi!(event);
+    Ok(())
+}
+
+/// Starts a review round on `review`, initializing it on first submission. Shared with
+/// team-approved submissions; the caller emits the returned event.
+/// Votes are weighted by contributions, so a review only opens once the project's
+/// round (`None` when closed or not passed) no longer lets them be withdrawn.
+pub fn open_milestone_review(
+    project: &Account<Project>,
+    review: &mut MilestoneReview,
+    round: Option<&FundingRound>,
+    round_meta: &FundingRoundMeta,
+    milestone_index: u8,
+    bump: u8,
+) -> Result<MilestoneSubmitted> {
+    require!((milestone_index as usize) < project.milestones.len(), AnectosError::MilestoneIndexOutOfBounds);
+    let milestone = &project.milestones[milestone_index as usize];
+    require!(!milestone.is_achieved, AnectosError::MilestoneAlreadyCompleted);
+    require!(milestone.amount <= project.ledger.donations_received, AnectosError::InsufficientProjectVaultFunds);
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !withdrawals_open(round, round_meta, now),
+        AnectosError::ContributionsStillWithdrawable
+    );
+
+    if review.project == Pubkey::default() {
+        review.project = project.key();
+        review.milestone_index = milestone_index;
+        review.attempt = 0;
+        review.failed_attempts = 0;
+        review.version = ACCOUNT_VERSION;
+        review.bump = bump;
+    } else {
+        match review.status {
+            MilestoneReviewStatus::Voting => return err!(AnectosError::MilestoneReviewInProgress),
+            MilestoneReviewStatus::Approved => return err!(AnectosError::MilestoneAlreadyCompleted),
+            MilestoneReviewStatus::Rejected => {
+                require!(review.failed_attempts < MAX_MILESTONE_FAILURES, AnectosError::ProjectRefunding);
+                review.attempt = review.attempt.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+            }
+        }
+    }
+
+    review.status = MilestoneReviewStatus::Voting;
+    review.submitted_at = now;
+    review.voting_ends_at = now + MILESTONE_VOTING_PERIOD;
+    review.approve_weight = 0;
+    review.reject_weight = 0;
+    review.voter_count = 0;
+    review.challenge_ends_at = 0;
+    review.challenge_open = false;
+
+    Ok(MilestoneSubmitted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        milestone_index,
+        attempt: review.attempt,
+        voting_ends_at: review.voting_ends_at,
+        timestamp: now,
+    })
+}
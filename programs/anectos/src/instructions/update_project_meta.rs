@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::EVENT_SCHEMA_VERSION,
+    error::AnectosError,
+    state::{FundingRound, FundingStage, Project, ProjectMeta, ProjectMetaUpdated, SDGGoals},
+    validation::validate_project_metadata,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateProjectMeta<'info> {
+    pub owner: Signer<'info>,
+    #[account(
+        has_one = owner,
+        seeds = [b"project", project.creator.as_ref()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"project_metadata", project.key().as_ref()],
+        bump = project_meta.bump
+    )]
+    pub project_meta: Account<'info, ProjectMeta>,
+    #[account(address = project.round @ AnectosError::Unauthorized)]
+    pub funding_round: Account<'info, FundingRound>,
+    /// Co-signature of the round owner, required once the project left Planning
+    pub round_owner: Option<Signer<'info>>,
+}
+
+/// Replaces the project's descriptive metadata. Owners edit freely while the project
+/// is in Planning; afterwards donors have relied on it, so the round owner co-signs.
+pub fn handler(
+    ctx: Context<UpdateProjectMeta>,
+    title: String,
+    description: String,
+    image_metadata_uri: String,
+    sdg_goals: Vec<SDGGoals>,
+) -> Result<()> {
+    require!(!ctx.accounts.project.has_team, AnectosError::TeamApprovalRequired);
+    let event = apply_meta_update(
+        ctx.accounts.project.key(),
+        &mut ctx.accounts.project_meta,
+        &ctx.accounts.funding_round,
+        ctx.accounts.round_owner.as_ref().map(|signer| signer.key()),
+        title,
+        description,
+        image_metadata_uri,
+        sdg_goals,
+    )?;
+    emit_cpi!(event);
+    Ok(())
+}
+
+/// Validates and writes a metadata update. `approved_by` is the co-signing round owner,
+/// if any. Shared with team-approved updates; the caller emits the returned event.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_meta_update(
+    project: Pubkey,
+    meta: &mut ProjectMeta,
+    funding_round: &FundingRound,
+    approved_by: Option<Pubkey>,
+    title: String,
+    description: String,
+    image_metadata_uri: String,
+    sdg_goals: Vec<SDGGoals>,
+) -> Result<ProjectMetaUpdated> {
+    let sdg_goals = validate_project_metadata(&title, &description, &image_metadata_uri, sdg_goals)?;
+
+    if let Some(signer) = approved_by {
+        require_keys_eq!(signer, funding_round.owner, AnectosError::Unauthorized);
+    }
+    require!(
+        meta.funding_stage == FundingStage::Planning || approved_by.is_some(),
+        AnectosError::MetadataUpdateRequiresApproval
+    );
+
+    let previous_content_hash = meta.content_hash()?;
+    meta.title = title;
+    meta.description = description;
+    meta.image_metadata_uri = image_metadata_uri;
+    meta.sdg_goals = sdg_goals;
+    meta.revision = meta.revision.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+
+    Ok(ProjectMetaUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project,
+        revision: meta.revision,
+        previous_content_hash,
+        content_hash: meta.content_hash()?,
+        funding_stage: meta.funding_stage,
+        approved_by,
+        timestamp: Clock::get()?.unix_timestamp,
+    })
+}
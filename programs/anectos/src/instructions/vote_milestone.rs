@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ACCOUNT_VERSION, EVENT_SCHEMA_VERSION, PAUSE_MILESTONES},
+    error::AnectosError,
+    math::isqrt_u128,
+    state::{Contribution, MilestoneReview, MilestoneReviewStatus, MilestoneVote, MilestoneVoteCast, Project, ProtocolConfig},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct VoteMilestone<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    #[account(
+        seeds = [b"project", project.creator.as_ref()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [b"milestone_review", project.key().as_ref(), &[milestone_index]],
+        bump = milestone_review.bump
+    )]
+    pub milestone_review: Account<'info, MilestoneReview>,
+    #[account(
+        has_one = project,
+        has_one = contributor,
+        seeds = [b"contrib", project.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    /// One vote per contributor per review attempt
+    #[account(
+        init,
+        payer = contributor,
+        space = 8 + MilestoneVote::INIT_SPACE,
+        seeds = [
+            b"milestone_vote",
+            milestone_review.key().as_ref(),
+            &[milestone_review.attempt],
+            contributor.key().as_ref()
+        ],
+        bump
+    )]
+    pub milestone_vote: Account<'info, MilestoneVote>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Casts a contributor's approve/reject vote, weighted by sqrt(total_contributed) as in QF.
+pub fn handler(ctx: Context<VoteMilestone>, milestone_index: u8, approve: bool) -> Result<()> {
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_MILESTONES, None)?;
+    let review = &mut ctx.accounts.milestone_review;
+    let contribution = &ctx.accounts.contribution;
+
+    require!(review.status == MilestoneReviewStatus::Voting, AnectosError::MilestoneNotInReview);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < review.voting_ends_at, AnectosError::VotingClosed);
+    require!(
+        !contribution.is_excluded && !contribution.refunded && contribution.total_contributed > 0,
+        AnectosError::ContributionNotEligible
+    );
+
+    let weight = isqrt_u128(contribution.total_contributed as u128);
+    if approve {
+        review.approve_weight = review.approve_weight.checked_add(weight).ok_or(AnectosError::MathOverflow)?;
+    } else {
+        review.reject_weight = review.reject_weight.checked_add(weight).ok_or(AnectosError::MathOverflow)?;
+    }
+    review.voter_count = review.voter_count.checked_add(1).ok_or(AnectosError::MathOverflow)?;
+
+    let vote = &mut ctx.accounts.milestone_vote;
+    vote.review = review.key();
+    vote.contributor = ctx.accounts.contributor.key();
+    vote.attempt = review.attempt;
+    vote.approve = approve;
+    vote.weight = weight;
+    vote.version = ACCOUNT_VERSION;
+    vote.bump = ctx.bumps.milestone_vote;
+
+    emit_cpi!(MilestoneVoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: ctx.accounts.project.key(),
+        milestone_index,
+        contributor: vote.contributor,
+        approve,
+        weight,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{
+    constants::{CONTRIBUTION_WITHDRAWAL_CUTOFF, EVENT_SCHEMA_VERSION, MIN_CONTRIBUTION_AMOUNT, PAUSE_WITHDRAWALS},
+    error::AnectosError,
+    math::area_delta,
+    state::{
+        record_ledger_entry, Contribution, ContributionWithdrawn, FundingRound, FundingRoundMeta, LedgerEntry,
+        Project, ProtocolConfig,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawContribution<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    #[account(mut)]
+    pub funding_round: Account<'info, FundingRound>,
+    #[account(
+        seeds = [b"funding_round_metadata", funding_round.key().as_ref()],
+        bump
+    )]
+    pub funding_round_metadata: Account<'info, FundingRoundMeta>,
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref()],
+        bump,
+        constraint = project.round == funding_round.key() @ AnectosError::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"vault", project.creator.as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = project,
+        has_one = contributor,
+        seeds = [b"contrib", project.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Whether contributions to a round can still be withdrawn at `now`. Without the round
+/// (`None`, e.g. once it is closed) only the time cutoff is known, so that alone decides.
+pub fn withdrawals_open(round: Option<&FundingRound>, meta: &FundingRoundMeta, now: i64) -> bool {
+    let cutoff = meta.end_time.saturating_sub(CONTRIBUTION_WITHDRAWAL_CUTOFF);
+    let round_open = match round {
+        Some(round) => round.is_active && !round.settlement_started,
+        None => true,
+    };
+    now < cutoff && round_open
+}
+
+/// Returns `amount` of the caller's contribution and takes its quadratic weight back
+/// out, as if that part had never been contributed. Closes `CONTRIBUTION_WITHDRAWAL_CUTOFF`
+/// before the round ends so the final tally cannot be swung at the last moment.
+/// Either the whole contribution leaves or at least `MIN_CONTRIBUTION_AMOUNT` stays.
+pub fn handler(ctx: Context<WithdrawContribution>, amount: u64) -> Result<()> {
+    // An exit for donors, so it stays open while new contributions are paused
+    ctx.accounts.protocol_config.require_not_paused(PAUSE_WITHDRAWALS, Some(&ctx.accounts.funding_round))?;
+    require!(amount > 0, AnectosError::InvalidContributionAmount);
+
+    let funding_round = &mut ctx.accounts.funding_round;
+    let project = &mut ctx.accounts.project;
+    let contribution = &mut ctx.accounts.contribution;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        withdrawals_open(Some(funding_round), &ctx.accounts.funding_round_metadata, now),
+        AnectosError::ContributionWithdrawalClosed
+    );
+    require!(!project.refunds_enabled, AnectosError::ProjectRefunding);
+    require!(!contribution.is_excluded, AnectosError::ContributionAlreadyExcluded);
+    require!(amount <= contribution.total_contributed, AnectosError::WithdrawalExceedsContribution);
+    let remaining = contribution.total_contributed - amount;
+    require!(
+        remaining == 0 || remaining >= MIN_CONTRIBUTION_AMOUNT,
+        AnectosError::RemainingContributionTooSmall
+    );
+    // Donations the owner already withdrew are no longer in the vault to give back
+    require!(amount <= project.current_funding, AnectosError::InsufficientProjectVaultFunds);
+    require!(
+        project.current_funding.saturating_sub(project.disputed_amount) >= amount,
+        AnectosError::FundsLockedByDispute
+    );
+
+    let creator = project.creator;
+    let signer_seeds = [b"vault", creator.as_ref(), &[ctx.bumps.vault]];
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.contributor.to_account_info(),
+            },
+            &[&signer_seeds[..]],
+        ),
+        amount,
+    )?;
+
+    record_ledger_entry(project, funding_round, LedgerEntry::DonationCancelled(amount))?;
+
+    // Area telescopes to sqrt(total) per contributor, so removing
+    // sqrt(prev) - sqrt(new) restores exactly the state of a smaller contribution
+    let prev = contribution.total_contributed as u128;
+    let new_total = prev - amount as u128;
+    let removed = area_delta(new_total, prev);
+    contribution.total_contributed = new_total as u64;
+    project.area = project.area.checked_sub(removed).ok_or(AnectosError::MathOverflow)?;
+    funding_round.area = funding_round.area.checked_sub(removed).ok_or(AnectosError::MathOverflow)?;
+    if new_total == 0 {
+        funding_round.contributor_count = funding_round.contributor_count.saturating_sub(1);
+    }
+
+    emit_cpi!(ContributionWithdrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        project: project.key(),
+        contributor: contribution.contributor,
+        amount,
+        remaining_contribution: contribution.total_contributed,
+        area_removed: removed,
+        new_total_funding: project.current_funding,
+        timestamp: now,
+    });
+
+    Ok(())
+}
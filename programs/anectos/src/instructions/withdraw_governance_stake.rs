@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{GovernanceConfig, VoterStake, VoterStakeUpdated};
+use crate::constants::EVENT_SCHEMA_VERSION;
+use crate::error::AnectosError;
+use crate::instructions::create_proposal::read_acts_balance;
+use crate::instructions::deposit_governance_stake::transfer_acts;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawGovernanceStake<'info> {
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, GovernanceConfig>,
+    #[account(
+        mut,
+        has_one = voter,
+        seeds = [b"voter_stake", voter.key().as_ref()],
+        bump = voter_stake.bump
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+    /// CHECK: voter's ACTS token account, validated in `read_acts_balance`
+    #[account(mut)]
+    pub voter_token_account: UncheckedAccount<'info>,
+    /// CHECK: ACTS token account owned by `escrow_authority`, validated in `read_acts_balance`
+    #[account(mut)]
+    pub escrow_token_account: UncheckedAccount<'info>,
+    /// CHECK: PDA owning the escrowed ACTS: seeds = [b"governance_escrow"]
+    #[account(seeds = [b"governance_escrow"], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    /// CHECK: must own both token accounts, checked in `transfer_acts`
+    pub token_program: UncheckedAccount<'info>,
+}
+
+/// Returns staked ACTS once every proposal the stake voted on has closed.
+/// Like refund claims, this stays open while governance is paused.
+pub fn handler(ctx: Context<WithdrawGovernanceStake>, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let stake = &mut ctx.accounts.voter_stake;
+    require!(now >= stake.locked_until, AnectosError::StakeLocked);
+    require!(amount > 0 && amount <= stake.amount, AnectosError::InsufficientGovernanceTokens);
+
+    let voter = ctx.accounts.voter.key();
+    let acts_mint = ctx.accounts.governance.acts_mint;
+    read_acts_balance(&ctx.accounts.voter_token_account.to_account_info(), &acts_mint, &voter)?;
+    read_acts_balance(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &acts_mint,
+        &ctx.accounts.escrow_authority.key(),
+    )?;
+
+    transfer_acts(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.voter_token_account.to_account_info(),
+        &ctx.accounts.escrow_authority.to_account_info(),
+        amount,
+        &[&[b"governance_escrow", &[ctx.bumps.escrow_authority]]],
+    )?;
+    stake.amount -= amount;
+
+    emit_cpi!(VoterStakeUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        voter,
+        deposited: false,
+        amount,
+        total_staked: stake.amount,
+        locked_until: stake.locked_until,
+        timestamp: now,
+    });
+    Ok(())
+}
@@ -1,10 +1,15 @@
 #![allow(unexpected_cfgs)]
 #![allow(deprecated)]
 #![allow(unused)]
+#![allow(ambiguous_glob_reexports)]
+#![allow(clippy::too_many_arguments)]
 pub mod constants;
 pub mod error;
 pub mod instructions;
+pub mod math;
+pub mod migration;
 pub mod state;
+pub mod validation;
 
 use anchor_lang::prelude::*;
 
@@ -39,8 +44,157 @@ pub mod anectos {
     change_project_funding_stage::handler(ctx, new_stage)
     }
 
-    pub fn complete_milestone(ctx: Context<CompleteMilestone>, milestone_index: u8, current_funding: u64) -> Result<()> {
-    complete_milestone::handler(ctx, milestone_index, current_funding)
+    pub fn update_project_meta(
+        ctx: Context<UpdateProjectMeta>,
+        title: String,
+        description: String,
+        image_metadata_uri: String,
+        sdg_goals: Vec<SDGGoals>,
+    ) -> Result<()> {
+    update_project_meta::handler(ctx, title, description, image_metadata_uri, sdg_goals)
+    }
+
+    pub fn propose_project_owner(ctx: Context<ProposeProjectOwner>, new_owner: Pubkey) -> Result<()> {
+    propose_project_owner::handler(ctx, new_owner)
+    }
+
+    pub fn accept_project_owner(ctx: Context<AcceptProjectOwner>) -> Result<()> {
+    accept_project_owner::handler(ctx)
+    }
+
+    pub fn set_payout_splits(ctx: Context<SetPayoutSplits>, recipients: Vec<PayoutRecipient>) -> Result<()> {
+    set_payout_splits::handler(ctx, recipients)
+    }
+
+    pub fn distribute_payout<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributePayout<'info>>,
+    ) -> Result<()> {
+    distribute_payout::handler(ctx)
+    }
+
+    pub fn configure_project_team(
+        ctx: Context<ConfigureProjectTeam>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+    configure_project_team::handler(ctx, members, threshold)
+    }
+
+    pub fn propose_team_action(ctx: Context<ProposeTeamAction>, action: TeamAction) -> Result<()> {
+    propose_team_action::handler(ctx, action)
+    }
+
+    pub fn approve_team_action(ctx: Context<ApproveTeamAction>) -> Result<()> {
+    approve_team_action::handler(ctx)
+    }
+
+    pub fn execute_team_action(ctx: Context<ExecuteTeamAction>) -> Result<()> {
+    execute_team_action::handler(ctx)
+    }
+
+    pub fn close_contribution(ctx: Context<CloseContribution>) -> Result<()> {
+    close_contribution::handler(ctx)
+    }
+
+    pub fn close_project(ctx: Context<CloseProject>) -> Result<()> {
+    close_project::handler(ctx)
+    }
+
+    pub fn withdraw_contribution(ctx: Context<WithdrawContribution>, amount: u64) -> Result<()> {
+    withdraw_contribution::handler(ctx, amount)
+    }
+
+    pub fn contribute_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ContributeBatch<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+    contribute_batch::handler(ctx, amounts)
+    }
+
+    pub fn submit_milestone(ctx: Context<SubmitMilestone>, milestone_index: u8) -> Result<()> {
+    submit_milestone::handler(ctx, milestone_index)
+    }
+
+    pub fn vote_milestone(ctx: Context<VoteMilestone>, milestone_index: u8, approve: bool) -> Result<()> {
+    vote_milestone::handler(ctx, milestone_index, approve)
+    }
+
+    pub fn complete_milestone(ctx: Context<CompleteMilestone>, milestone_index: u8) -> Result<()> {
+    complete_milestone::handler(ctx, milestone_index)
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    claim_refund::handler(ctx)
+    }
+
+    pub fn set_round_arbitrator(ctx: Context<SetRoundArbitrator>, arbitrator: Pubkey) -> Result<()> {
+    set_round_arbitrator::handler(ctx, arbitrator)
+    }
+
+    pub fn challenge_milestone(
+        ctx: Context<ChallengeMilestone>,
+        milestone_index: u8,
+        evidence_uri: String,
+    ) -> Result<()> {
+    challenge_milestone::handler(ctx, milestone_index, evidence_uri)
+    }
+
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>, milestone_index: u8, upheld: bool) -> Result<()> {
+    resolve_challenge::handler(ctx, milestone_index, upheld)
+    }
+
+    pub fn audit_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AuditRound<'info>>,
+    ) -> Result<RoundAuditReport> {
+    audit_round::handler(ctx)
+    }
+
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        guardians: Vec<Pubkey>,
+        guardian_threshold: u8,
+    ) -> Result<()> {
+    initialize_protocol_config::handler(ctx, guardians, guardian_threshold)
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, paused_groups: u8) -> Result<()> {
+    set_pause::handler(ctx, paused_groups)
+    }
+
+    pub fn configure_guardians(
+        ctx: Context<ConfigureGuardians>,
+        guardians: Vec<Pubkey>,
+        guardian_threshold: u8,
+    ) -> Result<()> {
+    configure_guardians::handler(ctx, guardians, guardian_threshold)
+    }
+
+    pub fn set_round_vesting(
+        ctx: Context<SetRoundVesting>,
+        vesting_cliff_secs: i64,
+        vesting_duration_secs: i64,
+    ) -> Result<()> {
+    set_round_vesting::handler(ctx, vesting_cliff_secs, vesting_duration_secs)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    claim_vested::handler(ctx)
+    }
+
+    pub fn cancel_vesting(ctx: Context<CancelVesting>) -> Result<()> {
+    cancel_vesting::handler(ctx)
+    }
+
+    pub fn set_milestone_deadlines(ctx: Context<SetMilestoneDeadlines>, due_dates: Vec<i64>) -> Result<()> {
+    set_milestone_deadlines::handler(ctx, due_dates)
+    }
+
+    pub fn set_round_rollover(ctx: Context<SetRoundRollover>, rollover_round: Pubkey) -> Result<()> {
+    set_round_rollover::handler(ctx, rollover_round)
+    }
+
+    pub fn clawback_expired_milestones(ctx: Context<ClawbackExpiredMilestones>) -> Result<()> {
+    clawback_expired_milestones::handler(ctx)
     }
 
     pub fn initialize_funding_round(
@@ -89,4 +243,82 @@ pub mod anectos {
     set_area_max::handler(ctx, area_max)
     }
 
-}
\ No newline at end of file
+    pub fn set_round_reviewer(ctx: Context<SetRoundReviewer>, reviewer: Pubkey) -> Result<()> {
+    set_round_reviewer::handler(ctx, reviewer)
+    }
+
+    pub fn flag_contribution(ctx: Context<FlagContribution>, reason_code: u8) -> Result<()> {
+    flag_contribution::handler(ctx, reason_code)
+    }
+
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        acts_mint: Pubkey,
+        proposal_threshold: u64,
+        voting_period: i64,
+        quorum: u64,
+        approval_threshold_bps: u16,
+    ) -> Result<()> {
+    initialize_governance::handler(ctx, acts_mint, proposal_threshold, voting_period, quorum, approval_threshold_bps)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        description_uri: String,
+        voting_mode: VotingMode,
+    ) -> Result<()> {
+    create_proposal::handler(ctx, action, description_uri, voting_mode)
+    }
+
+    pub fn deposit_governance_stake(ctx: Context<DepositGovernanceStake>, amount: u64) -> Result<()> {
+    deposit_governance_stake::handler(ctx, amount)
+    }
+
+    pub fn withdraw_governance_stake(ctx: Context<WithdrawGovernanceStake>, amount: u64) -> Result<()> {
+    withdraw_governance_stake::handler(ctx, amount)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+    cast_vote::handler(ctx, approve)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    execute_proposal::handler(ctx)
+    }
+
+    pub fn configure_quadratic_voting(
+        ctx: Context<ConfigureQuadraticVoting>,
+        voice_credits_per_epoch: u64,
+        qv_epoch_duration: i64,
+        qv_quorum: u64,
+        qv_min_stake: u64,
+    ) -> Result<()> {
+    configure_quadratic_voting::handler(ctx, voice_credits_per_epoch, qv_epoch_duration, qv_quorum, qv_min_stake)
+    }
+
+    pub fn cast_quadratic_vote(ctx: Context<CastQuadraticVote>, approve: bool, credits: u64) -> Result<()> {
+    cast_quadratic_vote::handler(ctx, approve, credits)
+    }
+
+    pub fn migrate_project(ctx: Context<MigrateProject>) -> Result<()> {
+    migrate_project::handler(ctx)
+    }
+
+    pub fn migrate_project_meta(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::handler::<migration::ProjectMetaV0>(ctx)
+    }
+
+    pub fn migrate_funding_round(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::handler::<migration::FundingRoundV0>(ctx)
+    }
+
+    pub fn migrate_funding_round_meta(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::handler::<migration::FundingRoundMetaV0>(ctx)
+    }
+
+    pub fn migrate_contribution(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::handler::<migration::ContributionV0>(ctx)
+    }
+
+}
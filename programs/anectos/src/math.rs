@@ -0,0 +1,107 @@
+//! Quadratic funding and payout arithmetic shared by the program, the client
+//! crates and the off-chain simulator.
+//!
+//! Only `core` is used here so the module stays `no_std` and free of Anchor types;
+//! `MathError` converts into `AnectosError` at the instruction boundary.
+
+use core::fmt;
+
+use crate::constants::BPS_DENOMINATOR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    Overflow,
+    DivisionByZero,
+    InvalidBasisPoints,
+    OutOfRange,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => f.write_str("arithmetic overflow"),
+            MathError::DivisionByZero => f.write_str("division by zero"),
+            MathError::InvalidBasisPoints => f.write_str("basis points above 10000"),
+            MathError::OutOfRange => f.write_str("index out of range"),
+        }
+    }
+}
+
+pub type MathResult<T> = core::result::Result<T, MathError>;
+
+/// Floor of the square root of `x`.
+pub fn isqrt_u128(x: u128) -> u128 {
+    if x == 0 {
+        return 0;
+    }
+    // Newton's method from an initial guess >= sqrt(x); written to avoid x + 1 overflowing
+    let mut z = (x >> 1) + (x & 1);
+    let mut y = x;
+    while z < y {
+        y = z;
+        z = (x / z + z) >> 1;
+    }
+    y
+}
+
+/// Area added when a contributor's running total moves from `prev_total` to `new_total`.
+/// Summed over a round this telescopes to sqrt(total) per contributor.
+pub fn area_delta(prev_total: u128, new_total: u128) -> u128 {
+    isqrt_u128(new_total).saturating_sub(isqrt_u128(prev_total))
+}
+
+/// Matching owed to a project with `project_area` out of a `pool` budget:
+/// pool * project_area^2 / max(round_area, area_max)^2, floored.
+/// Returns 0 when there is nothing to match.
+pub fn matching_allocation(pool: u64, project_area: u128, round_area: u128, area_max: u128) -> MathResult<u128> {
+    if round_area == 0 || project_area == 0 || pool == 0 {
+        return Ok(0);
+    }
+    let denom_area = round_area.max(area_max);
+    let num = project_area.checked_mul(project_area).ok_or(MathError::Overflow)?;
+    let den = denom_area.checked_mul(denom_area).ok_or(MathError::Overflow)?;
+    (pool as u128)
+        .checked_mul(num)
+        .ok_or(MathError::Overflow)?
+        .checked_div(den)
+        .ok_or(MathError::DivisionByZero)
+}
+
+/// Amount of milestone `index` (0-based) when `target` is split over `count`
+/// milestones in arithmetic progression: milestone i gets step * (i + 1) with
+/// step = target / (1 + 2 + .. + count), and the last one absorbs the rounding
+/// remainder so the milestones sum to exactly `target`.
+pub fn milestone_amount(target: u64, count: u8, index: u8) -> MathResult<u64> {
+    if index >= count {
+        return Err(MathError::OutOfRange);
+    }
+    let n = count as u64;
+    let triangle = n * (n + 1) / 2;
+    let step = target / triangle;
+    let amount = step * (index as u64 + 1);
+    if index + 1 == count {
+        // step * triangle <= target, so this cannot underflow
+        Ok(amount + (target - step * triangle))
+    } else {
+        Ok(amount)
+    }
+}
+
+/// `amount * bps / 10_000`, floored.
+pub fn bps_of(amount: u64, bps: u16) -> MathResult<u64> {
+    if bps > BPS_DENOMINATOR {
+        return Err(MathError::InvalidBasisPoints);
+    }
+    // bps <= 10_000, so the result never exceeds `amount`
+    Ok(((amount as u128) * (bps as u128) / (BPS_DENOMINATOR as u128)) as u64)
+}
+
+/// `amount * numerator / denominator`, floored: a pro-rata share of `amount`.
+pub fn pro_rata(amount: u64, numerator: u64, denominator: u64) -> MathResult<u64> {
+    let share = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(MathError::Overflow)?
+        .checked_div(denominator as u128)
+        .ok_or(MathError::DivisionByZero)?;
+    u64::try_from(share).map_err(|_| MathError::Overflow)
+}
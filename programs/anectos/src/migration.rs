@@ -0,0 +1,190 @@
+//! Layouts of the accounts the program deployed before the `version` byte and
+//! reserved padding were added: projects, their metadata, rounds, round metadata and
+//! contributions. Each legacy layout converts into the current one with defaults
+//! filled in; the `migrate_*` instructions use them to rewrite old accounts in place.
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::constants::ACCOUNT_VERSION;
+use crate::state::*;
+
+/// An account layout that predates account versioning.
+pub trait LegacyLayout: AnchorSerialize + AnchorDeserialize + Space {
+    /// The layout this one migrates to; shares the account discriminator.
+    type Current: AccountSerialize + Discriminator + Space + From<Self>;
+    /// Version stamped on accounts in this layout (unversioned layouts are 0).
+    const VERSION: u8;
+
+    /// Allocated size of an account in this layout, discriminator included.
+    fn account_size() -> usize {
+        Self::Current::DISCRIMINATOR.len() + Self::INIT_SPACE
+    }
+}
+
+/// Implements the conversion for a legacy layout. Fields added since are listed after
+/// `defaults`, computed from the legacy account; everything else is copied across.
+macro_rules! legacy_layout {
+    ($legacy:ident => $current:ident { $($field:ident),* $(,)? }) => {
+        legacy_layout!($legacy => $current { $($field),* } defaults |legacy| {});
+    };
+    (
+        $legacy:ident => $current:ident { $($field:ident),* $(,)? }
+        defaults |$old:ident| { $($new_field:ident: $value:expr),* $(,)? }
+    ) => {
+        impl LegacyLayout for $legacy {
+            type Current = $current;
+            const VERSION: u8 = 0;
+        }
+
+        impl From<$legacy> for $current {
+            fn from($old: $legacy) -> Self {
+                // Defaults come first so they can still read fields that are moved below
+                Self {
+                    $($new_field: $value,)*
+                    version: ACCOUNT_VERSION,
+                    $($field: $old.$field,)*
+                    reserved: core::array::from_fn(|_| 0),
+                }
+            }
+        }
+    };
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct MilestoneV0 {
+    pub amount: u64,
+    pub is_achieved: bool,
+}
+
+impl From<MilestoneV0> for Milestone {
+    fn from(legacy: MilestoneV0) -> Self {
+        // Legacy approvals predate challenge windows, so they are released at once
+        Self {
+            amount: legacy.amount,
+            is_achieved: legacy.is_achieved,
+            due_date: 0,
+            matching_clawed_back: false,
+            released_at: if legacy.is_achieved { 1 } else { 0 },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct ProjectV0 {
+    pub project_id: Pubkey,
+    pub round: Pubkey,
+    pub owner: Pubkey,
+    pub target_amount: u64,
+    pub area: u128,
+    pub current_funding: u64,
+    pub matching_unlocked: u64,
+    pub matching_pool: u64,
+    pub pool_distributed: u64,
+    pub is_whitelisted: bool,
+    pub has_withdrawn: bool,
+    pub milestone_count: u8,
+    #[max_len(16)]
+    pub milestones: Vec<MilestoneV0>,
+    pub bump: u8,
+}
+
+// Legacy projects only kept balances, so the ledger starts from what the vault still
+// holds: donations withdrawn before the migration are not part of it. The same
+// opening ledger is added to the project's round by `migrate_project`.
+legacy_layout!(ProjectV0 => Project {
+    project_id, round, owner, target_amount, area, current_funding, matching_unlocked,
+    matching_pool, pool_distributed, is_whitelisted, has_withdrawn, milestone_count, bump
+} defaults |legacy| {
+    milestones: legacy.milestones.into_iter().map(Milestone::from).collect(),
+    ledger: Ledger {
+        donations_received: legacy.current_funding,
+        matching_allocated: legacy.pool_distributed,
+        matching_withdrawn: legacy.pool_distributed.saturating_sub(legacy.matching_unlocked),
+        ..Ledger::default()
+    },
+    refunds_enabled: false,
+    refund_pool: 0,
+    disputed_amount: 0,
+    vesting_start: 0,
+    vesting_cliff: 0,
+    vesting_end: 0,
+    creator: legacy.owner,
+    has_payout_splits: false,
+    has_team: false,
+    bonds_forfeited: 0,
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct ProjectMetaV0 {
+    pub project: Pubkey,
+    #[max_len(64)]
+    pub title: String,
+    #[max_len(512)]
+    pub description: String,
+    #[max_len(200)]
+    pub image_metadata_uri: String,
+    pub funding_stage: FundingStage,
+    #[max_len(17)]
+    pub sdg_goals: Vec<SDGGoals>,
+    pub bump: u8,
+}
+
+legacy_layout!(ProjectMetaV0 => ProjectMeta {
+    project, title, description, image_metadata_uri, funding_stage, sdg_goals, bump
+} defaults |legacy| { revision: 0 });
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct FundingRoundV0 {
+    pub owner: Pubkey,
+    pub matching_pool: u64,
+    pub total_donations: u64,
+    pub area: u128,
+    pub area_max: u128,
+    pub contributor_count: u32,
+    pub project_vault: Pubkey,
+    pub vault_bump: u8,
+    pub is_active: bool,
+    pub fee: u64,
+    pub pool_distributed: u64,
+    pub bump: u8,
+}
+
+// A round's ledger is the sum of its projects' ledgers, so it starts empty and
+// `migrate_project` adds each project's opening ledger to it along with the balances
+// derived from it.
+legacy_layout!(FundingRoundV0 => FundingRound {
+    owner, matching_pool, area, area_max, contributor_count, project_vault,
+    vault_bump, is_active, fee, bump
+} defaults |legacy| {
+    ledger: Ledger::default(),
+    total_donations: 0,
+    pool_distributed: 0,
+    reviewer: Pubkey::default(),
+    arbitrator: Pubkey::default(),
+    settlement_started: legacy.pool_distributed > 0,
+    paused_groups: 0,
+    rollover_round: Pubkey::default(),
+    vesting_cliff_secs: 0,
+    vesting_duration_secs: 0,
+});
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct FundingRoundMetaV0 {
+    #[max_len(200)]
+    pub nft_metadata_uri: String,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+legacy_layout!(FundingRoundMetaV0 => FundingRoundMeta { nft_metadata_uri, start_time, end_time });
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct ContributionV0 {
+    pub project: Pubkey,
+    pub contributor: Pubkey,
+    pub total_contributed: u64,
+    pub bump: u8,
+}
+
+legacy_layout!(ContributionV0 => Contribution {
+    project, contributor, total_contributed, bump
+} defaults |legacy| { is_excluded: false, refunded: false });
@@ -1,24 +1,103 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use num_derive::*;
+use crate::constants::{
+    EVENT_SCHEMA_VERSION, MAX_DESCRIPTION_LEN, MAX_MILESTONES, MAX_PAYOUT_RECIPIENTS, MAX_TEAM_MEMBERS, MAX_TITLE_LEN,
+    MAX_URI_LEN,
+};
+use crate::error::AnectosError;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Project {
+    /// Layout version, see `constants::ACCOUNT_VERSION`
+    pub version: u8,
 	pub project_id: Pubkey,
 	pub round: Pubkey,
 	pub owner: Pubkey,
 	pub target_amount: u64,
 	pub area: u128,
+    /// Donations currently held for the project; derived from `ledger`
 	pub current_funding: u64,
+    /// Matching currently held for the project; derived from `ledger`
     pub matching_unlocked: u64,
     pub matching_pool: u64,
+    /// Matching settled to the project net of returns; derived from `ledger`
     pub pool_distributed: u64,
 	pub is_whitelisted: bool,
 	pub has_withdrawn: bool,
     pub milestone_count: u8,
-    #[max_len(16)]
+    #[max_len(MAX_MILESTONES)]
     pub milestones: Vec<Milestone>,
+    pub ledger: Ledger,
+    /// Set once a milestone fails review MAX_MILESTONE_FAILURES times
+    pub refunds_enabled: bool,
+    /// Donation funds set aside for pro-rata refunds when refunds were enabled
+    pub refund_pool: u64,
+    /// Sum of milestone amounts under an open challenge; not withdrawable
+    pub disputed_amount: u64,
+    /// Linear vesting of settled matching, fixed at the first settlement
+    pub vesting_start: i64,
+    pub vesting_cliff: i64,
+    pub vesting_end: i64,
     pub bump: u8,
+    /// Key the project was created with. Seeds the project and vault PDAs, so their
+    /// addresses survive ownership transfers; never changes.
+    pub creator: Pubkey,
+    /// Set once payout splits are configured; payouts then only go through `distribute_payout`
+    pub has_payout_splits: bool,
+    /// Set once a project team is configured; sensitive actions then need team approval
+    pub has_team: bool,
+    /// Bonds of dismissed challenges paid into the vault. Kept out of the ledger, so they
+    /// are neither withdrawable nor refundable; the vault sweep on close returns them.
+    pub bonds_forfeited: u64,
+    /// Zeroed space for future fields; new fields are carved out of it
+    pub reserved: [u8; 22],
+}
+
+impl Project {
+    /// Matching vested at `now`: nothing before the cliff, linear from start to end.
+    /// Matching returned to the round no longer vests.
+    pub fn vested_matching(&self, now: i64) -> u64 {
+        let total = self.ledger.matching_allocated.saturating_sub(self.ledger.matching_returned);
+        if now < self.vesting_cliff {
+            return 0;
+        }
+        if now >= self.vesting_end || self.vesting_end <= self.vesting_start {
+            return total;
+        }
+        let elapsed = (now - self.vesting_start) as u128;
+        let duration = (self.vesting_end - self.vesting_start) as u128;
+        ((total as u128) * elapsed / duration) as u64
+    }
+
+    /// Vested matching not yet withdrawn
+    pub fn claimable_matching(&self, now: i64) -> u64 {
+        self.vested_matching(now).saturating_sub(self.ledger.matching_withdrawn)
+    }
+
+    /// Sum of the milestones released at `now`, or `None` once all of them are and
+    /// whatever the vault holds beyond the target may leave too
+    pub fn released_milestones(&self, now: i64) -> Option<u64> {
+        if self.milestones.iter().all(|m| m.is_released(now)) {
+            return None;
+        }
+        Some(self.milestones.iter().filter(|m| m.is_released(now)).map(|m| m.amount).sum())
+    }
+
+    /// Donations plus claimable matching that may leave the vault, net of disputed funds
+    /// and capped at the released milestones less what was already withdrawn
+    pub fn releasable(&self, now: i64) -> u64 {
+        let unlocked = (self.current_funding as u128 + self.claimable_matching(now) as u128)
+            .saturating_sub(self.disputed_amount as u128) as u64;
+        match self.released_milestones(now) {
+            Some(released) => {
+                let withdrawn = self.ledger.donations_withdrawn.saturating_add(self.ledger.matching_withdrawn);
+                unlocked.min(released.saturating_sub(withdrawn))
+            }
+            None => unlocked,
+        }
+    }
 }
 
 #[derive(
@@ -42,6 +121,13 @@ pub enum FundingStage {
     Rejected,
 }
 
+impl FundingStage {
+    /// Completed and Rejected projects take no further contributions, votes or stage changes
+    pub fn is_final(&self) -> bool {
+        matches!(self, FundingStage::Completed | FundingStage::Rejected)
+    }
+}
+
 #[derive(
     AnchorSerialize,
     AnchorDeserialize,
@@ -78,69 +164,670 @@ pub enum SDGGoals {
 #[account]
 #[derive(InitSpace)]
 pub struct ProjectMeta {
+    pub version: u8,
     pub project: Pubkey,
-    #[max_len(64)]
+    #[max_len(MAX_TITLE_LEN)]
     pub title: String,
-    #[max_len(512)]
+    #[max_len(MAX_DESCRIPTION_LEN)]
     pub description: String,
-    #[max_len(200)]
+    #[max_len(MAX_URI_LEN)]
     pub image_metadata_uri: String,
     pub funding_stage: FundingStage,
     #[max_len(17)]
     pub sdg_goals: Vec<SDGGoals>,
     pub bump: u8,
+    /// Incremented on every `update_project_meta`
+    pub revision: u32,
+    pub reserved: [u8; 60],
+}
+
+impl ProjectMeta {
+    /// sha256 over the Borsh encoding of the title, description, image URI and SDG
+    /// goals, so clients can check which content a revision refers to.
+    pub fn content_hash(&self) -> Result<[u8; 32]> {
+        let mut content = Vec::new();
+        self.title.serialize(&mut content)?;
+        self.description.serialize(&mut content)?;
+        self.image_metadata_uri.serialize(&mut content)?;
+        self.sdg_goals.serialize(&mut content)?;
+        Ok(hash(&content).to_bytes())
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct FundingRound {
+    pub version: u8,
 	pub owner: Pubkey,
 	pub matching_pool: u64,
+    /// Derived from `ledger.donations_received`
 	pub total_donations: u64,
 	pub area: u128,
 	pub area_max: u128,
+    /// Contribution records with a non-zero total, one per (project, contributor)
 	pub contributor_count: u32,
     pub project_vault: Pubkey, // used as round_vault
 	pub vault_bump: u8,
 	pub is_active: bool,
 	pub fee: u64,
     pub pool_distributed: u64,
+    /// Sum of the ledgers of every project in the round
+    pub ledger: Ledger,
+    pub reviewer: Pubkey,
+    pub arbitrator: Pubkey,
+    pub settlement_started: bool,
+    /// Round-level pause bit flags, see `constants::PAUSE_*`
+    pub paused_groups: u8,
+    /// Round that receives clawed-back matching (default = this round's vault)
+    pub rollover_round: Pubkey,
+    /// Vesting applied to each project's matching at settlement (0 = immediate)
+    pub vesting_cliff_secs: i64,
+    pub vesting_duration_secs: i64,
     pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl FundingRound {
+    /// Recomputes the balances derived from `ledger`
+    pub fn refresh_balances(&mut self) {
+        self.total_donations = self.ledger.donations_received;
+        self.pool_distributed = self.ledger.matching_allocated.saturating_sub(self.ledger.matching_returned);
+    }
+
+    pub fn settings_updated(&self, round: Pubkey, timestamp: i64) -> RoundSettingsUpdated {
+        RoundSettingsUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            round,
+            reviewer: self.reviewer,
+            arbitrator: self.arbitrator,
+            rollover_round: self.rollover_round,
+            vesting_cliff_secs: self.vesting_cliff_secs,
+            vesting_duration_secs: self.vesting_duration_secs,
+            timestamp,
+        }
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct FundingRoundMeta {
+    pub version: u8,
+    #[max_len(MAX_URI_LEN)]
     pub nft_metadata_uri: String,
     pub start_time: i64,
     pub end_time: i64,
+    pub reserved: [u8; 64],
 }
 
-impl FundingRoundMeta {
-    pub const INIT_SPACE: usize = 4 + 200 + 8 + 8;
-    pub fn space() -> usize {
-        8 + 4 + 200 + 8 + 8
+/// Money movements for a project, or the sum over a round's projects.
+/// Balances shown on `Project` and `FundingRound` are derived from these.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub donations_received: u64,
+    pub donations_withdrawn: u64,
+    pub matching_allocated: u64,
+    pub matching_withdrawn: u64,
+    pub refunds_paid: u64,
+    /// Unvested matching sent back to the round vault
+    pub matching_returned: u64,
+}
+
+#[derive(Clone, Copy)]
+pub enum LedgerEntry {
+    DonationReceived(u64),
+    DonationWithdrawn(u64),
+    MatchingAllocated(u64),
+    MatchingWithdrawn(u64),
+    RefundPaid(u64),
+    MatchingReturned(u64),
+    /// A donation taken back by its donor during the contribution phase; nets out of
+    /// `donations_received` since it never counted towards the round
+    DonationCancelled(u64),
+}
+
+impl Ledger {
+    pub fn record(&mut self, entry: LedgerEntry) -> Result<()> {
+        let (slot, amount) = match entry {
+            LedgerEntry::DonationReceived(a) => (&mut self.donations_received, a),
+            LedgerEntry::DonationWithdrawn(a) => (&mut self.donations_withdrawn, a),
+            LedgerEntry::MatchingAllocated(a) => (&mut self.matching_allocated, a),
+            LedgerEntry::MatchingWithdrawn(a) => (&mut self.matching_withdrawn, a),
+            LedgerEntry::RefundPaid(a) => (&mut self.refunds_paid, a),
+            LedgerEntry::MatchingReturned(a) => (&mut self.matching_returned, a),
+            LedgerEntry::DonationCancelled(a) => {
+                self.donations_received = self
+                    .donations_received
+                    .checked_sub(a)
+                    .ok_or(AnectosError::LedgerOverflow)?;
+                return Ok(());
+            }
+        };
+        *slot = slot.checked_add(amount).ok_or(AnectosError::LedgerOverflow)?;
+        Ok(())
+    }
+
+    /// Donations received that have not been withdrawn or refunded
+    pub fn donations_held(&self) -> u64 {
+        self.donations_received
+            .saturating_sub(self.donations_withdrawn)
+            .saturating_sub(self.refunds_paid)
+    }
+
+    /// Matching allocated that has not been withdrawn or returned
+    pub fn matching_held(&self) -> u64 {
+        self.matching_allocated
+            .saturating_sub(self.matching_withdrawn)
+            .saturating_sub(self.matching_returned)
+    }
+
+    /// Adds every movement recorded in `other`, e.g. a project's ledger to its round's
+    pub fn absorb(&mut self, other: &Ledger) -> Result<()> {
+        for entry in [
+            LedgerEntry::DonationReceived(other.donations_received),
+            LedgerEntry::DonationWithdrawn(other.donations_withdrawn),
+            LedgerEntry::MatchingAllocated(other.matching_allocated),
+            LedgerEntry::MatchingWithdrawn(other.matching_withdrawn),
+            LedgerEntry::RefundPaid(other.refunds_paid),
+            LedgerEntry::MatchingReturned(other.matching_returned),
+        ] {
+            self.record(entry)?;
+        }
+        Ok(())
     }
 }
 
+/// Records a movement on both the project and its round ledger and refreshes the
+/// derived balances, so round totals always equal the sum of project ledgers.
+pub fn record_ledger_entry(project: &mut Project, round: &mut FundingRound, entry: LedgerEntry) -> Result<()> {
+    project.ledger.record(entry)?;
+    round.ledger.record(entry)?;
 
-#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+    project.current_funding = project.ledger.donations_held();
+    project.matching_unlocked = project.ledger.matching_held();
+    project.pool_distributed = project.ledger.matching_allocated.saturating_sub(project.ledger.matching_returned);
+    round.refresh_balances();
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default)]
 pub struct Milestone {
     pub amount: u64,       
     pub is_achieved: bool, 
+    /// Unix timestamp the milestone is due by (0 = no deadline)
+    pub due_date: i64,
+    /// Set once the milestone's matching share was returned after a missed deadline
+    pub matching_clawed_back: bool,
+    /// Unix timestamp the milestone's amount may leave the vault from: the end of its
+    /// challenge window once approved (0 = not approved, i64::MAX = under challenge)
+    pub released_at: i64,
+}
+
+impl Milestone {
+    pub fn is_released(&self, now: i64) -> bool {
+        self.is_achieved && self.released_at > 0 && now >= self.released_at
+    }
+}
+
+/// Protocol-wide emergency switches: seeds = [b"protocol_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    pub version: u8,
+    pub admin: Pubkey,
+    /// Global pause bit flags, see `constants::PAUSE_*`
+    pub paused_groups: u8,
+    /// Optional guardian multisig co-signing pause changes (threshold 0 = disabled)
+    #[max_len(5)]
+    pub guardians: Vec<Pubkey>,
+    pub guardian_threshold: u8,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl ProtocolConfig {
+    /// Fails if `group` is paused globally or, when given, on `round`.
+    pub fn require_not_paused(&self, group: u8, round: Option<&FundingRound>) -> Result<()> {
+        let round_paused = round.map_or(0, |r| r.paused_groups);
+        require!(
+            (self.paused_groups | round_paused) & group == 0,
+            AnectosError::OperationPaused
+        );
+        Ok(())
+    }
+
+    /// Counts distinct guardian signers among `accounts` against the threshold.
+    pub fn require_guardian_approval(&self, accounts: &[AccountInfo]) -> Result<()> {
+        if self.guardian_threshold == 0 {
+            return Ok(());
+        }
+        let mut approved: Vec<Pubkey> = Vec::with_capacity(self.guardians.len());
+        for account in accounts {
+            if account.is_signer && self.guardians.contains(account.key) && !approved.contains(account.key) {
+                approved.push(*account.key);
+            }
+        }
+        require!(
+            approved.len() >= self.guardian_threshold as usize,
+            AnectosError::GuardianApprovalMissing
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceConfig {
+    pub version: u8,
+    pub admin: Pubkey,
+    pub acts_mint: Pubkey,
+    /// Minimum ACTS balance (base units) required to open a proposal
+    pub proposal_threshold: u64,
+    /// Voting period length in seconds
+    pub voting_period: i64,
+    /// Minimum yes + no weight for a proposal to be valid
+    pub quorum: u64,
+    /// Share of yes votes (in bps of total votes) required to pass
+    pub approval_threshold_bps: u16,
+    pub proposal_count: u64,
+    /// Voice credits granted to each voter per quadratic voting epoch (0 = disabled)
+    pub voice_credits_per_epoch: u64,
+    /// Quadratic voting epoch length in seconds
+    pub qv_epoch_duration: i64,
+    /// Minimum yes + no quadratic votes for a quadratic proposal to be valid
+    pub qv_quorum: u64,
+    /// ACTS a voter must have staked before an epoch starts to receive its voice credits
+    pub qv_min_stake: u64,
+    pub bump: u8,
+    pub reserved: [u8; 58],
+}
+
+impl GovernanceConfig {
+    /// Start and end of a quadratic voting epoch
+    pub fn qv_epoch_bounds(&self, epoch: u64) -> (i64, i64) {
+        let start = (epoch as i64).saturating_mul(self.qv_epoch_duration);
+        (start, start.saturating_add(self.qv_epoch_duration))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    /// Move `project` in `round` to the Active funding stage
+    WhitelistProject { round: Pubkey, project: Pubkey },
+}
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    FromPrimitive,
+    ToPrimitive,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    InitSpace,
+)]
+pub enum ProposalStatus {
+    #[default]
+    Voting,
+    Defeated,
+    Executed,
+}
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    FromPrimitive,
+    ToPrimitive,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    InitSpace,
+)]
+pub enum VotingMode {
+    #[default]
+    TokenWeighted,
+    /// Casting n votes costs n^2 voice credits
+    Quadratic,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub version: u8,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    #[max_len(MAX_URI_LEN)]
+    pub description_uri: String,
+    pub voting_mode: VotingMode,
+    /// Quadratic voting epoch the proposal was opened in
+    pub epoch: u64,
+    /// ACTS weight or quadratic votes in favour, depending on `voting_mode`
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub voter_count: u32,
+    pub voting_starts_at: i64,
+    pub voting_ends_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+/// ACTS a voter keeps in the governance escrow: seeds = [b"voter_stake", voter]
+#[account]
+#[derive(InitSpace)]
+pub struct VoterStake {
+    pub version: u8,
+    pub voter: Pubkey,
+    /// ACTS (base units) held in escrow for the voter
+    pub amount: u64,
+    /// Time of the latest deposit; the stake only counts on votes opened after it
+    pub deposited_at: i64,
+    /// The stake cannot be withdrawn before this time; votes push it out
+    pub locked_until: i64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl VoterStake {
+    /// Stake held before `opened_at`. A deposit made since resets the whole stake, so
+    /// tokens moved in after a vote opened never count on it.
+    pub fn weight_at(&self, opened_at: i64) -> u64 {
+        if self.deposited_at < opened_at {
+            self.amount
+        } else {
+            0
+        }
+    }
+
+    pub fn lock_until(&mut self, until: i64) {
+        self.locked_until = self.locked_until.max(until);
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub version: u8,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoiceCredits {
+    pub version: u8,
+    pub voter: Pubkey,
+    pub epoch: u64,
+    pub allocated: u64,
+    pub spent: u64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct QuadraticVoteRecord {
+    pub version: u8,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub credits_spent: u64,
+    pub votes: u64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    FromPrimitive,
+    ToPrimitive,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    InitSpace,
+)]
+pub enum MilestoneReviewStatus {
+    #[default]
+    Voting,
+    Approved,
+    Rejected,
+}
+
+/// Contributor vote on a submitted milestone: seeds = [b"milestone_review", project, index]
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneReview {
+    pub version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    /// Incremented on every resubmission so contributors can vote again
+    pub attempt: u8,
+    pub failed_attempts: u8,
+    pub status: MilestoneReviewStatus,
+    pub submitted_at: i64,
+    pub voting_ends_at: i64,
+    pub approve_weight: u128,
+    pub reject_weight: u128,
+    pub voter_count: u32,
+    /// Contributors may challenge an approved milestone until this time
+    pub challenge_ends_at: i64,
+    pub challenge_open: bool,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneVote {
+    pub version: u8,
+    pub review: Pubkey,
+    pub contributor: Pubkey,
+    pub attempt: u8,
+    pub approve: bool,
+    pub weight: u128,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    FromPrimitive,
+    ToPrimitive,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    InitSpace,
+)]
+pub enum ChallengeStatus {
+    #[default]
+    Open,
+    Upheld,
+    Dismissed,
+}
+
+/// Bonded dispute of an approved milestone: seeds = [b"challenge", review, attempt].
+/// The bond is escrowed in this account's lamports until resolution.
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneChallenge {
+    pub version: u8,
+    pub project: Pubkey,
+    pub review: Pubkey,
+    pub milestone_index: u8,
+    pub challenger: Pubkey,
+    pub bond: u64,
+    #[max_len(MAX_URI_LEN)]
+    pub evidence_uri: String,
+    pub status: ChallengeStatus,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub resolved_by: Pubkey,
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+/// Pending handover of a project to `new_owner`: seeds = [b"owner_transfer", project].
+/// Closed when the nominee accepts.
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectOwnerTransfer {
+    pub version: u8,
+    pub project: Pubkey,
+    /// Owner who nominated `new_owner` and paid the rent of this account
+    pub proposed_by: Pubkey,
+    pub new_owner: Pubkey,
+    pub proposed_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 16],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub struct PayoutRecipient {
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+}
+
+/// Split of a project's payouts between partner organisations:
+/// seeds = [b"payout_config", project]. Fixed once the project leaves Planning.
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutConfig {
+    pub version: u8,
+    pub project: Pubkey,
+    /// Shares sum to `BPS_DENOMINATOR`; the last recipient also receives rounding dust
+    #[max_len(MAX_PAYOUT_RECIPIENTS)]
+    pub recipients: Vec<PayoutRecipient>,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+/// Members sharing control of a project: seeds = [b"project_team", project].
+/// Replaces the owner's signature on sensitive actions with `threshold` approvals.
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectTeam {
+    pub version: u8,
+    pub project: Pubkey,
+    #[max_len(MAX_TEAM_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Id of the next proposed action; seeds its `TeamActionProposal`
+    pub action_count: u64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl ProjectTeam {
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+/// Left behind by `close_project`: seeds = [b"project_tombstone", creator]. Its
+/// existence retires the creator's project address, so contribution records of the
+/// closed project can never attach to a new one.
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectTombstone {
+    pub version: u8,
+    pub project: Pubkey,
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum TeamAction {
+    /// Withdraw released donations and matching from the project vault to `recipient`
+    Withdraw { amount: u64, recipient: Pubkey },
+    SubmitMilestone { milestone_index: u8 },
+    SetPayoutSplits {
+        #[max_len(MAX_PAYOUT_RECIPIENTS)]
+        recipients: Vec<PayoutRecipient>,
+    },
+    UpdateMeta {
+        #[max_len(MAX_TITLE_LEN)]
+        title: String,
+        #[max_len(MAX_DESCRIPTION_LEN)]
+        description: String,
+        #[max_len(MAX_URI_LEN)]
+        image_metadata_uri: String,
+        #[max_len(17)]
+        sdg_goals: Vec<SDGGoals>,
+    },
+    UpdateTeam {
+        #[max_len(MAX_TEAM_MEMBERS)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
+
+impl TeamAction {
+    /// Milestone a `SubmitMilestone` action targets, 0 for other actions; seeds the
+    /// review account at execution
+    pub fn milestone_index(&self) -> u8 {
+        match self {
+            TeamAction::SubmitMilestone { milestone_index } => *milestone_index,
+            _ => 0,
+        }
+    }
+}
+
+/// An action awaiting team approvals: seeds = [b"team_action", project, id].
+/// Closed to the proposer when executed.
+#[account]
+#[derive(InitSpace)]
+pub struct TeamActionProposal {
+    pub version: u8,
+    pub project: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: TeamAction,
+    /// Members who approved; the proposer's approval is implied by proposing
+    #[max_len(MAX_TEAM_MEMBERS)]
+    pub approvals: Vec<Pubkey>,
+    pub proposed_at: i64,
+    pub bump: u8,
+    pub reserved: [u8; 32],
+}
+
+impl TeamActionProposal {
+    /// Approvals from keys that are still team members
+    pub fn current_approvals(&self, team: &ProjectTeam) -> u8 {
+        self.approvals.iter().filter(|key| team.is_member(key)).count() as u8
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct Contribution {
+    pub version: u8,
     pub project: Pubkey,
     pub contributor: Pubkey,
     pub total_contributed: u64,
+    pub is_excluded: bool,
+    pub refunded: bool,
     pub bump: u8,
+    pub reserved: [u8; 32],
 }
 
 
 #[event]
 pub struct ContributionMade {
+    pub schema_version: u8,
     pub project: Pubkey,
     pub contributor: Pubkey,
     pub amount: u64,
@@ -150,6 +837,7 @@ pub struct ContributionMade {
 
 #[event]
 pub struct ProjectCreated {
+    pub schema_version: u8,
     pub project: Pubkey,
     pub owner: Pubkey,
     pub target_amount: u64,
@@ -159,8 +847,497 @@ pub struct ProjectCreated {
 
 #[event]
 pub struct MilestoneCompleted {
+    pub schema_version: u8,
     pub project: Pubkey,
     pub milestone_index: u8,
     pub milestone_amount: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ContributionFlagged {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub project: Pubkey,
+    pub contributor: Pubkey,
+    pub flagged_by: Pubkey,
+    pub weight_removed: u128,
+    pub reason_code: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub schema_version: u8,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    pub action: ProposalAction,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub schema_version: u8,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoterStakeUpdated {
+    pub schema_version: u8,
+    pub voter: Pubkey,
+    /// True for a deposit, false for a withdrawal
+    pub deposited: bool,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub locked_until: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub schema_version: u8,
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuadraticVoteCast {
+    pub schema_version: u8,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub credits_spent: u64,
+    pub votes_added: u64,
+    pub total_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneSubmitted {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    pub attempt: u8,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneVoteCast {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    pub contributor: Pubkey,
+    pub approve: bool,
+    pub weight: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneRejected {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    pub failed_attempts: u8,
+    pub refunds_enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContributionWithdrawn {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    /// Contributor's total after the withdrawal
+    pub remaining_contribution: u64,
+    /// Area taken off both the project and the round
+    pub area_removed: u128,
+    pub new_total_funding: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContributionClosed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub contributor: Pubkey,
+    pub rent_reclaimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectClosed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub owner: Pubkey,
+    pub creator: Pubkey,
+    /// Rent of the project and metadata accounts, returned to the creator
+    pub rent_reclaimed: u64,
+    /// Reserve left in the vault, returned to the owner
+    pub vault_swept: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneChallenged {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    pub challenger: Pubkey,
+    pub bond: u64,
+    pub evidence_uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChallengeResolved {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    pub challenger: Pubkey,
+    pub upheld: bool,
+    pub reward: u64,
+    pub resolved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub schema_version: u8,
+    /// `Pubkey::default()` for a global change, otherwise the round
+    pub scope: Pubkey,
+    pub previous_groups: u8,
+    pub paused_groups: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingClaimed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub amount: u64,
+    pub vested_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingCancelled {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub returned: u64,
+    pub vested_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingClawedBack {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub milestone_index: u8,
+    /// Round whose vault received the funds
+    pub destination_round: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundInitialized {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub owner: Pubkey,
+    pub matching_pool: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundClosed {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub owner: Pubkey,
+    pub total_donations: u64,
+    pub matching_pool: u64,
+    pub pool_distributed: u64,
+    /// Rent returned to the owner when the account was closed
+    pub lamports_reclaimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundVaultCreated {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub round_vault: Pubkey,
+    pub rent_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundPoolFunded {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub matching_pool_before: u64,
+    pub matching_pool_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectPoolFunded {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub project: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub matching_pool_before: u64,
+    pub matching_pool_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingPoolSynced {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub matching_pool_before: u64,
+    pub matching_pool_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingSettled {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub project: Pubkey,
+    pub amount: u64,
+    pub matching_allocated_before: u64,
+    pub matching_allocated_after: u64,
+    pub round_vault_before: u64,
+    pub round_vault_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundingStageChanged {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub previous_stage: FundingStage,
+    pub new_stage: FundingStage,
+    pub is_whitelisted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectMetaUpdated {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub revision: u32,
+    pub previous_content_hash: [u8; 32],
+    pub content_hash: [u8; 32],
+    pub funding_stage: FundingStage,
+    /// Round owner who co-signed an update made after the Planning stage
+    pub approved_by: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectOwnerProposed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectOwnerTransferred {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutSplitsSet {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub recipients: Vec<PayoutRecipient>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PayoutDistributed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+    pub amount: u64,
+    /// Total released by this `distribute_payout` call across all recipients
+    pub total_released: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProjectTeamConfigured {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TeamActionProposed {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub team_action: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: TeamAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TeamActionApproved {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub team_action: Pubkey,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TeamActionExecuted {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub team_action: Pubkey,
+    pub id: u64,
+    pub executed_by: Pubkey,
+    pub action: TeamAction,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsWithdrawn {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    /// Account the funds were sent to: the owner, or a team-approved recipient
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub from_donations: u64,
+    pub from_matching: u64,
+    pub current_funding_before: u64,
+    pub current_funding_after: u64,
+    pub matching_unlocked_before: u64,
+    pub matching_unlocked_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AreaMaxUpdated {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub area_max_before: u128,
+    pub area_max_after: u128,
+    pub timestamp: i64,
+}
+
+/// Snapshot of a round's owner-controlled settings after any of them changes
+#[event]
+pub struct RoundSettingsUpdated {
+    pub schema_version: u8,
+    pub round: Pubkey,
+    pub reviewer: Pubkey,
+    pub arbitrator: Pubkey,
+    pub rollover_round: Pubkey,
+    pub vesting_cliff_secs: i64,
+    pub vesting_duration_secs: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneDeadlinesSet {
+    pub schema_version: u8,
+    pub project: Pubkey,
+    pub due_dates: Vec<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceConfigUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub acts_mint: Pubkey,
+    pub proposal_threshold: u64,
+    pub voting_period: i64,
+    pub quorum: u64,
+    pub approval_threshold_bps: u16,
+    pub voice_credits_per_epoch: u64,
+    pub qv_epoch_duration: i64,
+    pub qv_quorum: u64,
+    pub qv_min_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardiansUpdated {
+    pub schema_version: u8,
+    pub guardians: Vec<Pubkey>,
+    pub previous_threshold: u8,
+    pub guardian_threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub schema_version: u8,
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub new_size: u32,
+    /// Lamports the payer added to keep the grown account rent-exempt
+    pub rent_topped_up: u64,
+    pub timestamp: i64,
+}
+
+/// Returned by `audit_round` so monitors can simulate it and decode the result
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RoundAuditReport {
+    pub round: Pubkey,
+    pub projects_checked: u32,
+    pub rent_exempt_minimum: u64,
+    pub round_vault_lamports: u64,
+    /// Unsettled project matching budgets still held by the round vault
+    pub round_vault_liabilities: u64,
+    pub matching_pool: u64,
+    /// Matching settled to the audited projects
+    pub matching_allocated: u64,
+    pub project_vault_lamports: u64,
+    /// Donations plus matching still owed by the audited project vaults
+    pub project_vault_liabilities: u64,
+}
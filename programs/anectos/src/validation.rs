@@ -0,0 +1,81 @@
+//! Checks shared by every instruction that stores project or round metadata, so
+//! oversized or malformed input fails with a specific error instead of a
+//! serialization failure when the account is written.
+use anchor_lang::prelude::*;
+use crate::constants::{ALLOWED_URI_SCHEMES, MAX_DESCRIPTION_LEN, MAX_MILESTONES, MAX_TITLE_LEN, MAX_URI_LEN};
+use crate::error::AnectosError;
+use crate::state::SDGGoals;
+
+pub fn validate_title(title: &str) -> Result<()> {
+    require!(!title.trim().is_empty(), AnectosError::EmptyTitle);
+    require!(title.len() <= MAX_TITLE_LEN as usize, AnectosError::TitleTooLong);
+    Ok(())
+}
+
+pub fn validate_description(description: &str) -> Result<()> {
+    require!(
+        description.len() <= MAX_DESCRIPTION_LEN as usize,
+        AnectosError::DescriptionTooLong
+    );
+    Ok(())
+}
+
+/// Empty, or one of `ALLOWED_URI_SCHEMES` followed by a non-empty location.
+pub fn validate_uri(uri: &str) -> Result<()> {
+    require!(uri.len() <= MAX_URI_LEN as usize, AnectosError::UriTooLong);
+    if uri.is_empty() {
+        return Ok(());
+    }
+    let location = ALLOWED_URI_SCHEMES
+        .iter()
+        .find_map(|scheme| uri.strip_prefix(scheme))
+        .ok_or(AnectosError::UnsupportedUriScheme)?;
+    require!(
+        !location.is_empty() && !location.contains(char::is_whitespace),
+        AnectosError::UnsupportedUriScheme
+    );
+    Ok(())
+}
+
+/// Drops repeated goals, keeping the first occurrence of each.
+pub fn dedup_sdg_goals(goals: Vec<SDGGoals>) -> Vec<SDGGoals> {
+    let mut unique = Vec::with_capacity(goals.len());
+    for goal in goals {
+        if !unique.contains(&goal) {
+            unique.push(goal);
+        }
+    }
+    unique
+}
+
+pub fn validate_milestone_count(milestone_count: u8) -> Result<()> {
+    require!(
+        (1..=MAX_MILESTONES).contains(&milestone_count),
+        AnectosError::InvalidMilestoneCount
+    );
+    Ok(())
+}
+
+/// A round must open before it closes and must not already be over.
+pub fn validate_round_window(start_time: i64, end_time: i64, now: i64) -> Result<()> {
+    require!(start_time < end_time && end_time > now, AnectosError::InvalidRoundWindow);
+    Ok(())
+}
+
+pub fn validate_target_amount(target_amount: u64) -> Result<()> {
+    require!(target_amount > 0, AnectosError::InvalidTargetAmount);
+    Ok(())
+}
+
+/// Validates a project's descriptive fields and returns its de-duplicated SDG goals.
+pub fn validate_project_metadata(
+    title: &str,
+    description: &str,
+    image_metadata_uri: &str,
+    sdg_goals: Vec<SDGGoals>,
+) -> Result<Vec<SDGGoals>> {
+    validate_title(title)?;
+    validate_description(description)?;
+    validate_uri(image_metadata_uri)?;
+    Ok(dedup_sdg_goals(sdg_goals))
+}
@@ -0,0 +1,231 @@
+//! Fixtures shared by the integration tests (see [`svm`]): funded wallets, a
+//! configured protocol, rounds with a funded vault and projects, all created by
+//! running the program's instructions.
+#![allow(dead_code)]
+
+pub mod svm;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anectos::constants::{CONTRIBUTION_WITHDRAWAL_CUTOFF, MILESTONE_CHALLENGE_PERIOD, MILESTONE_VOTING_PERIOD};
+use anectos::{GovernanceConfig, ProposalAction, SDGGoals, VotingMode};
+use anectos_client::{instructions, pda};
+
+pub use svm::{Svm, TxError, TOKEN_PROGRAM};
+
+pub const SOL: u64 = 1_000_000_000;
+
+/// Round start used by the fixtures; the clock starts at 1_700_000_000.
+pub const ROUND_START: i64 = 1_700_000_000;
+pub const ROUND_END: i64 = ROUND_START + 30 * 24 * 60 * 60;
+pub const VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+/// The error a failed instruction returns for an Anchor or program error code.
+pub fn anchor_error(code: impl Into<u32>) -> TxError {
+    TxError::Program(ProgramError::Custom(code.into()))
+}
+
+pub fn funded_wallet(svm: &mut Svm, lamports: u64) -> Pubkey {
+    let wallet = Pubkey::new_unique();
+    svm.airdrop(&wallet, lamports);
+    wallet
+}
+
+/// Creates the protocol config with no guardians and returns its admin, who is
+/// also made the program's upgrade authority.
+pub fn init_protocol(svm: &mut Svm) -> Pubkey {
+    let admin = funded_wallet(svm, 10 * SOL);
+    svm.set_upgrade_authority(Some(admin));
+    svm.process(&instructions::initialize_protocol_config(admin, vec![], 0), &[admin])
+        .expect("initialize_protocol_config");
+    admin
+}
+
+/// Creates a round owned by `owner` and its round vault; returns the round address.
+pub fn init_round(svm: &mut Svm, owner: Pubkey) -> Pubkey {
+    let round = Pubkey::new_unique();
+    let ix = instructions::initialize_funding_round(owner, round, 0, ROUND_START, ROUND_END, "ipfs://round".into());
+    svm.process(&ix, &[owner, round]).expect("initialize_funding_round");
+    svm.process(&instructions::create_round_vault(owner, round), &[owner])
+        .expect("create_round_vault");
+    round
+}
+
+/// Creates a project in `round` for a fresh owner; returns the owner.
+pub fn create_project(svm: &mut Svm, round: Pubkey, target_amount: u64, milestone_count: u8) -> Pubkey {
+    let owner = funded_wallet(svm, 10 * SOL);
+    let ix = instructions::create_project(
+        owner,
+        round,
+        "Clean water".into(),
+        "Wells for three villages".into(),
+        target_amount,
+        milestone_count,
+        vec![SDGGoals::CleanWaterAndSanitation],
+        "ipfs://project".into(),
+    );
+    svm.process(&ix, &[owner]).expect("create_project");
+    owner
+}
+
+/// A configured protocol with one round whose vault exists.
+pub struct RoundFixture {
+    pub svm: Svm,
+    pub admin: Pubkey,
+    pub round_owner: Pubkey,
+    pub round: Pubkey,
+}
+
+impl RoundFixture {
+    pub fn new() -> Self {
+        let mut svm = Svm::new();
+        let admin = init_protocol(&mut svm);
+        let round_owner = funded_wallet(&mut svm, 100 * SOL);
+        let round = init_round(&mut svm, round_owner);
+        Self { svm, admin, round_owner, round }
+    }
+
+    pub fn wallet(&mut self, lamports: u64) -> Pubkey {
+        funded_wallet(&mut self.svm, lamports)
+    }
+
+    pub fn create_project(&mut self, target_amount: u64, milestone_count: u8) -> Pubkey {
+        create_project(&mut self.svm, self.round, target_amount, milestone_count)
+    }
+
+    pub fn round_vault(&self) -> Pubkey {
+        pda::round_vault_pda(&self.round).0
+    }
+
+    pub fn contribute(&mut self, user: Pubkey, project_owner: Pubkey, amount: u64) -> Result<(), TxError> {
+        self.svm
+            .process(&instructions::contribute(user, self.round, project_owner, amount), &[user])
+    }
+
+    /// Funds `project_owner`'s matching budget from the round owner's wallet.
+    pub fn fund_project_pool(&mut self, project_owner: Pubkey, amount: u64) -> Result<(), TxError> {
+        let funder = self.round_owner;
+        self.svm.process(
+            &instructions::fund_project_pool(funder, self.round, project_owner, amount),
+            &[funder],
+        )
+    }
+
+    pub fn settle(&mut self, project_owner: Pubkey) -> Result<(), TxError> {
+        self.svm
+            .process(&instructions::settle_matching_for_project(project_owner, self.round), &[project_owner])
+    }
+
+    /// Moves the clock to the withdrawal cutoff unless it is already past it, so
+    /// contributions are final and milestone reviews may open.
+    pub fn close_withdrawals(&mut self) {
+        let cutoff = ROUND_END - CONTRIBUTION_WITHDRAWAL_CUTOFF;
+        if self.svm.clock().unix_timestamp < cutoff {
+            self.svm.warp_to(cutoff);
+        }
+    }
+
+    /// Approves milestones `0..count` of `project_owner` by `voter`'s vote and moves the
+    /// clock past their challenge windows, releasing their amounts for withdrawal.
+    /// Closes contribution withdrawals first.
+    pub fn release_milestones(&mut self, project_owner: Pubkey, voter: Pubkey, count: u8) {
+        self.close_withdrawals();
+        let round = self.round;
+        for index in 0..count {
+            self.svm
+                .process(&instructions::submit_milestone(project_owner, round, index), &[project_owner])
+                .expect("submit_milestone");
+            self.svm
+                .process(&instructions::vote_milestone(voter, project_owner, index, 0, true), &[voter])
+                .expect("vote_milestone");
+        }
+        self.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        for index in 0..count {
+            self.svm
+                .process(&instructions::complete_milestone(voter, project_owner, index), &[voter])
+                .expect("complete_milestone");
+        }
+        self.svm.advance_clock(MILESTONE_CHALLENGE_PERIOD);
+    }
+
+    pub fn withdraw(&mut self, project_owner: Pubkey, amount: u64) -> Result<(), TxError> {
+        self.svm.process(
+            &instructions::distribute_funds_to_owner(project_owner, self.round, amount),
+            &[project_owner],
+        )
+    }
+}
+
+impl Default for RoundFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A round fixture plus governance created by the upgrade authority, with a 100 ACTS
+/// quorum, simple majority and an ACTS escrow account.
+pub struct GovernanceFixture {
+    pub fixture: RoundFixture,
+    pub mint: Pubkey,
+    pub escrow: Pubkey,
+}
+
+impl GovernanceFixture {
+    pub fn new() -> Self {
+        let mut fixture = RoundFixture::new();
+        let admin = fixture.admin;
+        let mint = Pubkey::new_unique();
+        let ix = instructions::initialize_governance(admin, mint, 1, VOTING_PERIOD, 100, 5_000);
+        fixture.svm.process(&ix, &[admin]).expect("initialize_governance");
+        let escrow = Pubkey::new_unique();
+        fixture.svm.set_token_account(escrow, mint, pda::governance_escrow_pda().0, 0);
+        Self { fixture, mint, escrow }
+    }
+
+    /// A wallet holding `amount` ACTS; returns (wallet, token account).
+    pub fn holder(&mut self, amount: u64) -> (Pubkey, Pubkey) {
+        let wallet = self.fixture.wallet(SOL);
+        let token_account = Pubkey::new_unique();
+        self.fixture.svm.set_token_account(token_account, self.mint, wallet, amount);
+        (wallet, token_account)
+    }
+
+    pub fn stake(&mut self, (voter, token_account): (Pubkey, Pubkey), amount: u64) -> Result<(), TxError> {
+        let ix = instructions::deposit_governance_stake(voter, token_account, self.escrow, TOKEN_PROGRAM, amount);
+        self.fixture.svm.process(&ix, &[voter])
+    }
+
+    pub fn unstake(&mut self, (voter, token_account): (Pubkey, Pubkey), amount: u64) -> Result<(), TxError> {
+        let ix = instructions::withdraw_governance_stake(voter, token_account, self.escrow, TOKEN_PROGRAM, amount);
+        self.fixture.svm.process(&ix, &[voter])
+    }
+
+    pub fn config(&self) -> GovernanceConfig {
+        self.fixture.svm.get(&pda::governance_pda().0)
+    }
+
+    /// Opens a proposal to whitelist a fresh project; returns (id, project creator).
+    pub fn propose_whitelist(&mut self, proposer: (Pubkey, Pubkey), voting_mode: VotingMode) -> (u64, Pubkey) {
+        let creator = self.fixture.create_project(100 * SOL, 2);
+        let action = ProposalAction::WhitelistProject {
+            round: self.fixture.round,
+            project: pda::project_pda(&creator).0,
+        };
+        let id = self.config().proposal_count;
+        let ix = instructions::create_proposal(proposer.0, proposer.1, id, action, "ipfs://proposal".into(), voting_mode);
+        self.fixture.svm.process(&ix, &[proposer.0]).expect("create_proposal");
+        (id, creator)
+    }
+
+    pub fn execute(&mut self, id: u64, creator: Pubkey) -> Result<(), TxError> {
+        let executor = self.fixture.admin;
+        let ix = instructions::execute_proposal(executor, id, Some((creator, self.fixture.round)));
+        self.fixture.svm.process(&ix, &[executor])
+    }
+}
+
+impl Default for GovernanceFixture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
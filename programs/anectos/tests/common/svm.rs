@@ -0,0 +1,866 @@
+//! Runtime for the integration tests.
+//!
+//! When the program has been built with `anchor build`, `target/deploy/anectos.so`
+//! (or `anectos.so` under `SBF_OUT_DIR`) is loaded into LiteSVM and the tests run the
+//! client's instructions against the deployed binary, with the real system and SPL
+//! token programs, account rules and compute budget.
+//!
+//! Without the binary the tests fall back to a hand-written emulator, unless
+//! `ANECTOS_REQUIRE_SBF` is set, in which case they fail instead. The emulator compiles
+//! the program for the host and calls its `entry` directly. Accounts are serialized in
+//! the loader's aligned input format, so `AccountInfo`s, reallocs and lamport/owner
+//! writes behave as on-chain. CPIs, sysvars and logs are served through
+//! `program_stubs`; the system program, SPL token transfers and the upgradeable
+//! loader's ProgramData account are emulated natively. After each program invocation
+//! the runtime's account rules are enforced (read-only accounts, owner-only debits and
+//! data writes, lamport conservation, rent-exempt transitions), but compute budgets and
+//! other SBF-only limits are not modelled.
+//!
+//! On both backends signatures are not verified: `process` only checks that every
+//! signer the instruction asks for was passed in. Transaction fees are paid by a
+//! separate account, so wallets only move by what the program transfers.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Once, OnceLock};
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::{AccountInfo, Clock, Pubkey, Rent};
+use anchor_lang::solana_program::entrypoint::{
+    deserialize, ProgramResult, BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::message::Message;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::{bpf_loader, pubkey};
+use anchor_lang::{system_program, AccountDeserialize, AccountSerialize, AnchorDeserialize, Discriminator, Event};
+use anectos_client::accounts::AccountFetcher;
+use anectos_client::ClientError;
+use litesvm::LiteSVM;
+use solana_account::{Account as SolanaAccount, ReadableAccount};
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+const NATIVE_LOADER: Pubkey = pubkey!("NativeLoader1111111111111111111111111111111");
+const BPF_LOADER_UPGRADEABLE: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+pub const TOKEN_PROGRAM: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const SUCCESS: u64 = 0;
+/// Set to fail instead of falling back to the emulator when the binary is missing
+const REQUIRE_SBF: &str = "ANECTOS_REQUIRE_SBF";
+const FEE_PAYER_LAMPORTS: u64 = 1_000_000_000_000;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Account {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxError {
+    /// A signer required by the instruction was not passed to `process`
+    MissingSignature(Pubkey),
+    /// The program (or a program it invoked) returned an error
+    Program(ProgramError),
+    /// A runtime account rule was broken
+    Runtime(String),
+}
+
+/// An account as seen by one instruction: merged privileges and a working copy.
+struct Slot {
+    key: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    account: Account,
+}
+
+/// A program invocation in progress; `pre` is the state its changes are checked against.
+struct Frame {
+    program_id: Pubkey,
+    pre: HashMap<Pubkey, (Account, bool)>,
+}
+
+#[derive(Default)]
+struct Invocation {
+    clock: Clock,
+    rent: Rent,
+    frames: Vec<Frame>,
+    logs: Vec<String>,
+    events: Vec<Vec<u8>>,
+    /// First failure inside a CPI; it fails the transaction even if the caller ignores it
+    cpi_error: Option<TxError>,
+}
+
+thread_local! {
+    static INVOCATION: RefCell<Invocation> = RefCell::new(Invocation::default());
+}
+
+fn with_invocation<R>(f: impl FnOnce(&mut Invocation) -> R) -> R {
+    INVOCATION.with(|invocation| f(&mut invocation.borrow_mut()))
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, message: &str) {
+        with_invocation(|i| i.logs.push(message.to_string()));
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_signed(instruction, account_infos, signers_seeds)
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = with_invocation(|i| i.clock.clone());
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Clock, clock) };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let rent = with_invocation(|i| i.rent.clone());
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Rent, rent) };
+        SUCCESS
+    }
+}
+
+pub struct Svm {
+    backend: Backend,
+    clock: Clock,
+    rent: Rent,
+    trace: Trace,
+}
+
+enum Backend {
+    /// The deployed program in LiteSVM; `payer` pays the transaction fees
+    Sbf { vm: Box<LiteSVM>, payer: Pubkey },
+    Emulator { accounts: HashMap<Pubkey, Account> },
+}
+
+/// Logs and `emit_cpi!` event data of the last processed instruction.
+#[derive(Default)]
+struct Trace {
+    logs: Vec<String>,
+    events: Vec<Vec<u8>>,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    pub fn new() -> Self {
+        let clock = Clock { slot: 1, unix_timestamp: 1_700_000_000, ..Clock::default() };
+        let backend = match sbf_program() {
+            Some(elf) => {
+                let mut vm = LiteSVM::new()
+                    .with_sigverify(false)
+                    .with_blockhash_check(false)
+                    .with_transaction_history(0);
+                vm.add_program(anectos::ID, elf).expect("loading the program binary");
+                let payer = Pubkey::new_unique();
+                vm.airdrop(&payer, FEE_PAYER_LAMPORTS).expect("funding the fee payer");
+                Backend::Sbf { vm: Box::new(vm), payer }
+            }
+            None => {
+                static STUBS: Once = Once::new();
+                STUBS.call_once(|| {
+                    set_syscall_stubs(Box::new(Stubs));
+                });
+
+                let mut accounts = HashMap::new();
+                let program = |owner| Account { lamports: 1, data: vec![], owner, executable: true };
+                accounts.insert(system_program::ID, program(NATIVE_LOADER));
+                accounts.insert(anectos::ID, program(bpf_loader::ID));
+                accounts.insert(TOKEN_PROGRAM, program(bpf_loader::ID));
+                Backend::Emulator { accounts }
+            }
+        };
+        Self { backend, clock, rent: Rent::default(), trace: Trace::default() }
+    }
+
+    pub fn airdrop(&mut self, address: &Pubkey, lamports: u64) {
+        let mut account = self.account(address).unwrap_or_default();
+        account.lamports += lamports;
+        self.set_account(*address, account);
+    }
+
+    pub fn account(&self, address: &Pubkey) -> Option<Account> {
+        match &self.backend {
+            Backend::Sbf { vm, .. } => vm.get_account(address).filter(|a| a.lamports > 0).as_ref().map(to_account),
+            Backend::Emulator { accounts } => accounts.get(address).cloned(),
+        }
+    }
+
+    pub fn set_account(&mut self, address: Pubkey, account: Account) {
+        match &mut self.backend {
+            Backend::Sbf { vm, .. } => {
+                let Account { lamports, data, owner, executable } = account;
+                let account = SolanaAccount { lamports, data, owner, executable, rent_epoch: u64::MAX };
+                vm.set_account(address, account).unwrap_or_else(|e| panic!("setting {address}: {e}"));
+            }
+            Backend::Emulator { accounts } => {
+                accounts.insert(address, account);
+            }
+        }
+    }
+
+    /// Records `authority` as the program's upgrade authority in its ProgramData account.
+    pub fn set_upgrade_authority(&mut self, authority: Option<Pubkey>) {
+        // bincode of UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address }
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(authority.is_some() as u8);
+        data.extend_from_slice(authority.unwrap_or_default().as_ref());
+        let address = Pubkey::find_program_address(&[anectos::ID.as_ref()], &BPF_LOADER_UPGRADEABLE).0;
+        let lamports = self.rent.minimum_balance(data.len());
+        self.set_account(address, Account { lamports, data, owner: BPF_LOADER_UPGRADEABLE, executable: false });
+    }
+
+    /// Creates an SPL token account holding `amount` of `mint` for `owner`.
+    pub fn set_token_account(&mut self, address: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let mut data = vec![0; token::ACCOUNT_LEN];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data[108] = token::INITIALIZED;
+        let lamports = self.rent.minimum_balance(data.len());
+        self.set_account(address, Account { lamports, data, owner: TOKEN_PROGRAM, executable: false });
+    }
+
+    pub fn token_balance(&self, address: &Pubkey) -> u64 {
+        self.account(address).map_or(0, |a| read_u64(&a.data, 64))
+    }
+
+    pub fn lamports(&self, address: &Pubkey) -> u64 {
+        self.account(address).map_or(0, |a| a.lamports)
+    }
+
+    /// Deserializes an Anchor account, panicking if it is missing or malformed.
+    pub fn get<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.account(address).unwrap_or_else(|| panic!("account {address} not found"));
+        T::try_deserialize(&mut account.data.as_slice()).unwrap_or_else(|e| panic!("decoding {address}: {e}"))
+    }
+
+    /// Overwrites an Anchor account's data in place, e.g. to plant a broken invariant.
+    pub fn put<T: AccountSerialize>(&mut self, address: &Pubkey, value: &T) {
+        let mut account = self.account(address).unwrap_or_else(|| panic!("account {address} not found"));
+        let mut data = Vec::with_capacity(account.data.len());
+        value.try_serialize(&mut data).unwrap_or_else(|e| panic!("encoding {address}: {e}"));
+        account.data[..data.len()].copy_from_slice(&data);
+        self.set_account(*address, account);
+    }
+
+    pub fn rent(&self) -> &Rent {
+        &self.rent
+    }
+
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        self.clock.unix_timestamp = unix_timestamp;
+    }
+
+    pub fn advance_clock(&mut self, seconds: i64) {
+        self.clock.unix_timestamp += seconds;
+    }
+
+    /// Logs of the last processed instruction.
+    pub fn logs(&self) -> &[String] {
+        &self.trace.logs
+    }
+
+    /// Events emitted through `emit_cpi!` by the last processed instruction.
+    pub fn events<T: Event + Discriminator + AnchorDeserialize>(&self) -> Vec<T> {
+        self.trace
+            .events
+            .iter()
+            .filter_map(|data| data.strip_prefix(T::DISCRIMINATOR))
+            .map(|mut data| T::deserialize(&mut data).expect("event data"))
+            .collect()
+    }
+
+    /// Runs `instruction` as a single-instruction transaction signed by `signers`.
+    /// Account changes are committed only if it succeeds.
+    pub fn process(&mut self, instruction: &Instruction, signers: &[Pubkey]) -> Result<(), TxError> {
+        for meta in &instruction.accounts {
+            if meta.is_signer && !signers.contains(&meta.pubkey) {
+                return Err(TxError::MissingSignature(meta.pubkey));
+            }
+        }
+
+        let (result, trace) = match &mut self.backend {
+            Backend::Sbf { vm, payer } => send(vm, *payer, &self.clock, &self.rent, instruction),
+            Backend::Emulator { accounts } => emulate(accounts, &self.clock, &self.rent, instruction),
+        };
+        self.trace = trace;
+        self.clock.slot += 1;
+        result
+    }
+}
+
+impl AccountFetcher for Svm {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        Ok(self.account(address).map(|a| a.data))
+    }
+}
+
+/// The program binary, read once; `None` if it has not been built.
+fn sbf_program() -> Option<&'static [u8]> {
+    static PROGRAM: OnceLock<Option<Vec<u8>>> = OnceLock::new();
+    PROGRAM
+        .get_or_init(|| {
+            let dir = std::env::var_os("SBF_OUT_DIR").map_or_else(
+                || Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy"),
+                PathBuf::from,
+            );
+            let path = dir.join("anectos.so");
+            match std::fs::read(&path) {
+                Ok(elf) => Some(elf),
+                Err(_) if std::env::var_os(REQUIRE_SBF).is_none() => None,
+                Err(e) => panic!("{REQUIRE_SBF} is set but {} cannot be read: {e}", path.display()),
+            }
+        })
+        .as_deref()
+}
+
+/// Sends `instruction` to LiteSVM at `clock`, with `payer` paying the fee.
+fn send(
+    vm: &mut LiteSVM,
+    payer: Pubkey,
+    clock: &Clock,
+    rent: &Rent,
+    instruction: &Instruction,
+) -> (Result<(), TxError>, Trace) {
+    vm.set_sysvar(clock);
+    let message = Message::new(std::slice::from_ref(instruction), Some(&payer));
+    let keys = message.account_keys.clone();
+    let transaction = Transaction::new_unsigned(message);
+    // LiteSVM skips the rent check for accounts without data, so a transfer could leave
+    // a wallet or vault below the rent-exempt minimum; the validator refuses that
+    if let Ok(simulated) = vm.simulate_transaction(transaction.clone()) {
+        for (key, post) in &simulated.post_accounts {
+            let pre = vm.get_account(key).as_ref().map(to_account).unwrap_or_default();
+            if let Err(error) = check_rent_transition(rent, key, &pre, &to_account(post)) {
+                return (Err(error), Trace { logs: simulated.meta.logs, ..Trace::default() });
+            }
+        }
+    }
+    let (meta, error) = match vm.send_transaction(transaction) {
+        Ok(meta) => (meta, None),
+        Err(failed) => (failed.meta, Some(failed.err)),
+    };
+    let events = meta
+        .inner_instructions
+        .iter()
+        .flatten()
+        .filter(|inner| keys[usize::from(inner.instruction.program_id_index)] == anectos::ID)
+        .filter_map(|inner| inner.instruction.data.strip_prefix(EVENT_IX_TAG_LE))
+        .map(<[u8]>::to_vec)
+        .collect();
+    let result = match error {
+        None => Ok(()),
+        Some(TransactionError::InstructionError(_, error)) => {
+            Err(ProgramError::try_from(error).map_or_else(|e| TxError::Runtime(e.to_string()), TxError::Program))
+        }
+        Some(error) => Err(TxError::Runtime(error.to_string())),
+    };
+    (result, Trace { logs: meta.logs, events })
+}
+
+fn to_account(account: &impl ReadableAccount) -> Account {
+    Account {
+        lamports: account.lamports(),
+        data: account.data().to_vec(),
+        owner: *account.owner(),
+        executable: account.executable(),
+    }
+}
+
+/// Runs `instruction` on the emulator, committing its changes to `accounts` if it succeeds.
+fn emulate(
+    accounts: &mut HashMap<Pubkey, Account>,
+    clock: &Clock,
+    rent: &Rent,
+    instruction: &Instruction,
+) -> (Result<(), TxError>, Trace) {
+    let loaded = slots_for(instruction, |meta| Ok(accounts.get(&meta.pubkey).cloned().unwrap_or_default()));
+    let (mut slots, order) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => return (Err(TxError::Program(error)), Trace::default()),
+    };
+    let pre: Vec<Account> = slots.iter().map(|s| s.account.clone()).collect();
+
+    with_invocation(|i| *i = Invocation { clock: clock.clone(), rent: rent.clone(), ..Invocation::default() });
+    let result = invoke(&instruction.program_id, &mut slots, &order, &instruction.data);
+    let invocation = with_invocation(std::mem::take);
+    let trace = Trace { logs: invocation.logs, events: invocation.events };
+    if let Some(error) = invocation.cpi_error {
+        return (Err(error), trace);
+    }
+    let result = result.and_then(|()| {
+        let before: u128 = pre.iter().map(|a| a.lamports as u128).sum();
+        let after: u128 = slots.iter().map(|s| s.account.lamports as u128).sum();
+        if before != after {
+            return Err(TxError::Runtime(format!("lamports not conserved: {before} -> {after}")));
+        }
+        for (slot, pre) in slots.iter().zip(&pre) {
+            if slot.is_writable {
+                check_rent_transition(rent, &slot.key, pre, &slot.account)?;
+            }
+        }
+        Ok(())
+    });
+    if result.is_ok() {
+        for slot in slots {
+            if slot.account.lamports == 0 {
+                accounts.remove(&slot.key);
+            } else {
+                accounts.insert(slot.key, slot.account);
+            }
+        }
+    }
+    (result, trace)
+}
+
+/// Dedups the instruction's accounts, merging privileges, and maps each meta to its slot.
+fn slots_for(
+    instruction: &Instruction,
+    mut load: impl FnMut(&anchor_lang::solana_program::instruction::AccountMeta) -> Result<Account, ProgramError>,
+) -> Result<(Vec<Slot>, Vec<usize>), ProgramError> {
+    let mut slots: Vec<Slot> = vec![];
+    let mut order = vec![];
+    for meta in &instruction.accounts {
+        match slots.iter().position(|s| s.key == meta.pubkey) {
+            Some(index) => {
+                slots[index].is_signer |= meta.is_signer;
+                slots[index].is_writable |= meta.is_writable;
+                order.push(index);
+            }
+            None => {
+                slots.push(Slot {
+                    key: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                    account: load(meta)?,
+                });
+                order.push(slots.len() - 1);
+            }
+        }
+    }
+    Ok((slots, order))
+}
+
+fn invoke(program_id: &Pubkey, slots: &mut [Slot], order: &[usize], data: &[u8]) -> Result<(), TxError> {
+    if *program_id == system_program::ID {
+        system::process(slots, order, data).map_err(TxError::Program)
+    } else if *program_id == TOKEN_PROGRAM {
+        token::process(slots, order, data).map_err(TxError::Program)
+    } else if *program_id == anectos::ID {
+        run_program(program_id, slots, order, data)
+    } else {
+        Err(TxError::Runtime(format!("program {program_id} is not loaded")))
+    }
+}
+
+/// Serializes the accounts as the BPF loader does, runs the program and reads the
+/// results back out of the buffer.
+fn run_program(program_id: &Pubkey, slots: &mut [Slot], order: &[usize], data: &[u8]) -> Result<(), TxError> {
+    let mut input = Input::default();
+    input.u64(order.len() as u64);
+    let mut offsets = vec![None; slots.len()];
+    for &index in order {
+        if offsets[index].is_some() {
+            let first = order.iter().position(|&i| i == index).unwrap();
+            input.bytes(&[first as u8, 0, 0, 0, 0, 0, 0, 0]);
+            continue;
+        }
+        let slot = &slots[index];
+        input.bytes(&[NON_DUP_MARKER, slot.is_signer as u8, slot.is_writable as u8, slot.account.executable as u8]);
+        input.bytes(&[0; 4]);
+        input.bytes(slot.key.as_ref());
+        offsets[index] = Some(input.len());
+        input.bytes(slot.account.owner.as_ref());
+        input.u64(slot.account.lamports);
+        input.u64(slot.account.data.len() as u64);
+        input.bytes(&slot.account.data);
+        input.bytes(&vec![0; MAX_PERMITTED_DATA_INCREASE]);
+        input.align(BPF_ALIGN_OF_U128);
+        input.u64(u64::MAX); // rent epoch
+    }
+    input.u64(data.len() as u64);
+    input.bytes(data);
+    input.bytes(program_id.as_ref());
+
+    let pre = slots
+        .iter()
+        .map(|s| (s.key, (s.account.clone(), s.is_writable)))
+        .collect();
+    with_invocation(|i| i.frames.push(Frame { program_id: *program_id, pre }));
+    let result = {
+        let mut buffer = input.finish();
+        // SAFETY: the buffer is laid out exactly as `deserialize` expects and outlives the call
+        let result = unsafe {
+            let (program_id, infos, data) = deserialize(buffer.as_mut_ptr() as *mut u8);
+            anectos::entry(program_id, &infos, data)
+        };
+        let bytes = as_bytes(&buffer);
+        for (slot, offset) in slots.iter_mut().zip(&offsets) {
+            let offset = offset.expect("every slot is serialized");
+            slot.account.owner = Pubkey::try_from(&bytes[offset..offset + 32]).unwrap();
+            slot.account.lamports = read_u64(bytes, offset + 32);
+            let len = read_u64(bytes, offset + 40) as usize;
+            slot.account.data = bytes[offset + 48..offset + 48 + len].to_vec();
+        }
+        result
+    };
+    let frame = with_invocation(|i| i.frames.pop()).expect("frame pushed above");
+    result.map_err(TxError::Program)?;
+    for slot in slots.iter() {
+        let (pre, writable) = &frame.pre[&slot.key];
+        check_changes(&frame.program_id, &slot.key, pre, *writable, &slot.account)?;
+    }
+    Ok(())
+}
+
+/// Cross-program invocation from the running program.
+fn invoke_signed(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let caller = with_invocation(|i| i.frames.last().map(|f| f.program_id)).expect("CPI outside a program");
+    let pda_signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &caller))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+
+    let find = |key: &Pubkey| account_infos.iter().find(|info| info.key == key);
+    for meta in &instruction.accounts {
+        let info = find(&meta.pubkey).ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let escalated = (meta.is_signer && !info.is_signer && !pda_signers.contains(&meta.pubkey))
+            || (meta.is_writable && !info.is_writable);
+        if escalated {
+            return fail(TxError::Runtime(format!("CPI privilege escalation for {}", meta.pubkey)));
+        }
+    }
+    let (mut slots, order) = slots_for(instruction, |meta| Ok(snapshot(find(&meta.pubkey).unwrap())))?;
+
+    // The caller's own changes must be legal before the callee sees them
+    let checked = with_invocation(|i| {
+        let frame = i.frames.last().unwrap();
+        slots.iter().try_for_each(|slot| {
+            let (pre, writable) = &frame.pre[&slot.key];
+            check_changes(&frame.program_id, &slot.key, pre, *writable, &slot.account)
+        })
+    });
+    if let Err(error) = checked {
+        return fail(error);
+    }
+
+    if instruction.program_id == anectos::ID {
+        if let Some(event) = instruction.data.strip_prefix(EVENT_IX_TAG_LE) {
+            with_invocation(|i| i.events.push(event.to_vec()));
+        }
+    }
+    if let Err(error) = invoke(&instruction.program_id, &mut slots, &order, &instruction.data) {
+        return fail(error);
+    }
+
+    for slot in &slots {
+        let info = find(&slot.key).unwrap();
+        if slot.is_writable {
+            **info.try_borrow_mut_lamports()? = slot.account.lamports;
+            if *info.owner != slot.account.owner {
+                info.assign(&slot.account.owner);
+            }
+            info.resize(slot.account.data.len())?;
+            info.try_borrow_mut_data()?.copy_from_slice(&slot.account.data);
+        }
+    }
+    with_invocation(|i| {
+        let frame = i.frames.last_mut().unwrap();
+        for slot in slots {
+            frame.pre.get_mut(&slot.key).unwrap().0 = slot.account;
+        }
+    });
+    Ok(())
+}
+
+/// Records a CPI failure so it aborts the transaction, as on-chain.
+fn fail(error: TxError) -> ProgramResult {
+    let returned = match &error {
+        TxError::Program(e) => e.clone(),
+        _ => ProgramError::InvalidAccountData,
+    };
+    with_invocation(|i| {
+        i.cpi_error.get_or_insert(error);
+    });
+    Err(returned)
+}
+
+fn snapshot(info: &AccountInfo) -> Account {
+    Account {
+        lamports: info.lamports(),
+        data: info.data.borrow().to_vec(),
+        owner: *info.owner,
+        executable: info.executable,
+    }
+}
+
+/// Account rules the runtime enforces on `program_id` after it runs.
+fn check_changes(program_id: &Pubkey, key: &Pubkey, pre: &Account, writable: bool, post: &Account) -> Result<(), TxError> {
+    let violation = |rule: &str| Err(TxError::Runtime(format!("{rule}: {key}")));
+    if pre == post {
+        return Ok(());
+    }
+    if !writable {
+        return violation("read-only account modified");
+    }
+    if pre.executable || post.executable {
+        return violation("executable account modified");
+    }
+    let owned = pre.owner == *program_id;
+    if pre.owner != post.owner && (!owned || post.data.iter().any(|b| *b != 0)) {
+        return violation("illegal owner change");
+    }
+    if post.lamports < pre.lamports && !owned {
+        return violation("lamports debited from an account the program does not own");
+    }
+    if pre.data != post.data && !owned {
+        return violation("data modified in an account the program does not own");
+    }
+    Ok(())
+}
+
+fn check_rent_transition(rent: &Rent, key: &Pubkey, pre: &Account, post: &Account) -> Result<(), TxError> {
+    let rent_paying = |a: &Account| a.lamports > 0 && !rent.is_exempt(a.lamports, a.data.len());
+    if !rent_paying(post) {
+        return Ok(());
+    }
+    // An account may stay rent-paying only if it shrinks in lamports and keeps its size
+    if rent_paying(pre) && post.data.len() == pre.data.len() && post.lamports <= pre.lamports {
+        return Ok(());
+    }
+    Err(TxError::Runtime(format!(
+        "insufficient funds for rent: {key} holds {} lamports for {} bytes",
+        post.lamports,
+        post.data.len()
+    )))
+}
+
+/// 8-byte aligned instruction input buffer.
+#[derive(Default)]
+struct Input {
+    bytes: Vec<u8>,
+}
+
+impl Input {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn align(&mut self, align: usize) {
+        let padding = self.bytes.len().next_multiple_of(align) - self.bytes.len();
+        self.bytes(&vec![0; padding]);
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn finish(mut self) -> Vec<u64> {
+        self.align(8);
+        self.bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+}
+
+fn as_bytes(buffer: &[u64]) -> &[u8] {
+    // SAFETY: u64 has no padding and u8 has alignment 1
+    unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const u8, buffer.len() * 8) }
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+/// Native emulation of the system program instructions Anchor programs use.
+mod system {
+    use super::*;
+
+    const ACCOUNT_ALREADY_IN_USE: u32 = 0;
+    const RESULT_WITH_NEGATIVE_LAMPORTS: u32 = 1;
+    const MAX_PERMITTED_DATA_LENGTH: u64 = 10 * 1024 * 1024;
+
+    struct Reader<'a>(&'a [u8]);
+
+    impl Reader<'_> {
+        fn take<const N: usize>(&mut self) -> Result<[u8; N], ProgramError> {
+            let (head, rest) = self.0.split_at_checked(N).ok_or(ProgramError::InvalidInstructionData)?;
+            self.0 = rest;
+            Ok(head.try_into().unwrap())
+        }
+
+        fn u64(&mut self) -> Result<u64, ProgramError> {
+            self.take().map(u64::from_le_bytes)
+        }
+
+        fn pubkey(&mut self) -> Result<Pubkey, ProgramError> {
+            self.take().map(Pubkey::new_from_array)
+        }
+    }
+
+    pub(super) fn process(slots: &mut [Slot], order: &[usize], data: &[u8]) -> Result<(), ProgramError> {
+        let mut reader = Reader(data);
+        let account = |n: usize| order.get(n).copied().ok_or(ProgramError::NotEnoughAccountKeys);
+        match u32::from_le_bytes(reader.take()?) {
+            // CreateAccount { lamports, space, owner }
+            0 => {
+                let (lamports, space, owner) = (reader.u64()?, reader.u64()?, reader.pubkey()?);
+                let (from, to) = (account(0)?, account(1)?);
+                let target = &slots[to].account;
+                if target.lamports > 0 || !target.data.is_empty() || target.owner != system_program::ID {
+                    return Err(ProgramError::Custom(ACCOUNT_ALREADY_IN_USE));
+                }
+                allocate(slots, to, space, owner)?;
+                transfer(slots, from, to, lamports)
+            }
+            // Assign { owner }
+            1 => {
+                let owner = reader.pubkey()?;
+                let target = account(0)?;
+                require_signer(&slots[target])?;
+                if slots[target].account.owner != owner {
+                    require_system_owned(&slots[target])?;
+                    slots[target].account.owner = owner;
+                }
+                Ok(())
+            }
+            // Transfer { lamports }
+            2 => {
+                let lamports = reader.u64()?;
+                transfer(slots, account(0)?, account(1)?, lamports)
+            }
+            // Allocate { space }
+            8 => {
+                let space = reader.u64()?;
+                let target = account(0)?;
+                let owner = slots[target].account.owner;
+                allocate(slots, target, space, owner)
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    fn require_signer(slot: &Slot) -> Result<(), ProgramError> {
+        if slot.is_signer {
+            Ok(())
+        } else {
+            Err(ProgramError::MissingRequiredSignature)
+        }
+    }
+
+    fn require_system_owned(slot: &Slot) -> Result<(), ProgramError> {
+        if slot.account.owner == system_program::ID {
+            Ok(())
+        } else {
+            Err(ProgramError::IncorrectProgramId)
+        }
+    }
+
+    fn allocate(slots: &mut [Slot], target: usize, space: u64, owner: Pubkey) -> Result<(), ProgramError> {
+        let slot = &mut slots[target];
+        require_signer(slot)?;
+        if !slot.account.data.is_empty() || slot.account.owner != system_program::ID {
+            return Err(ProgramError::Custom(ACCOUNT_ALREADY_IN_USE));
+        }
+        if space > MAX_PERMITTED_DATA_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+        slot.account.data = vec![0; space as usize];
+        slot.account.owner = owner;
+        Ok(())
+    }
+
+    fn transfer(slots: &mut [Slot], from: usize, to: usize, lamports: u64) -> Result<(), ProgramError> {
+        require_signer(&slots[from])?;
+        if !slots[from].account.data.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        require_system_owned(&slots[from])?;
+        if slots[from].account.lamports < lamports {
+            return Err(ProgramError::Custom(RESULT_WITH_NEGATIVE_LAMPORTS));
+        }
+        slots[from].account.lamports -= lamports;
+        slots[to].account.lamports = slots[to]
+            .account
+            .lamports
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Native emulation of the SPL token `Transfer` instruction.
+mod token {
+    use super::*;
+
+    pub(super) const ACCOUNT_LEN: usize = 165;
+    pub(super) const INITIALIZED: u8 = 1;
+    const TRANSFER: u8 = 3;
+    const INSUFFICIENT_FUNDS: u32 = 1;
+    const OWNER_MISMATCH: u32 = 4;
+    const MINT_MISMATCH: u32 = 3;
+
+    pub(super) fn process(slots: &mut [Slot], order: &[usize], data: &[u8]) -> Result<(), ProgramError> {
+        let (&tag, amount) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+        if tag != TRANSFER || amount.len() != 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let amount = read_u64(amount, 0);
+        let account = |n: usize| order.get(n).copied().ok_or(ProgramError::NotEnoughAccountKeys);
+        let (from, to, authority) = (account(0)?, account(1)?, account(2)?);
+        for index in [from, to] {
+            let token = &slots[index].account;
+            if token.owner != TOKEN_PROGRAM || token.data.len() != ACCOUNT_LEN || token.data[108] != INITIALIZED {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        if slots[from].account.data[..32] != slots[to].account.data[..32] {
+            return Err(ProgramError::Custom(MINT_MISMATCH));
+        }
+        if slots[from].account.data[32..64] != *slots[authority].key.as_ref() {
+            return Err(ProgramError::Custom(OWNER_MISMATCH));
+        }
+        if !slots[authority].is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        let balance = read_u64(&slots[from].account.data, 64);
+        let remaining = balance.checked_sub(amount).ok_or(ProgramError::Custom(INSUFFICIENT_FUNDS))?;
+        slots[from].account.data[64..72].copy_from_slice(&remaining.to_le_bytes());
+        let credited = read_u64(&slots[to].account.data, 64)
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        slots[to].account.data[64..72].copy_from_slice(&credited.to_le_bytes());
+        Ok(())
+    }
+}
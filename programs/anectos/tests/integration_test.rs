@@ -1,3 +1,6 @@
+#![allow(clippy::useless_vec, clippy::absurd_extreme_comparisons)]
+
+use anectos::math::{isqrt_u128, milestone_amount};
 use anectos::state::Milestone;
 
 // Simple unit tests for the business logic
@@ -11,26 +14,7 @@ mod tests {
         let target_amount = 1000u64;
         let milestone_count = 4u8;
         
-        // Simulate milestone calculation (arithmetic progression)
-        let n = milestone_count as u64;
-        let step = target_amount / (n * (n + 1) / 2);
-        let mut milestones = Vec::new();
-        let mut sum = 0;
-        
-        for i in 1..=n {
-            let amount = step * i;
-            milestones.push(Milestone { 
-                amount, 
-                is_achieved: false 
-            });
-            sum += amount;
-        }
-        
-        // Adjust for rounding errors
-        if sum != target_amount {
-            let diff = target_amount - sum;
-            milestones.last_mut().unwrap().amount += diff;
-        }
+        let milestones = calculate_milestones(target_amount, milestone_count);
         
         // Verify milestones are in increasing order
         for i in 1..milestones.len() {
@@ -57,7 +41,7 @@ mod tests {
         let mut total_area = 0u128;
         
         for contribution in &contributions {
-            let area_contribution = (*contribution as f64).sqrt() as u128;
+            let area_contribution = isqrt_u128(*contribution as u128);
             total_area += area_contribution;
         }
         
@@ -70,7 +54,7 @@ mod tests {
         
         println!("✅ Quadratic funding calculation test passed");
         println!("Contributions: {:?}", contributions);
-        println!("Individual areas: {:?}", contributions.iter().map(|c| (*c as f64).sqrt() as u128).collect::<Vec<_>>());
+        println!("Individual areas: {:?}", contributions.iter().map(|c| isqrt_u128(*c as u128)).collect::<Vec<_>>());
         println!("Total area: {}, Matching pool: {}", total_area, matching_pool);
     }
 
@@ -78,10 +62,10 @@ mod tests {
     fn test_milestone_validation_logic() {
         // Test milestone completion validation
         let milestones = vec![
-            Milestone { amount: 250, is_achieved: false },
-            Milestone { amount: 500, is_achieved: false },
-            Milestone { amount: 750, is_achieved: false },
-            Milestone { amount: 1000, is_achieved: false },
+            Milestone { amount: 250, is_achieved: false, ..Default::default() },
+            Milestone { amount: 500, is_achieved: false, ..Default::default() },
+            Milestone { amount: 750, is_achieved: false, ..Default::default() },
+            Milestone { amount: 1000, is_achieved: false, ..Default::default() },
         ];
         
         // Test which milestones can be completed with different funding levels
@@ -120,7 +104,7 @@ mod tests {
         let max_safe_contribution = 1_000_000_000u64; // 1 billion
         
         // Test square root calculation doesn't panic
-        let area = (max_safe_contribution as f64).sqrt() as u128;
+        let area = isqrt_u128(max_safe_contribution as u128);
         assert!(area > 0, "Area calculation should work for large numbers");
         
         // Test that area squared doesn't overflow u64
@@ -135,16 +119,16 @@ mod tests {
     #[test]
     fn test_edge_cases() {
         // Test zero contribution
-        let zero_area = (0u64 as f64).sqrt() as u128;
+        let zero_area = isqrt_u128(0);
         assert_eq!(zero_area, 0, "Zero contribution should result in zero area");
         
         // Test single milestone
-        let single_milestone = vec![Milestone { amount: 1000, is_achieved: false }];
+        let single_milestone = vec![Milestone { amount: 1000, is_achieved: false, ..Default::default() }];
         assert_eq!(single_milestone.len(), 1, "Single milestone should work");
         
         // Test very small contribution
         let small_contribution = 1u64;
-        let small_area = (small_contribution as f64).sqrt() as u128;
+        let small_area = isqrt_u128(small_contribution as u128);
         assert_eq!(small_area, 1, "Small contribution should result in area of 1");
         
         println!("✅ Edge cases test passed");
@@ -153,33 +137,19 @@ mod tests {
 
 // Integration test helper functions
 pub fn calculate_milestones(target_amount: u64, milestone_count: u8) -> Vec<Milestone> {
-    let n = milestone_count as u64;
-    let step = target_amount / (n * (n + 1) / 2);
-    let mut milestones = Vec::new();
-    let mut sum = 0;
-    
-    for i in 1..=n {
-        let amount = step * i;
-        milestones.push(Milestone { 
-            amount, 
-            is_achieved: false 
-        });
-        sum += amount;
-    }
-    
-    // Adjust for rounding errors
-    if sum != target_amount {
-        let diff = target_amount - sum;
-        milestones.last_mut().unwrap().amount += diff;
-    }
-    
-    milestones
+    (0..milestone_count)
+        .map(|index| Milestone {
+            amount: milestone_amount(target_amount, milestone_count, index).unwrap(),
+            is_achieved: false,
+            ..Default::default()
+        })
+        .collect()
 }
 
 pub fn calculate_quadratic_area(contributions: &[u64]) -> u128 {
     contributions
         .iter()
-        .map(|contribution| (*contribution as f64).sqrt() as u128)
+        .map(|contribution| isqrt_u128(*contribution as u128))
         .sum()
 }
 
@@ -255,3 +225,257 @@ mod integration_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod ledger_tests {
+    use anectos::state::{Ledger, LedgerEntry};
+
+    #[test]
+    fn test_ledger_derives_held_balances() {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry::DonationReceived(1_000)).unwrap();
+        ledger.record(LedgerEntry::MatchingAllocated(400)).unwrap();
+        ledger.record(LedgerEntry::DonationWithdrawn(300)).unwrap();
+        ledger.record(LedgerEntry::RefundPaid(100)).unwrap();
+        ledger.record(LedgerEntry::MatchingWithdrawn(150)).unwrap();
+
+        assert_eq!(ledger.donations_held(), 600);
+        assert_eq!(ledger.matching_held(), 250);
+        // Received/allocated totals are never decremented by outflows
+        assert_eq!(ledger.donations_received, 1_000);
+        assert_eq!(ledger.matching_allocated, 400);
+    }
+
+    #[test]
+    fn test_ledger_rejects_overflow() {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry::DonationReceived(u64::MAX)).unwrap();
+        assert!(ledger.record(LedgerEntry::DonationReceived(1)).is_err());
+    }
+
+    #[test]
+    fn test_cancelled_donations_net_out_of_received() {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry::DonationReceived(1_000)).unwrap();
+        ledger.record(LedgerEntry::DonationCancelled(400)).unwrap();
+        assert_eq!((ledger.donations_received, ledger.donations_held()), (600, 600));
+        assert!(ledger.record(LedgerEntry::DonationCancelled(601)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use anectos::error::AnectosError;
+    use anectos::state::SDGGoals;
+    use anectos::validation::*;
+
+    #[test]
+    fn test_uri_schemes_are_allowlisted() {
+        for uri in ["", "ipfs://bafy", "ar://tx", "https://anectos.org/meta.json"] {
+            assert!(validate_uri(uri).is_ok(), "{uri}");
+        }
+        for uri in ["http://anectos.org", "javascript:alert(1)", "ipfs://", "ipfs://a b", "IPFS://bafy"] {
+            assert_eq!(validate_uri(uri).unwrap_err(), AnectosError::UnsupportedUriScheme.into(), "{uri}");
+        }
+        let long = format!("ipfs://{}", "a".repeat(200));
+        assert_eq!(validate_uri(&long).unwrap_err(), AnectosError::UriTooLong.into());
+    }
+
+    #[test]
+    fn test_titles_must_be_non_blank_and_bounded() {
+        assert_eq!(validate_title("  ").unwrap_err(), AnectosError::EmptyTitle.into());
+        assert!(validate_title(&"t".repeat(64)).is_ok());
+        assert_eq!(validate_title(&"t".repeat(65)).unwrap_err(), AnectosError::TitleTooLong.into());
+        // Limits are in bytes, as stored on chain
+        assert_eq!(validate_title(&"é".repeat(33)).unwrap_err(), AnectosError::TitleTooLong.into());
+    }
+
+    #[test]
+    fn test_sdg_goals_keep_first_occurrence_order() {
+        let goals = vec![
+            SDGGoals::ClimateAction,
+            SDGGoals::NoPoverty,
+            SDGGoals::ClimateAction,
+            SDGGoals::NoPoverty,
+        ];
+        assert!(dedup_sdg_goals(goals) == vec![SDGGoals::ClimateAction, SDGGoals::NoPoverty]);
+    }
+}
+
+#[cfg(test)]
+mod pause_tests {
+    use anectos::constants::{ACCOUNT_VERSION, PAUSE_ALL, PAUSE_CONTRIBUTIONS, PAUSE_SETTLEMENT, PAUSE_WITHDRAWALS};
+    use anectos::state::ProtocolConfig;
+    use anchor_lang::prelude::Pubkey;
+
+    fn config(paused_groups: u8) -> ProtocolConfig {
+        ProtocolConfig {
+            version: ACCOUNT_VERSION,
+            admin: Pubkey::new_unique(),
+            paused_groups,
+            guardians: vec![],
+            guardian_threshold: 0,
+            bump: 255,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn test_pause_groups_are_independent() {
+        let cfg = config(PAUSE_SETTLEMENT);
+        assert!(cfg.require_not_paused(PAUSE_SETTLEMENT, None).is_err());
+        assert!(cfg.require_not_paused(PAUSE_CONTRIBUTIONS, None).is_ok());
+        assert!(cfg.require_not_paused(PAUSE_WITHDRAWALS, None).is_ok());
+    }
+
+    #[test]
+    fn test_pause_all_freezes_every_group() {
+        let cfg = config(PAUSE_ALL);
+        for group in [PAUSE_CONTRIBUTIONS, PAUSE_WITHDRAWALS, PAUSE_SETTLEMENT] {
+            assert!(cfg.require_not_paused(group, None).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod vesting_tests {
+    use anectos::constants::ACCOUNT_VERSION;
+    use anectos::state::{Ledger, Project};
+    use anchor_lang::prelude::Pubkey;
+
+    fn project_with_schedule(allocated: u64, start: i64, cliff: i64, end: i64) -> Project {
+        Project {
+            version: ACCOUNT_VERSION,
+            project_id: Pubkey::new_unique(),
+            round: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            target_amount: 0,
+            area: 0,
+            current_funding: 0,
+            matching_unlocked: allocated,
+            matching_pool: allocated,
+            pool_distributed: allocated,
+            is_whitelisted: true,
+            has_withdrawn: false,
+            milestone_count: 0,
+            milestones: vec![],
+            ledger: Ledger { matching_allocated: allocated, ..Default::default() },
+            refunds_enabled: false,
+            refund_pool: 0,
+            disputed_amount: 0,
+            vesting_start: start,
+            vesting_cliff: cliff,
+            vesting_end: end,
+            bump: 255,
+            creator: Pubkey::default(),
+            has_payout_splits: false,
+            has_team: false,
+            bonds_forfeited: 0,
+            reserved: [0; 22],
+        }
+    }
+
+    #[test]
+    fn test_matching_vests_linearly_after_cliff() {
+        let project = project_with_schedule(1_000, 100, 200, 1_100);
+        assert_eq!(project.vested_matching(150), 0, "nothing vests before the cliff");
+        assert_eq!(project.vested_matching(200), 100);
+        assert_eq!(project.vested_matching(600), 500);
+        assert_eq!(project.vested_matching(5_000), 1_000);
+    }
+
+    #[test]
+    fn test_zero_duration_vests_immediately() {
+        let project = project_with_schedule(1_000, 100, 100, 100);
+        assert_eq!(project.vested_matching(100), 1_000);
+    }
+
+    #[test]
+    fn test_claimable_excludes_withdrawn_matching() {
+        let mut project = project_with_schedule(1_000, 0, 0, 1_000);
+        project.ledger.matching_withdrawn = 300;
+        assert_eq!(project.claimable_matching(500), 200);
+        assert_eq!(project.claimable_matching(200), 0);
+    }
+}
+
+#[cfg(test)]
+mod math_tests {
+    use anectos::math::{area_delta, bps_of, matching_allocation, pro_rata, MathError};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_isqrt_boundaries() {
+        assert_eq!(isqrt_u128(0), 0);
+        assert_eq!(isqrt_u128(1), 1);
+        assert_eq!(isqrt_u128(3), 1);
+        assert_eq!(isqrt_u128(4), 2);
+        assert_eq!(isqrt_u128(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt_u128((u64::MAX as u128) * (u64::MAX as u128)), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_math_errors() {
+        assert_eq!(milestone_amount(1_000, 4, 4), Err(MathError::OutOfRange));
+        assert_eq!(bps_of(1_000, 10_001), Err(MathError::InvalidBasisPoints));
+        assert_eq!(pro_rata(1_000, 1, 0), Err(MathError::DivisionByZero));
+        assert_eq!(pro_rata(u64::MAX, u64::MAX, 1), Err(MathError::Overflow));
+        assert_eq!(matching_allocation(1_000, u128::MAX, u128::MAX, 0), Err(MathError::Overflow));
+    }
+
+    proptest! {
+        #[test]
+        fn isqrt_is_floor(x in any::<u128>()) {
+            let r = isqrt_u128(x);
+            prop_assert!(r * r <= x);
+            // (r + 1)^2 overflowing u128 means it is certainly above x
+            prop_assert!((r + 1).checked_mul(r + 1).is_none_or(|next| next > x));
+        }
+
+        #[test]
+        fn area_deltas_telescope(amounts in prop::collection::vec(1u64..=u32::MAX as u64, 1..20)) {
+            let mut total = 0u128;
+            let mut area = 0u128;
+            for amount in amounts {
+                let next = total + amount as u128;
+                area += area_delta(total, next);
+                total = next;
+            }
+            prop_assert_eq!(area, isqrt_u128(total));
+        }
+
+        #[test]
+        fn allocations_never_exceed_pool(
+            pool in any::<u64>(),
+            areas in prop::collection::vec(0u128..=u32::MAX as u128, 1..16),
+            area_max in 0u128..=u64::MAX as u128,
+        ) {
+            let round_area: u128 = areas.iter().sum();
+            let mut allocated = 0u128;
+            for area in &areas {
+                allocated += matching_allocation(pool, *area, round_area, area_max).unwrap();
+            }
+            prop_assert!(allocated <= pool as u128);
+        }
+
+        #[test]
+        fn milestones_sum_to_target(target in any::<u64>(), count in 1u8..=20) {
+            let amounts: Vec<u64> = (0..count).map(|i| milestone_amount(target, count, i).unwrap()).collect();
+            prop_assert_eq!(amounts.iter().map(|a| *a as u128).sum::<u128>(), target as u128);
+            prop_assert!(amounts.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        #[test]
+        fn bps_never_exceeds_amount(amount in any::<u64>(), bps in 0u16..=10_000) {
+            prop_assert!(bps_of(amount, bps).unwrap() <= amount);
+        }
+
+        #[test]
+        fn pro_rata_never_exceeds_amount(amount in any::<u64>(), a in any::<u64>(), b in any::<u64>()) {
+            let (numerator, denominator) = (a.min(b), a.max(b).max(1));
+            prop_assert!(pro_rata(amount, numerator, denominator).unwrap() <= amount);
+        }
+    }
+}
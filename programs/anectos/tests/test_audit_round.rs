@@ -0,0 +1,66 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{FundingRound, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// Audits the round with the (project, vault) pairs of `creators`.
+fn audit(fixture: &mut RoundFixture, creators: &[Pubkey]) -> Result<(), TxError> {
+    let ix = instructions::audit_round(fixture.round, creators);
+    let payer = fixture.round_owner;
+    fixture.svm.process(&ix, &[payer])
+}
+
+/// Two projects with 5 SOL budgets and donations, the round pool set from its vault
+/// and project A settled.
+fn settled_round() -> (RoundFixture, Pubkey, Pubkey) {
+    let mut fixture = RoundFixture::new();
+    let (a, b) = (fixture.create_project(10 * SOL, 4), fixture.create_project(10 * SOL, 4));
+    let alice = fixture.wallet(20 * SOL);
+    for creator in [a, b] {
+        fixture.fund_project_pool(creator, 5 * SOL).unwrap();
+        fixture.contribute(alice, creator, 5 * SOL).unwrap();
+    }
+    let (round, round_owner) = (fixture.round, fixture.round_owner);
+    fixture
+        .svm
+        .process(&instructions::set_matching_pool(round_owner, round), &[round_owner])
+        .unwrap();
+    fixture.settle(a).unwrap();
+    (fixture, a, b)
+}
+
+#[cfg(test)]
+mod audit_round_tests {
+    use super::*;
+
+    #[test]
+    fn test_consistent_round_passes_with_any_subset_of_projects() {
+        let (mut fixture, a, b) = settled_round();
+        audit(&mut fixture, &[a, b]).unwrap();
+        audit(&mut fixture, &[b]).unwrap();
+        audit(&mut fixture, &[]).unwrap();
+        assert_eq!(audit(&mut fixture, &[a, a]), Err(anchor_error(AnectosError::InvalidAuditAccounts)));
+    }
+
+    #[test]
+    fn test_over_allocation_is_caught_without_the_offending_project() {
+        let (mut fixture, a, b) = settled_round();
+        // Plant a settlement on B beyond the whole round's pool, booked on both ledgers
+        let address = pda::project_pda(&b).0;
+        let mut project: Project = fixture.svm.get(&address);
+        let mut round: FundingRound = fixture.svm.get(&fixture.round);
+        let excess = round.matching_pool + 1 - round.ledger.matching_allocated;
+        project.ledger.matching_allocated += excess;
+        round.ledger.matching_allocated += excess;
+        fixture.svm.put(&address, &project);
+        let round_address = fixture.round;
+        fixture.svm.put(&round_address, &round);
+
+        assert_eq!(audit(&mut fixture, &[a, b]), Err(anchor_error(AnectosError::MatchingExceedsPool)));
+        // Leaving B out does not hide it: the round ledger still exceeds the pool
+        assert_eq!(audit(&mut fixture, &[a]), Err(anchor_error(AnectosError::MatchingExceedsPool)));
+    }
+}
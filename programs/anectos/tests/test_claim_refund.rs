@@ -0,0 +1,122 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::{MAX_MILESTONE_FAILURES, MILESTONE_VOTING_PERIOD, PAUSE_ALL};
+use anectos::error::AnectosError;
+use anectos::state::{Contribution, Project, RefundClaimed};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// A 10 SOL project over 4 milestones backed by 3 SOL from Alice and 1 SOL from Bob.
+/// The owner takes out the released 1 SOL of milestone 0, then milestone 1 is voted
+/// down until the project refunds what is left. Returns (owner, alice, bob).
+fn refunding_project(fixture: &mut RoundFixture) -> (Pubkey, Pubkey, Pubkey) {
+    let owner = fixture.create_project(10 * SOL, 4);
+    let (alice, bob) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+    fixture.contribute(alice, owner, 3 * SOL).unwrap();
+    fixture.contribute(bob, owner, SOL).unwrap();
+    fixture.release_milestones(owner, alice, 1);
+    fixture.withdraw(owner, SOL).unwrap();
+
+    for attempt in 0..MAX_MILESTONE_FAILURES {
+        fixture
+            .svm
+            .process(&instructions::submit_milestone(owner, fixture.round, 1), &[owner])
+            .unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(alice, owner, 1, attempt, false), &[alice])
+            .unwrap();
+        fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        fixture
+            .svm
+            .process(&instructions::complete_milestone(alice, owner, 1), &[alice])
+            .unwrap();
+    }
+    (owner, alice, bob)
+}
+
+fn claim(fixture: &mut RoundFixture, contributor: Pubkey, owner: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::claim_refund(contributor, fixture.round, owner);
+    fixture.svm.process(&ix, &[contributor])
+}
+
+fn contribution(fixture: &RoundFixture, owner: &Pubkey, contributor: &Pubkey) -> Contribution {
+    let project = pda::project_pda(owner).0;
+    fixture.svm.get(&pda::contribution_pda(&project, contributor).0)
+}
+
+#[cfg(test)]
+mod claim_refund_tests {
+    use super::*;
+
+    #[test]
+    fn test_refunds_open_once_a_milestone_fails_for_good() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, SOL).unwrap();
+        assert_eq!(
+            claim(&mut fixture, alice, owner),
+            Err(anchor_error(AnectosError::RefundsNotEnabled))
+        );
+        assert!(!contribution(&fixture, &owner, &alice).refunded);
+    }
+
+    #[test]
+    fn test_refund_is_the_pro_rata_share_of_what_is_left() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, bob) = refunding_project(&mut fixture);
+        let project: Project = fixture.svm.get(&pda::project_pda(&owner).0);
+        assert!(project.refunds_enabled);
+        // The 3 SOL still held less the vault's rent reserve, out of 4 SOL donated
+        let pool = 3 * SOL - fixture.svm.rent().minimum_balance(0);
+        assert_eq!((project.refund_pool, project.ledger.donations_received), (pool, 4 * SOL));
+
+        let vault = pda::vault_pda(&owner).0;
+        let vault_before = fixture.svm.lamports(&vault);
+        claim(&mut fixture, alice, owner).unwrap();
+        let refunded = fixture.svm.events::<RefundClaimed>().pop().unwrap();
+        assert_eq!((refunded.contributor, refunded.amount), (alice, pool * 3 / 4));
+        assert_eq!(fixture.svm.lamports(&vault), vault_before - pool * 3 / 4);
+        assert!(contribution(&fixture, &owner, &alice).refunded);
+        assert!(!contribution(&fixture, &owner, &bob).refunded);
+
+        claim(&mut fixture, bob, owner).unwrap();
+        assert_eq!(fixture.svm.events::<RefundClaimed>().pop().unwrap().amount, pool / 4);
+        let project: Project = fixture.svm.get(&pda::project_pda(&owner).0);
+        assert_eq!(project.ledger.refunds_paid, pool * 3 / 4 + pool / 4);
+    }
+
+    #[test]
+    fn test_refund_is_claimed_once() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, _) = refunding_project(&mut fixture);
+        claim(&mut fixture, alice, owner).unwrap();
+        let vault_after_claim = fixture.svm.lamports(&pda::vault_pda(&owner).0);
+        assert_eq!(
+            claim(&mut fixture, alice, owner),
+            Err(anchor_error(AnectosError::AlreadyRefunded))
+        );
+        assert_eq!(fixture.svm.lamports(&pda::vault_pda(&owner).0), vault_after_claim);
+    }
+
+    #[test]
+    fn test_refunds_stay_open_while_the_protocol_is_paused() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, bob) = refunding_project(&mut fixture);
+        let (admin, round) = (fixture.admin, fixture.round);
+        fixture
+            .svm
+            .process(&instructions::set_pause(admin, Some(round), PAUSE_ALL, &[]), &[admin])
+            .unwrap();
+        claim(&mut fixture, alice, owner).unwrap();
+
+        fixture
+            .svm
+            .process(&instructions::set_pause(admin, None, PAUSE_ALL, &[]), &[admin])
+            .unwrap();
+        claim(&mut fixture, bob, owner).unwrap();
+        assert!(contribution(&fixture, &owner, &bob).refunded);
+    }
+}
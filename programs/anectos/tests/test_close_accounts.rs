@@ -0,0 +1,171 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::PAUSE_WITHDRAWALS;
+use anectos::error::AnectosError;
+use anectos::state::{Contribution, ContributionClosed, FundingStage, ProjectClosed, ProjectTombstone, SDGGoals};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn set_stage(fixture: &mut RoundFixture, owner: Pubkey, stage: FundingStage) {
+    let (round, round_owner) = (fixture.round, fixture.round_owner);
+    fixture
+        .svm
+        .process(&instructions::change_project_funding_stage(round_owner, owner, round, stage), &[round_owner])
+        .unwrap();
+}
+
+fn close_contribution(fixture: &mut RoundFixture, contributor: Pubkey, creator: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::close_contribution(contributor, creator, fixture.round);
+    fixture.svm.process(&ix, &[contributor])
+}
+
+fn close_project(fixture: &mut RoundFixture, owner: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::close_project(owner, owner, fixture.round);
+    fixture.svm.process(&ix, &[owner])
+}
+
+fn recreate_project(fixture: &mut RoundFixture, owner: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::create_project(
+        owner,
+        fixture.round,
+        "Clean water".into(),
+        "Wells for three villages".into(),
+        2 * SOL,
+        1,
+        vec![SDGGoals::CleanWaterAndSanitation],
+        String::new(),
+    );
+    fixture.svm.process(&ix, &[owner])
+}
+
+#[cfg(test)]
+mod close_accounts_tests {
+    use super::*;
+
+    #[test]
+    fn test_contribution_closes_once_round_and_project_are_final() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 2 * SOL).unwrap();
+        let contribution = pda::contribution_pda(&pda::project_pda(&owner).0, &alice).0;
+        let rent = fixture.svm.lamports(&contribution);
+
+        assert_eq!(
+            close_contribution(&mut fixture, alice, owner),
+            Err(anchor_error(AnectosError::RoundNotFinalized))
+        );
+        set_stage(&mut fixture, owner, FundingStage::Completed);
+        assert_eq!(
+            close_contribution(&mut fixture, alice, owner),
+            Err(anchor_error(AnectosError::RoundNotFinalized))
+        );
+
+        let (round, round_owner) = (fixture.round, fixture.round_owner);
+        fixture
+            .svm
+            .process(&instructions::close_round(round_owner, round), &[round_owner])
+            .unwrap();
+        let before = fixture.svm.lamports(&alice);
+        close_contribution(&mut fixture, alice, owner).unwrap();
+        assert!(fixture.svm.account(&contribution).is_none());
+        assert_eq!(fixture.svm.lamports(&alice), before + rent);
+        let event = fixture.svm.events::<ContributionClosed>().pop().unwrap();
+        assert_eq!((event.contributor, event.rent_reclaimed), (alice, rent));
+    }
+
+    #[test]
+    fn test_project_closes_once_final_and_drained() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(2 * SOL, 1);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 2 * SOL).unwrap();
+        let project = pda::project_pda(&owner).0;
+        let meta = pda::project_metadata_pda(&project).0;
+        let rent = fixture.svm.lamports(&project) + fixture.svm.lamports(&meta);
+
+        assert_eq!(
+            close_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::ProjectNotFinalized))
+        );
+        set_stage(&mut fixture, owner, FundingStage::Completed);
+        assert_eq!(
+            close_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::VaultNotEmpty))
+        );
+
+        fixture.release_milestones(owner, alice, 1);
+        fixture.withdraw(owner, 2 * SOL).unwrap();
+        // Closing moves lamports, so it waits out a withdrawal pause
+        let admin = fixture.admin;
+        fixture.svm.process(&instructions::set_pause(admin, None, PAUSE_WITHDRAWALS, &[]), &[admin]).unwrap();
+        assert_eq!(
+            close_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::OperationPaused))
+        );
+        fixture.svm.process(&instructions::set_pause(admin, None, 0, &[]), &[admin]).unwrap();
+        let before = fixture.svm.lamports(&owner);
+        close_project(&mut fixture, owner).unwrap();
+        assert!(fixture.svm.account(&project).is_none() && fixture.svm.account(&meta).is_none());
+        // The owner pays for the tombstone out of the returned rent
+        let tombstone = pda::project_tombstone_pda(&owner).0;
+        assert_eq!(fixture.svm.get::<ProjectTombstone>(&tombstone).project, project);
+        assert_eq!(fixture.svm.lamports(&owner), before + rent - fixture.svm.lamports(&tombstone));
+        let event = fixture.svm.events::<ProjectClosed>().pop().unwrap();
+        assert_eq!((event.rent_reclaimed, event.vault_swept), (rent, 0));
+
+        // The address is retired, so Alice's record never attaches to a new project
+        assert_eq!(
+            recreate_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::ProjectAddressRetired))
+        );
+        assert_eq!(
+            fixture.contribute(alice, owner, SOL),
+            Err(anchor_error(ErrorCode::AccountNotInitialized))
+        );
+        let record: Contribution = fixture.svm.get(&pda::contribution_pda(&project, &alice).0);
+        assert_eq!(record.total_contributed, 2 * SOL);
+
+        // Records of a closed project no longer wait for the round
+        close_contribution(&mut fixture, alice, owner).unwrap();
+    }
+
+    #[test]
+    fn test_project_waits_for_matching_budgeted_in_an_open_round() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(2 * SOL, 1);
+        fixture.fund_project_pool(owner, SOL).unwrap();
+        set_stage(&mut fixture, owner, FundingStage::Rejected);
+        assert_eq!(
+            close_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::MatchingUnsettled))
+        );
+
+        // Once the round is gone nothing more can be settled
+        let (round, round_owner) = (fixture.round, fixture.round_owner);
+        fixture
+            .svm
+            .process(&instructions::close_round(round_owner, round), &[round_owner])
+            .unwrap();
+        close_project(&mut fixture, owner).unwrap();
+    }
+
+    #[test]
+    fn test_team_project_does_not_close_on_the_owner_key() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(2 * SOL, 1);
+        let member = fixture.wallet(SOL);
+        fixture
+            .svm
+            .process(&instructions::configure_project_team(owner, owner, vec![owner, member], 2), &[owner])
+            .unwrap();
+        set_stage(&mut fixture, owner, FundingStage::Rejected);
+        assert_eq!(
+            close_project(&mut fixture, owner),
+            Err(anchor_error(AnectosError::TeamApprovalRequired))
+        );
+        assert!(fixture.svm.account(&pda::project_pda(&owner).0).is_some());
+    }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anectos::state::FundingRound;
+use anectos_client::instructions;
+use common::*;
+
+#[cfg(test)]
+mod close_round_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_round_owner_can_close_the_round() {
+        let mut fixture = RoundFixture::new();
+        let mallory = fixture.wallet(SOL);
+        let round = fixture.round;
+        assert_eq!(
+            fixture.svm.process(&instructions::close_round(mallory, round), &[mallory]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        assert!(fixture.svm.get::<FundingRound>(&round).is_active);
+    }
+
+    #[test]
+    fn test_closed_round_rejects_contributions() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let (round_owner, round) = (fixture.round_owner, fixture.round);
+        fixture
+            .svm
+            .process(&instructions::close_round(round_owner, round), &[round_owner])
+            .unwrap();
+
+        let alice = fixture.wallet(2 * SOL);
+        assert_eq!(
+            fixture.contribute(alice, owner, SOL),
+            Err(anchor_error(ErrorCode::AccountNotInitialized))
+        );
+        assert_eq!(
+            fixture.svm.process(&instructions::close_round(round_owner, round), &[round_owner]),
+            Err(anchor_error(ErrorCode::AccountNotInitialized))
+        );
+    }
+}
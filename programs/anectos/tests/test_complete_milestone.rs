@@ -0,0 +1,180 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::{MILESTONE_CHALLENGE_PERIOD, MILESTONE_VOTING_PERIOD};
+use anectos::error::AnectosError;
+use anectos::state::{MilestoneCompleted, MilestoneReview, MilestoneReviewStatus, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// A project with a 10 SOL target over 4 milestones (1, 2, 3 and 4 SOL) and two
+/// contributors of 4 SOL and 1 SOL, i.e. vote weights of sqrt(4e9) and sqrt(1e9),
+/// past the point where those contributions can be withdrawn.
+fn funded_project(fixture: &mut RoundFixture) -> (Pubkey, Pubkey, Pubkey) {
+    let owner = fixture.create_project(10 * SOL, 4);
+    let (whale, minnow) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+    fixture.contribute(whale, owner, 4 * SOL).unwrap();
+    fixture.contribute(minnow, owner, SOL).unwrap();
+    fixture.close_withdrawals();
+    (owner, whale, minnow)
+}
+
+fn review(fixture: &RoundFixture, owner: &Pubkey, milestone_index: u8) -> MilestoneReview {
+    let project = pda::project_pda(owner).0;
+    fixture.svm.get(&pda::milestone_review_pda(&project, milestone_index).0)
+}
+
+#[cfg(test)]
+mod complete_milestone_tests {
+    use super::*;
+
+    #[test]
+    fn test_approved_milestone_completes_after_voting_window() {
+        let mut fixture = RoundFixture::new();
+        let (owner, whale, minnow) = funded_project(&mut fixture);
+        fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 0), &[owner]).unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(whale, owner, 0, 0, true), &[whale])
+            .unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(minnow, owner, 0, 0, false), &[minnow])
+            .unwrap();
+
+        let finalize = instructions::complete_milestone(minnow, owner, 0);
+        assert_eq!(
+            fixture.svm.process(&finalize, &[minnow]),
+            Err(anchor_error(AnectosError::VotingStillOpen))
+        );
+
+        fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        fixture.svm.process(&finalize, &[minnow]).unwrap();
+        let project: Project = fixture.svm.get(&pda::project_pda(&owner).0);
+        assert!(project.milestones[0].is_achieved);
+        assert!(review(&fixture, &owner, 0).status == MilestoneReviewStatus::Approved);
+        let completed = fixture.svm.events::<MilestoneCompleted>();
+        assert_eq!((completed[0].milestone_index, completed[0].milestone_amount), (0, SOL));
+    }
+
+    #[test]
+    fn test_funds_release_with_approved_milestones_after_the_challenge_window() {
+        let mut fixture = RoundFixture::new();
+        let (owner, whale, _) = funded_project(&mut fixture);
+        assert_eq!(
+            fixture.withdraw(owner, SOL),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+
+        fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 0), &[owner]).unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(whale, owner, 0, 0, true), &[whale])
+            .unwrap();
+        fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        fixture
+            .svm
+            .process(&instructions::complete_milestone(whale, owner, 0), &[whale])
+            .unwrap();
+        // Approved, but still open to challenge
+        assert_eq!(
+            fixture.withdraw(owner, SOL),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+
+        fixture.svm.advance_clock(MILESTONE_CHALLENGE_PERIOD);
+        assert_eq!(
+            fixture.withdraw(owner, SOL + 1),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+        fixture.withdraw(owner, SOL).unwrap();
+        assert_eq!(
+            fixture.withdraw(owner, 1),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+        let project: Project = fixture.svm.get(&pda::project_pda(&owner).0);
+        assert_eq!((project.current_funding, project.releasable(fixture.svm.clock().unix_timestamp)), (4 * SOL, 0));
+    }
+
+    #[test]
+    fn test_votes_close_with_the_window() {
+        let mut fixture = RoundFixture::new();
+        let (owner, whale, _) = funded_project(&mut fixture);
+        fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 0), &[owner]).unwrap();
+        fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        assert_eq!(
+            fixture.svm.process(&instructions::vote_milestone(whale, owner, 0, 0, true), &[whale]),
+            Err(anchor_error(AnectosError::VotingClosed))
+        );
+    }
+
+    #[test]
+    fn test_rejected_milestone_can_be_resubmitted() {
+        let mut fixture = RoundFixture::new();
+        let (owner, whale, minnow) = funded_project(&mut fixture);
+        fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 1), &[owner]).unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(whale, owner, 1, 0, false), &[whale])
+            .unwrap();
+        fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+        fixture
+            .svm
+            .process(&instructions::complete_milestone(minnow, owner, 1), &[minnow])
+            .unwrap();
+        let rejected = review(&fixture, &owner, 1);
+        assert!(rejected.status == MilestoneReviewStatus::Rejected);
+        assert_eq!(rejected.failed_attempts, 1);
+
+        // Only the owner may reopen the review; the next attempt takes fresh votes
+        assert_eq!(
+            fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 1), &[whale]),
+            Err(TxError::MissingSignature(owner))
+        );
+        fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 1), &[owner]).unwrap();
+        assert_eq!(review(&fixture, &owner, 1).attempt, 1);
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(whale, owner, 1, 1, true), &[whale])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_milestone_above_donations_cannot_be_submitted() {
+        let mut fixture = RoundFixture::new();
+        // First milestone of a 100 SOL target is 10 SOL
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 5 * SOL).unwrap();
+        assert_eq!(
+            fixture.svm.process(&instructions::submit_milestone(owner, fixture.round, 0), &[owner]),
+            Err(anchor_error(AnectosError::InsufficientProjectVaultFunds))
+        );
+    }
+
+    #[test]
+    fn test_votes_cannot_be_withdrawn_after_they_are_cast() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        // A sybil backing the owner's first milestone
+        let sybil = fixture.wallet(10 * SOL);
+        fixture.contribute(sybil, owner, 4 * SOL).unwrap();
+        let submit = instructions::submit_milestone(owner, fixture.round, 0);
+        assert_eq!(
+            fixture.svm.process(&submit, &[owner]),
+            Err(anchor_error(AnectosError::ContributionsStillWithdrawable))
+        );
+
+        fixture.close_withdrawals();
+        fixture.svm.process(&submit, &[owner]).unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(sybil, owner, 0, 0, true), &[sybil])
+            .unwrap();
+        let withdraw = instructions::withdraw_contribution(sybil, fixture.round, owner, 4 * SOL);
+        assert_eq!(
+            fixture.svm.process(&withdraw, &[sybil]),
+            Err(anchor_error(AnectosError::ContributionWithdrawalClosed))
+        );
+    }
+}
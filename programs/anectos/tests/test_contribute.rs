@@ -0,0 +1,100 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anectos::constants::PAUSE_CONTRIBUTIONS;
+use anectos::error::AnectosError;
+use anectos::math::isqrt_u128;
+use anectos::state::{Contribution, FundingRound, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+#[cfg(test)]
+mod contribute_tests {
+    use super::*;
+
+    #[test]
+    fn test_repeat_contributions_accumulate_sqrt_of_total() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(20 * SOL);
+        fixture.contribute(alice, owner, 2 * SOL).unwrap();
+        let project = pda::project_pda(&owner).0;
+        let record = pda::contribution_pda(&project, &alice).0;
+        let record_rent = fixture.svm.lamports(&record);
+
+        fixture.contribute(alice, owner, 3 * SOL).unwrap();
+        fixture.contribute(alice, owner, 4 * SOL).unwrap();
+
+        // One contributor: area is sqrt of their total, not the sum of per-payment roots
+        let contribution: Contribution = fixture.svm.get(&record);
+        assert_eq!(contribution.total_contributed, 9 * SOL);
+        let expected_area = isqrt_u128(9 * SOL as u128);
+        assert_eq!(fixture.svm.get::<Project>(&project).area, expected_area);
+        assert_eq!(fixture.svm.get::<FundingRound>(&fixture.round).area, expected_area);
+        // Rent for the contribution record is only paid once
+        assert_eq!(fixture.svm.lamports(&alice), 11 * SOL - record_rent);
+        assert_eq!(fixture.svm.lamports(&pda::vault_pda(&owner).0), 9 * SOL);
+    }
+
+    #[test]
+    fn test_zero_amount_is_rejected() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(SOL);
+        assert_eq!(
+            fixture.contribute(alice, owner, 0),
+            Err(anchor_error(AnectosError::InvalidContributionAmount))
+        );
+    }
+
+    #[test]
+    fn test_first_contribution_must_cover_vault_rent() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(SOL);
+        // The project vault is created by the first transfer into it
+        let too_small = fixture.svm.rent().minimum_balance(0) - 1;
+        assert!(matches!(fixture.contribute(alice, owner, too_small), Err(TxError::Runtime(_))));
+        assert_eq!(fixture.svm.lamports(&alice), SOL);
+    }
+
+    #[test]
+    fn test_contribution_to_project_in_another_round_is_rejected() {
+        let mut fixture = RoundFixture::new();
+        let other_owner = fixture.wallet(10 * SOL);
+        let other_round = init_round(&mut fixture.svm, other_owner);
+        let owner = create_project(&mut fixture.svm, other_round, 100 * SOL, 4);
+        let alice = fixture.wallet(2 * SOL);
+        assert_eq!(
+            fixture.contribute(alice, owner, SOL),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_contributions_respect_round_pause() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(5 * SOL);
+        let (admin, round) = (fixture.admin, fixture.round);
+
+        let mallory = fixture.wallet(SOL);
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::set_pause(mallory, Some(round), PAUSE_CONTRIBUTIONS, &[]), &[mallory]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+
+        fixture
+            .svm
+            .process(&instructions::set_pause(admin, Some(round), PAUSE_CONTRIBUTIONS, &[]), &[admin])
+            .unwrap();
+        assert_eq!(fixture.contribute(alice, owner, SOL), Err(anchor_error(AnectosError::OperationPaused)));
+        fixture
+            .svm
+            .process(&instructions::set_pause(admin, Some(round), 0, &[]), &[admin])
+            .unwrap();
+        fixture.contribute(alice, owner, SOL).unwrap();
+    }
+}
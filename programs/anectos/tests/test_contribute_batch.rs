@@ -0,0 +1,96 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{Contribution, ContributionMade, FundingRound, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+const AMOUNTS: [u64; 3] = [SOL, 2 * SOL + 3, 5 * SOL];
+
+fn batch(fixture: &mut RoundFixture, user: Pubkey, legs: &[(Pubkey, u64)]) -> Result<(), TxError> {
+    let ix = instructions::contribute_batch(user, fixture.round, legs);
+    fixture.svm.process(&ix, &[user])
+}
+
+/// Per-project (area, current_funding), then the round's (area, total_donations)
+fn qf_state(fixture: &RoundFixture, creators: &[Pubkey]) -> (Vec<(u128, u64)>, (u128, u64)) {
+    let projects = creators
+        .iter()
+        .map(|creator| {
+            let project: Project = fixture.svm.get(&pda::project_pda(creator).0);
+            (project.area, project.current_funding)
+        })
+        .collect();
+    let round: FundingRound = fixture.svm.get(&fixture.round);
+    (projects, (round.area, round.total_donations))
+}
+
+#[cfg(test)]
+mod contribute_batch_tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_books_like_individual_contributions() {
+        let mut single = RoundFixture::new();
+        let mut batched = RoundFixture::new();
+        let mut creators = [Vec::new(), Vec::new()];
+        for (fixture, creators) in [&mut single, &mut batched].into_iter().zip(creators.iter_mut()) {
+            *creators = (0..3).map(|_| fixture.create_project(100 * SOL, 4)).collect();
+            let early = fixture.wallet(10 * SOL);
+            fixture.contribute(early, creators[0], 4 * SOL).unwrap();
+        }
+
+        let alice = single.wallet(20 * SOL);
+        single.contribute(alice, creators[0][0], SOL).unwrap();
+        for (creator, amount) in creators[0].iter().zip(AMOUNTS) {
+            single.contribute(alice, *creator, amount).unwrap();
+        }
+
+        // Alice already has a record for the first project; the others are created
+        let alice = batched.wallet(20 * SOL);
+        batched.contribute(alice, creators[1][0], SOL).unwrap();
+        let legs: Vec<(Pubkey, u64)> = creators[1].iter().copied().zip(AMOUNTS).collect();
+        batch(&mut batched, alice, &legs).unwrap();
+
+        assert_eq!(qf_state(&batched, &creators[1]), qf_state(&single, &creators[0]));
+        let events = batched.svm.events::<ContributionMade>();
+        let batch_events = &events[events.len() - 3..];
+        assert!(batch_events.iter().zip(&legs).all(|(event, (creator, amount))| {
+            event.project == pda::project_pda(creator).0 && event.amount == *amount && event.contributor == alice
+        }));
+        let project = pda::project_pda(&creators[1][2]).0;
+        let record: Contribution = batched.svm.get(&pda::contribution_pda(&project, &alice).0);
+        assert_eq!((record.contributor, record.total_contributed), (alice, 5 * SOL));
+    }
+
+    #[test]
+    fn test_any_failing_leg_fails_the_batch() {
+        let mut fixture = RoundFixture::new();
+        let creators: Vec<Pubkey> = (0..2).map(|_| fixture.create_project(100 * SOL, 4)).collect();
+        let alice = fixture.wallet(10 * SOL);
+        let before = qf_state(&fixture, &creators);
+
+        assert_eq!(
+            batch(&mut fixture, alice, &[(creators[0], SOL), (creators[1], 0)]),
+            Err(anchor_error(AnectosError::InvalidContributionAmount))
+        );
+        assert_eq!(
+            batch(&mut fixture, alice, &[(creators[0], SOL), (creators[0], SOL)]),
+            Err(anchor_error(AnectosError::InvalidContributionBatch))
+        );
+        // Vaults swapped between the two legs
+        let mut ix = instructions::contribute_batch(alice, fixture.round, &[(creators[0], SOL), (creators[1], SOL)]);
+        let n = ix.accounts.len();
+        ix.accounts.swap(n - 5, n - 2);
+        assert_eq!(
+            fixture.svm.process(&ix, &[alice]),
+            Err(anchor_error(AnectosError::BatchAccountMismatch))
+        );
+
+        assert_eq!(qf_state(&fixture, &creators), before);
+        assert_eq!(fixture.svm.lamports(&alice), 10 * SOL);
+        let project = pda::project_pda(&creators[0]).0;
+        assert!(fixture.svm.account(&pda::contribution_pda(&project, &alice).0).is_none());
+    }
+}
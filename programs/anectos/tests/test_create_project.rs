@@ -0,0 +1,156 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anectos::error::AnectosError;
+use anectos::math::milestone_amount;
+use anectos::state::{FundingStage, Project, ProjectCreated, ProjectMeta, SDGGoals};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// Inputs for one `create_project` call; `Default` is a valid project.
+struct ProjectInput {
+    title: String,
+    description: String,
+    target_amount: u64,
+    milestone_count: u8,
+    sdg_goals: Vec<SDGGoals>,
+    image_uri: String,
+}
+
+impl Default for ProjectInput {
+    fn default() -> Self {
+        Self {
+            title: "Clean water".into(),
+            description: "Wells for three villages".into(),
+            target_amount: 100 * SOL,
+            milestone_count: 4,
+            sdg_goals: vec![SDGGoals::CleanWaterAndSanitation],
+            image_uri: "ipfs://project".into(),
+        }
+    }
+}
+
+fn try_create(fixture: &mut RoundFixture, input: ProjectInput) -> (Pubkey, Result<(), TxError>) {
+    let owner = fixture.wallet(10 * SOL);
+    let ix = instructions::create_project(
+        owner,
+        fixture.round,
+        input.title,
+        input.description,
+        input.target_amount,
+        input.milestone_count,
+        input.sdg_goals,
+        input.image_uri,
+    );
+    (owner, fixture.svm.process(&ix, &[owner]))
+}
+
+#[cfg(test)]
+mod create_project_tests {
+    use super::*;
+
+    #[test]
+    fn test_project_is_created_with_milestones_and_metadata() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(1_000 * SOL, 4);
+        let address = pda::project_pda(&owner).0;
+
+        let project: Project = fixture.svm.get(&address);
+        assert_eq!((project.owner, project.round), (owner, fixture.round));
+        assert_eq!(project.project_id, address);
+        let amounts: Vec<u64> = project.milestones.iter().map(|m| m.amount).collect();
+        let expected: Vec<u64> = (0..4).map(|i| milestone_amount(1_000 * SOL, 4, i).unwrap()).collect();
+        assert_eq!(amounts, expected);
+        assert_eq!(amounts.iter().sum::<u64>(), 1_000 * SOL);
+
+        let meta: ProjectMeta = fixture.svm.get(&pda::project_metadata_pda(&address).0);
+        assert_eq!(meta.project, address);
+        assert!(meta.funding_stage == FundingStage::Planning);
+        assert!(meta.sdg_goals == vec![SDGGoals::CleanWaterAndSanitation]);
+
+        let created = fixture.svm.events::<ProjectCreated>();
+        assert_eq!((created[0].project, created[0].milestone_count), (address, 4));
+    }
+
+    #[test]
+    fn test_owner_pays_rent_for_both_accounts() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 2);
+        let project = pda::project_pda(&owner).0;
+        let meta = pda::project_metadata_pda(&project).0;
+        let rent = fixture.svm.rent().clone();
+        let paid: u64 = [project, meta]
+            .iter()
+            .map(|a| {
+                let account = fixture.svm.account(a).unwrap();
+                assert_eq!(account.owner, anectos::ID);
+                assert_eq!(account.lamports, rent.minimum_balance(account.data.len()));
+                account.lamports
+            })
+            .sum();
+        assert_eq!(fixture.svm.lamports(&owner), 10 * SOL - paid);
+    }
+
+    #[test]
+    fn test_one_project_per_owner() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 2);
+        let ix = instructions::create_project(
+            owner,
+            fixture.round,
+            "Second".into(),
+            "Another project".into(),
+            50 * SOL,
+            1,
+            vec![],
+            "ipfs://second".into(),
+        );
+        // The project PDA is derived from the owner alone, so it already exists
+        assert_eq!(
+            fixture.svm.process(&ix, &[owner]),
+            Err(TxError::Program(ProgramError::Custom(0)))
+        );
+        assert_eq!(fixture.svm.get::<Project>(&pda::project_pda(&owner).0).target_amount, 100 * SOL);
+    }
+
+    #[test]
+    fn test_invalid_project_input_is_rejected_with_specific_errors() {
+        let mut fixture = RoundFixture::new();
+        let cases = [
+            (ProjectInput { title: " ".into(), ..Default::default() }, AnectosError::EmptyTitle),
+            (ProjectInput { title: "t".repeat(65), ..Default::default() }, AnectosError::TitleTooLong),
+            (ProjectInput { description: "d".repeat(513), ..Default::default() }, AnectosError::DescriptionTooLong),
+            (ProjectInput { image_uri: "http://image".into(), ..Default::default() }, AnectosError::UnsupportedUriScheme),
+            (ProjectInput { milestone_count: 0, ..Default::default() }, AnectosError::InvalidMilestoneCount),
+            (ProjectInput { milestone_count: 21, ..Default::default() }, AnectosError::InvalidMilestoneCount),
+            (ProjectInput { target_amount: 0, ..Default::default() }, AnectosError::InvalidTargetAmount),
+        ];
+        for (input, error) in cases {
+            let (owner, result) = try_create(&mut fixture, input);
+            assert_eq!(result, Err(anchor_error(error)));
+            assert!(fixture.svm.account(&pda::project_pda(&owner).0).is_none());
+        }
+    }
+
+    #[test]
+    fn test_limits_match_the_metadata_layout() {
+        let mut fixture = RoundFixture::new();
+        let input = ProjectInput {
+            title: "t".repeat(64),
+            description: "d".repeat(512),
+            milestone_count: 20,
+            sdg_goals: vec![SDGGoals::ClimateAction, SDGGoals::LifeOnLand, SDGGoals::ClimateAction],
+            image_uri: format!("https://{}", "i".repeat(192)),
+            ..Default::default()
+        };
+        let (owner, result) = try_create(&mut fixture, input);
+        result.unwrap();
+
+        let project = pda::project_pda(&owner).0;
+        assert_eq!(fixture.svm.get::<Project>(&project).milestones.len(), 20);
+        let meta: ProjectMeta = fixture.svm.get(&pda::project_metadata_pda(&project).0);
+        assert_eq!((meta.title.len(), meta.description.len()), (64, 512));
+        assert!(meta.sdg_goals == vec![SDGGoals::ClimateAction, SDGGoals::LifeOnLand]);
+    }
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::math::isqrt_u128;
+use anectos::state::{Contribution, ContributionFlagged, FundingRound, Project, RoundSettingsUpdated};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// A 10 SOL project backed by 4 SOL from Alice and 1 SOL from Bob.
+/// Returns (owner, alice, bob).
+fn funded_project(fixture: &mut RoundFixture) -> (Pubkey, Pubkey, Pubkey) {
+    let owner = fixture.create_project(10 * SOL, 4);
+    let (alice, bob) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+    fixture.contribute(alice, owner, 4 * SOL).unwrap();
+    fixture.contribute(bob, owner, SOL).unwrap();
+    (owner, alice, bob)
+}
+
+fn flag(fixture: &mut RoundFixture, authority: Pubkey, owner: Pubkey, contributor: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::flag_contribution(authority, fixture.round, owner, contributor, 7);
+    fixture.svm.process(&ix, &[authority])
+}
+
+fn set_reviewer(fixture: &mut RoundFixture, signer: Pubkey, reviewer: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::set_round_reviewer(signer, fixture.round, reviewer);
+    fixture.svm.process(&ix, &[signer])
+}
+
+/// (project area, round area)
+fn areas(fixture: &RoundFixture, owner: &Pubkey) -> (u128, u128) {
+    let project: Project = fixture.svm.get(&pda::project_pda(owner).0);
+    let round: FundingRound = fixture.svm.get(&fixture.round);
+    (project.area, round.area)
+}
+
+#[cfg(test)]
+mod flag_contribution_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_round_owner_sets_the_reviewer() {
+        let mut fixture = RoundFixture::new();
+        let reviewer = fixture.wallet(SOL);
+        assert_eq!(
+            set_reviewer(&mut fixture, reviewer, reviewer),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+
+        let round_owner = fixture.round_owner;
+        set_reviewer(&mut fixture, round_owner, reviewer).unwrap();
+        assert_eq!(fixture.svm.get::<FundingRound>(&fixture.round).reviewer, reviewer);
+        let event = fixture.svm.events::<RoundSettingsUpdated>().pop().unwrap();
+        assert_eq!((event.round, event.reviewer), (fixture.round, reviewer));
+
+        set_reviewer(&mut fixture, round_owner, Pubkey::default()).unwrap();
+        assert_eq!(fixture.svm.get::<FundingRound>(&fixture.round).reviewer, Pubkey::default());
+    }
+
+    #[test]
+    fn test_flag_needs_the_round_owner_or_its_reviewer() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, bob) = funded_project(&mut fixture);
+        let (reviewer, stranger) = (fixture.wallet(SOL), fixture.wallet(SOL));
+        fixture.svm.warp_to(ROUND_END + 1);
+
+        assert_eq!(
+            flag(&mut fixture, reviewer, owner, alice),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+        let round_owner = fixture.round_owner;
+        set_reviewer(&mut fixture, round_owner, reviewer).unwrap();
+        assert_eq!(
+            flag(&mut fixture, stranger, owner, alice),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+        flag(&mut fixture, reviewer, owner, alice).unwrap();
+        let event = fixture.svm.events::<ContributionFlagged>().pop().unwrap();
+        assert_eq!((event.contributor, event.flagged_by, event.reason_code), (alice, reviewer, 7));
+
+        // Delegating review does not take it away from the round owner
+        flag(&mut fixture, round_owner, owner, bob).unwrap();
+        let event = fixture.svm.events::<ContributionFlagged>().pop().unwrap();
+        assert_eq!((event.contributor, event.flagged_by), (bob, round_owner));
+    }
+
+    #[test]
+    fn test_flag_is_refused_outside_the_review_window() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, _) = funded_project(&mut fixture);
+        let round_owner = fixture.round_owner;
+
+        // The review window opens strictly after end_time
+        assert_eq!(
+            flag(&mut fixture, round_owner, owner, alice),
+            Err(anchor_error(AnectosError::ReviewWindowNotOpen))
+        );
+        fixture.svm.warp_to(ROUND_END);
+        assert_eq!(
+            flag(&mut fixture, round_owner, owner, alice),
+            Err(anchor_error(AnectosError::ReviewWindowNotOpen))
+        );
+
+        // ...and closes once matching starts to settle
+        fixture.svm.warp_to(ROUND_END + 1);
+        fixture.fund_project_pool(owner, SOL).unwrap();
+        fixture.settle(owner).unwrap();
+        assert_eq!(
+            flag(&mut fixture, round_owner, owner, alice),
+            Err(anchor_error(AnectosError::SettlementAlreadyStarted))
+        );
+    }
+
+    #[test]
+    fn test_flag_removes_the_contributor_from_matching_for_good() {
+        let mut fixture = RoundFixture::new();
+        let (owner, alice, _) = funded_project(&mut fixture);
+        let round_owner = fixture.round_owner;
+        fixture.svm.warp_to(ROUND_END + 1);
+        let (project_area, round_area) = areas(&fixture, &owner);
+
+        flag(&mut fixture, round_owner, owner, alice).unwrap();
+        // Alice's weight is sqrt of her total, taken off both the project and the round
+        let weight = isqrt_u128(4 * SOL as u128);
+        assert_eq!(areas(&fixture, &owner), (project_area - weight, round_area - weight));
+        assert_eq!(fixture.svm.events::<ContributionFlagged>()[0].weight_removed, weight);
+        let project = pda::project_pda(&owner).0;
+        let contribution: Contribution = fixture.svm.get(&pda::contribution_pda(&project, &alice).0);
+        assert!(contribution.is_excluded);
+        // The funds themselves stay with the project
+        assert_eq!(contribution.total_contributed, 4 * SOL);
+
+        assert_eq!(
+            flag(&mut fixture, round_owner, owner, alice),
+            Err(anchor_error(AnectosError::ContributionAlreadyExcluded))
+        );
+        assert_eq!(
+            fixture.contribute(alice, owner, SOL),
+            Err(anchor_error(AnectosError::ContributionAlreadyExcluded))
+        );
+        assert_eq!(areas(&fixture, &owner), (project_area - weight, round_area - weight));
+    }
+}
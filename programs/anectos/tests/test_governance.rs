@@ -0,0 +1,108 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{FundingStage, Project, ProjectMeta, Proposal, ProposalStatus, VotingMode};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn vote(gov: &mut GovernanceFixture, voter: Pubkey, id: u64, approve: bool) -> Result<(), TxError> {
+    gov.fixture
+        .svm
+        .process(&instructions::cast_vote(voter, id, approve), &[voter])
+}
+
+fn proposal(gov: &GovernanceFixture, id: u64) -> Proposal {
+    gov.fixture.svm.get(&pda::proposal_pda(id).0)
+}
+
+#[cfg(test)]
+mod governance_tests {
+    use super::*;
+
+    #[test]
+    fn test_votes_count_stake_locked_before_the_proposal() {
+        let mut gov = GovernanceFixture::new();
+        let (alice, bob, erin) = (gov.holder(600), gov.holder(500), gov.holder(300));
+        let (dave, proposer) = (gov.holder(0), gov.holder(10));
+        gov.stake(alice, 600).unwrap();
+        gov.stake(bob, 500).unwrap();
+        gov.stake(erin, 300).unwrap();
+        gov.fixture.svm.advance_clock(60);
+        let (id, creator) = gov.propose_whitelist(proposer, VotingMode::TokenWeighted);
+
+        vote(&mut gov, alice.0, id, true).unwrap();
+        assert_eq!(gov.unstake(alice, 600), Err(anchor_error(AnectosError::StakeLocked)));
+        assert!(vote(&mut gov, alice.0, id, true).is_err());
+
+        // Bob moves his stake to Dave mid-vote: neither wallet carries weight any more
+        gov.unstake(bob, 500).unwrap();
+        gov.fixture.svm.set_token_account(bob.1, gov.mint, bob.0, 0);
+        gov.fixture.svm.set_token_account(dave.1, gov.mint, dave.0, 500);
+        gov.stake(dave, 500).unwrap();
+        for voter in [bob.0, dave.0] {
+            assert_eq!(
+                vote(&mut gov, voter, id, true),
+                Err(anchor_error(AnectosError::InsufficientGovernanceTokens))
+            );
+        }
+        vote(&mut gov, erin.0, id, false).unwrap();
+
+        gov.fixture.svm.advance_clock(VOTING_PERIOD);
+        gov.execute(id, creator).unwrap();
+        let proposal = proposal(&gov, id);
+        assert!(proposal.status == ProposalStatus::Executed);
+        assert_eq!((proposal.yes_votes, proposal.no_votes, proposal.voter_count), (600, 300, 2));
+        let project = pda::project_pda(&creator).0;
+        let meta: ProjectMeta = gov.fixture.svm.get(&pda::project_metadata_pda(&project).0);
+        assert!(gov.fixture.svm.get::<Project>(&project).is_whitelisted && meta.funding_stage == FundingStage::Active);
+
+        // Once the vote has closed the stake is free again
+        gov.unstake(alice, 600).unwrap();
+        assert_eq!(
+            (gov.fixture.svm.token_balance(&alice.1), gov.fixture.svm.token_balance(&gov.escrow)),
+            (600, 800)
+        );
+    }
+
+    #[test]
+    fn test_only_the_upgrade_authority_initializes_singletons() {
+        let mut svm = Svm::new();
+        let mallory = funded_wallet(&mut svm, SOL);
+        svm.set_upgrade_authority(Some(Pubkey::new_unique()));
+        assert_eq!(
+            svm.process(&instructions::initialize_protocol_config(mallory, vec![], 0), &[mallory]),
+            Err(anchor_error(AnectosError::NotUpgradeAuthority))
+        );
+
+        let mut fixture = RoundFixture::new();
+        let (admin, mallory) = (fixture.admin, fixture.wallet(SOL));
+        let mint = Pubkey::new_unique();
+        assert_eq!(
+            fixture.svm.process(
+                &instructions::initialize_governance(mallory, mint, 1, VOTING_PERIOD, 100, 5_000),
+                &[mallory]
+            ),
+            Err(anchor_error(AnectosError::NotUpgradeAuthority))
+        );
+        fixture
+            .svm
+            .process(&instructions::initialize_governance(admin, mint, 1, VOTING_PERIOD, 100, 5_000), &[admin])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_proposal_below_quorum_is_defeated() {
+        let mut gov = GovernanceFixture::new();
+        let (alice, proposer) = (gov.holder(99), gov.holder(10));
+        gov.stake(alice, 99).unwrap();
+        gov.fixture.svm.advance_clock(60);
+        let (id, creator) = gov.propose_whitelist(proposer, VotingMode::TokenWeighted);
+        vote(&mut gov, alice.0, id, true).unwrap();
+
+        gov.fixture.svm.advance_clock(VOTING_PERIOD);
+        gov.execute(id, creator).unwrap();
+        assert!(proposal(&gov, id).status == ProposalStatus::Defeated);
+        assert!(!gov.fixture.svm.get::<Project>(&pda::project_pda(&creator).0).is_whitelisted);
+    }
+}
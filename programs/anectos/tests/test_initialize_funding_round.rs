@@ -0,0 +1,113 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anectos::error::AnectosError;
+use anectos::state::{FundingRound, FundingRoundMeta, RoundInitialized};
+use anectos_client::{instructions, pda};
+use common::*;
+
+#[cfg(test)]
+mod initialize_funding_round_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_is_initialized_with_metadata_and_vault() {
+        let mut svm = Svm::new();
+        init_protocol(&mut svm);
+        let owner = funded_wallet(&mut svm, 10 * SOL);
+        let round = Pubkey::new_unique();
+
+        let ix = instructions::initialize_funding_round(owner, round, 0, ROUND_START, ROUND_END, "ipfs://round".into());
+        svm.process(&ix, &[owner, round]).unwrap();
+
+        let funding_round: FundingRound = svm.get(&round);
+        assert_eq!(funding_round.owner, owner);
+        assert!(funding_round.is_active);
+        assert_eq!((funding_round.area, funding_round.matching_pool), (0, 0));
+        let meta: FundingRoundMeta = svm.get(&pda::funding_round_metadata_pda(&round).0);
+        assert_eq!((meta.start_time, meta.end_time), (ROUND_START, ROUND_END));
+        assert_eq!(meta.nft_metadata_uri, "ipfs://round");
+
+        let events = svm.events::<RoundInitialized>();
+        assert_eq!(events.len(), 1);
+        assert_eq!((events[0].round, events[0].owner), (round, owner));
+
+        // Both accounts are rent-exempt and paid for by the owner
+        let rent = svm.rent().clone();
+        let round_rent = rent.minimum_balance(svm.account(&round).unwrap().data.len());
+        assert_eq!(svm.lamports(&round), round_rent);
+        let meta_address = pda::funding_round_metadata_pda(&round).0;
+        let meta_rent = rent.minimum_balance(svm.account(&meta_address).unwrap().data.len());
+        assert_eq!(svm.lamports(&owner), 10 * SOL - round_rent - meta_rent);
+
+        svm.process(&instructions::create_round_vault(owner, round), &[owner]).unwrap();
+        let vault = pda::round_vault_pda(&round).0;
+        assert_eq!(svm.lamports(&vault), rent.minimum_balance(0));
+        assert_eq!(svm.get::<FundingRound>(&round).project_vault, vault);
+    }
+
+    #[test]
+    fn test_round_account_must_sign() {
+        let mut svm = Svm::new();
+        let owner = funded_wallet(&mut svm, 10 * SOL);
+        let round = Pubkey::new_unique();
+        let ix = instructions::initialize_funding_round(owner, round, 0, ROUND_START, ROUND_END, "ipfs://round".into());
+        assert_eq!(svm.process(&ix, &[owner]), Err(TxError::MissingSignature(round)));
+        assert!(svm.account(&round).is_none());
+    }
+
+    #[test]
+    fn test_round_cannot_be_initialized_twice() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.round_owner;
+        let ix = instructions::initialize_funding_round(
+            owner,
+            fixture.round,
+            0,
+            ROUND_START,
+            ROUND_END,
+            "ipfs://again".into(),
+        );
+        let before = fixture.svm.lamports(&owner);
+        // The system program refuses to allocate an account that already exists
+        assert_eq!(
+            fixture.svm.process(&ix, &[owner, fixture.round]),
+            Err(TxError::Program(ProgramError::Custom(0)))
+        );
+        assert_eq!(fixture.svm.lamports(&owner), before);
+    }
+
+    #[test]
+    fn test_round_metadata_uri_is_validated() {
+        let mut svm = Svm::new();
+        init_protocol(&mut svm);
+        let owner = funded_wallet(&mut svm, 10 * SOL);
+        let round = Pubkey::new_unique();
+
+        let ix = instructions::initialize_funding_round(owner, round, 0, ROUND_START, ROUND_END, "ftp://round".into());
+        assert_eq!(svm.process(&ix, &[owner, round]), Err(anchor_error(AnectosError::UnsupportedUriScheme)));
+        assert!(svm.account(&round).is_none());
+    }
+
+    #[test]
+    fn test_round_window_is_validated() {
+        let mut svm = Svm::new();
+        init_protocol(&mut svm);
+        let owner = funded_wallet(&mut svm, 10 * SOL);
+        let now = svm.clock().unix_timestamp;
+
+        // Empty, inverted and already-finished windows
+        for (start, end) in [(ROUND_END, ROUND_END), (ROUND_END, ROUND_START), (now - 100, now)] {
+            let round = Pubkey::new_unique();
+            let ix = instructions::initialize_funding_round(owner, round, 0, start, end, "ipfs://round".into());
+            assert_eq!(svm.process(&ix, &[owner, round]), Err(anchor_error(AnectosError::InvalidRoundWindow)));
+            assert!(svm.account(&round).is_none());
+        }
+
+        // A round that has already opened but not yet closed is fine
+        let round = Pubkey::new_unique();
+        let ix = instructions::initialize_funding_round(owner, round, 0, now - 100, now + 1, "ipfs://round".into());
+        svm.process(&ix, &[owner, round]).unwrap();
+    }
+}
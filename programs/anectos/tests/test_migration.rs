@@ -0,0 +1,269 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{Discriminator, Space};
+use anectos::constants::ACCOUNT_VERSION;
+use anectos::error::AnectosError;
+use anectos::migration::{ContributionV0, FundingRoundMetaV0, FundingRoundV0, LegacyLayout, ProjectV0};
+use anectos::state::{AccountMigrated, Contribution, FundingRound, FundingRoundMeta, Ledger, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// Bytes of a project as the pre-versioning program stored it: 4 SOL and 6 SOL
+/// milestones with the first achieved, 3 SOL of donations held and 2 SOL of matching
+/// settled, 1 SOL of which is still held. Padded to its `max_len(16)` allocation.
+fn baseline_project(project_id: Pubkey, round: Pubkey, owner: Pubkey) -> Vec<u8> {
+    let mut data = Vec::new();
+    for key in [project_id, round, owner] {
+        data.extend_from_slice(key.as_ref());
+    }
+    data.extend_from_slice(&(10 * SOL).to_le_bytes()); // target_amount
+    data.extend_from_slice(&1_234_567u128.to_le_bytes()); // area
+    for amount in [3 * SOL, SOL, 2 * SOL, 2 * SOL] {
+        // current_funding, matching_unlocked, matching_pool, pool_distributed
+        data.extend_from_slice(&amount.to_le_bytes());
+    }
+    data.extend_from_slice(&[1, 0, 2]); // is_whitelisted, has_withdrawn, milestone_count
+    data.extend_from_slice(&2u32.to_le_bytes());
+    for (amount, is_achieved) in [(4 * SOL, true), (6 * SOL, false)] {
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(is_achieved as u8);
+    }
+    data.push(254); // bump
+    data.resize(BASELINE_PROJECT_SPACE, 0);
+    data
+}
+
+/// 3 keys, target, area, 4 balances, 3 flags, 16 milestones of (u64, bool), bump
+const BASELINE_PROJECT_SPACE: usize = 32 * 3 + 8 + 16 + 8 * 4 + 3 + 4 + 16 * 9 + 1;
+
+/// Bytes of a round as the pre-versioning program stored it, holding the two projects of
+/// `baseline_project`: 4 SOL of matching settled out of a 4 SOL pool and 8 SOL donated,
+/// 2 SOL of which their owners already withdrew.
+fn baseline_round(owner: Pubkey, round: Pubkey) -> Vec<u8> {
+    let mut data = owner.as_ref().to_vec();
+    data.extend_from_slice(&(4 * SOL).to_le_bytes()); // matching_pool
+    data.extend_from_slice(&(8 * SOL).to_le_bytes()); // total_donations
+    data.extend_from_slice(&2_469_134u128.to_le_bytes()); // area
+    data.extend_from_slice(&u128::MAX.to_le_bytes()); // area_max
+    data.extend_from_slice(&3u32.to_le_bytes()); // contributor_count
+    let (vault, vault_bump) = pda::round_vault_pda(&round);
+    data.extend_from_slice(vault.as_ref());
+    data.extend_from_slice(&[vault_bump, 1]); // vault_bump, is_active
+    data.extend_from_slice(&0u64.to_le_bytes()); // fee
+    data.extend_from_slice(&(4 * SOL).to_le_bytes()); // pool_distributed
+    data.push(253); // bump
+    data
+}
+
+/// Stores `payload` under `T`'s discriminator as a rent-exempt program account.
+fn set_legacy_account<T: Discriminator>(svm: &mut Svm, address: Pubkey, payload: Vec<u8>) {
+    let data = [T::DISCRIMINATOR, &payload[..]].concat();
+    let lamports = svm.rent().minimum_balance(data.len());
+    svm.set_account(address, svm::Account { lamports, data, owner: anectos::ID, executable: false });
+}
+
+#[cfg(test)]
+mod migration_tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_layout_sizes_match_deployed_accounts() {
+        assert_eq!(ProjectV0::account_size(), 8 + BASELINE_PROJECT_SPACE);
+        // Round metadata was allocated with the hand-computed `INIT_SPACE` of 4 + 200 + 8 + 8
+        assert_eq!(FundingRoundMetaV0::account_size(), 8 + 4 + 200 + 8 + 8);
+        assert_eq!(FundingRoundV0::account_size(), 8 + 32 + 8 * 2 + 16 * 2 + 4 + 32 + 2 + 8 * 2 + 1);
+        assert_eq!(ContributionV0::account_size(), 8 + 32 * 2 + 8 + 1);
+        assert!(8 + Project::INIT_SPACE > ProjectV0::account_size());
+    }
+
+    #[test]
+    fn test_migrate_project_rebuilds_the_ledger_and_tops_up_rent() {
+        let mut svm = Svm::new();
+        let payer = funded_wallet(&mut svm, SOL);
+        let (owner, round) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let address = pda::project_pda(&owner).0;
+        set_legacy_account::<Project>(&mut svm, address, baseline_project(address, round, owner));
+        let legacy_lamports = svm.lamports(&address);
+
+        // The legacy round is long closed, so only the project is rewritten
+        svm.process(&instructions::migrate_project(payer, address, round), &[payer])
+            .unwrap();
+
+        let project: Project = svm.get(&address);
+        assert_eq!(project.version, ACCOUNT_VERSION);
+        assert_eq!(project.reserved, [0; 22]);
+        assert!(!project.has_payout_splits && !project.has_team);
+        // Legacy projects were seeded by their owner, so that key becomes the creator
+        assert_eq!((project.owner, project.creator, project.round), (owner, owner, round));
+        assert_eq!((project.target_amount, project.area, project.bump), (10 * SOL, 1_234_567, 254));
+        assert!(project.is_whitelisted && !project.has_withdrawn);
+        let milestones: Vec<(u64, bool)> = project.milestones.iter().map(|m| (m.amount, m.is_achieved)).collect();
+        assert_eq!(milestones, vec![(4 * SOL, true), (6 * SOL, false)]);
+        let now = svm.clock().unix_timestamp;
+        assert!(project.milestones[0].is_released(now) && !project.milestones[1].is_released(now));
+
+        // Balances carry over and the ledger is rebuilt so they derive the same way
+        assert!(
+            project.ledger
+                == Ledger {
+                    donations_received: 3 * SOL,
+                    matching_allocated: 2 * SOL,
+                    matching_withdrawn: SOL,
+                    ..Ledger::default()
+                }
+        );
+        assert_eq!((project.current_funding, project.ledger.donations_held()), (3 * SOL, 3 * SOL));
+        assert_eq!((project.matching_unlocked, project.ledger.matching_held()), (SOL, SOL));
+        assert_eq!(project.pool_distributed, 2 * SOL);
+
+        let size = 8 + Project::INIT_SPACE;
+        assert_eq!(svm.account(&address).unwrap().data.len(), size);
+        let rent = svm.rent().minimum_balance(size);
+        assert_eq!(svm.lamports(&address), rent);
+        assert_eq!(svm.lamports(&payer), SOL - (rent - legacy_lamports));
+
+        let event = svm.events::<AccountMigrated>().pop().unwrap();
+        assert_eq!((event.account, event.from_version, event.to_version), (address, 0, ACCOUNT_VERSION));
+        assert_eq!(event.rent_topped_up, rent - legacy_lamports);
+    }
+
+    #[test]
+    fn test_migrate_funding_round_meta_from_hand_sized_layout() {
+        let mut svm = Svm::new();
+        let payer = funded_wallet(&mut svm, SOL);
+        let address = pda::funding_round_metadata_pda(&Pubkey::new_unique()).0;
+        let uri = b"ipfs://round";
+        let mut payload = (uri.len() as u32).to_le_bytes().to_vec();
+        payload.extend_from_slice(uri);
+        payload.extend_from_slice(&ROUND_START.to_le_bytes());
+        payload.extend_from_slice(&ROUND_END.to_le_bytes());
+        payload.resize(4 + 200 + 8 + 8, 0);
+        set_legacy_account::<FundingRoundMeta>(&mut svm, address, payload);
+
+        svm.process(&instructions::migrate_funding_round_meta(payer, address), &[payer])
+            .unwrap();
+
+        let meta: FundingRoundMeta = svm.get(&address);
+        assert_eq!(meta.version, ACCOUNT_VERSION);
+        assert_eq!(meta.nft_metadata_uri, "ipfs://round");
+        assert_eq!((meta.start_time, meta.end_time), (ROUND_START, ROUND_END));
+        assert_eq!(svm.account(&address).unwrap().data.len(), 8 + FundingRoundMeta::INIT_SPACE);
+    }
+
+    #[test]
+    fn test_legacy_contribution_is_unusable_until_migrated() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, SOL).unwrap();
+
+        // Rewrite Alice's record in the pre-versioning layout, byte by byte
+        let project = pda::project_pda(&owner).0;
+        let address = pda::contribution_pda(&project, &alice).0;
+        let bump = fixture.svm.get::<Contribution>(&address).bump;
+        let mut payload = Vec::new();
+        payload.extend_from_slice(project.as_ref());
+        payload.extend_from_slice(alice.as_ref());
+        payload.extend_from_slice(&SOL.to_le_bytes());
+        payload.push(bump);
+        set_legacy_account::<Contribution>(&mut fixture.svm, address, payload);
+
+        assert_eq!(
+            fixture.contribute(alice, owner, SOL),
+            Err(anchor_error(ErrorCode::AccountDidNotDeserialize))
+        );
+
+        fixture
+            .svm
+            .process(&instructions::migrate_contribution(alice, address), &[alice])
+            .unwrap();
+        let migrated: Contribution = fixture.svm.get(&address);
+        assert_eq!((migrated.version, migrated.total_contributed), (ACCOUNT_VERSION, SOL));
+        assert_eq!((migrated.project, migrated.contributor), (project, alice));
+
+        fixture.contribute(alice, owner, SOL).unwrap();
+        assert_eq!(fixture.svm.get::<Contribution>(&address).total_contributed, 2 * SOL);
+    }
+
+    #[test]
+    fn test_migrate_rejects_current_and_mismatched_accounts() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 2);
+        let project = pda::project_pda(&owner).0;
+        let payer = fixture.wallet(SOL);
+
+        assert_eq!(fixture.svm.get::<Project>(&project).version, ACCOUNT_VERSION);
+        assert_eq!(
+            fixture.svm.process(&instructions::migrate_project(payer, project, fixture.round), &[payer]),
+            Err(anchor_error(AnectosError::AccountAlreadyMigrated))
+        );
+        assert_eq!(
+            fixture.svm.process(&instructions::migrate_contribution(payer, project), &[payer]),
+            Err(anchor_error(ErrorCode::AccountDiscriminatorMismatch))
+        );
+
+        // An account of neither the legacy nor the current size is left alone
+        let address = pda::project_pda(&Pubkey::new_unique()).0;
+        let mut payload = baseline_project(address, fixture.round, owner);
+        payload.truncate(100);
+        set_legacy_account::<Project>(&mut fixture.svm, address, payload);
+        assert_eq!(
+            fixture.svm.process(&instructions::migrate_project(payer, address, fixture.round), &[payer]),
+            Err(anchor_error(AnectosError::UnknownAccountLayout))
+        );
+    }
+
+    #[test]
+    fn test_migrated_round_ledger_is_the_sum_of_its_projects() {
+        let mut svm = Svm::new();
+        let payer = funded_wallet(&mut svm, 10 * SOL);
+        let (round_owner, round) = (Pubkey::new_unique(), Pubkey::new_unique());
+        set_legacy_account::<FundingRound>(&mut svm, round, baseline_round(round_owner, round));
+        svm.airdrop(&pda::round_vault_pda(&round).0, svm.rent().minimum_balance(0));
+        let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+        for creator in creators {
+            let address = pda::project_pda(&creator).0;
+            set_legacy_account::<Project>(&mut svm, address, baseline_project(address, round, creator));
+            // 3 SOL of donations and 1 SOL of matching still held
+            svm.airdrop(&pda::vault_pda(&creator).0, 4 * SOL);
+        }
+        let project = |creator: &Pubkey| pda::project_pda(creator).0;
+
+        // Projects book their ledgers into the round, so it goes first
+        assert_eq!(
+            svm.process(&instructions::migrate_project(payer, project(&creators[0]), round), &[payer]),
+            Err(anchor_error(ErrorCode::AccountDidNotDeserialize))
+        );
+        svm.process(&instructions::migrate_funding_round(payer, round), &[payer])
+            .unwrap();
+        let migrated: FundingRound = svm.get(&round);
+        assert!(migrated.ledger == Ledger::default());
+        assert_eq!((migrated.matching_pool, migrated.bump), (4 * SOL, 253));
+        assert!(migrated.settlement_started);
+        // Only the project's own round takes its ledger
+        let other_round = Pubkey::new_unique();
+        assert_eq!(
+            svm.process(&instructions::migrate_project(payer, project(&creators[0]), other_round), &[payer]),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+        for creator in &creators {
+            svm.process(&instructions::migrate_project(payer, project(creator), round), &[payer])
+                .unwrap();
+        }
+
+        let mut projects = Ledger::default();
+        for creator in &creators {
+            projects.absorb(&svm.get::<Project>(&project(creator)).ledger).unwrap();
+        }
+        let migrated: FundingRound = svm.get(&round);
+        assert!(migrated.ledger == projects);
+        // Donations withdrawn before the migration drop out of the round total as well
+        assert_eq!((migrated.total_donations, migrated.pool_distributed), (6 * SOL, 4 * SOL));
+        assert_eq!(migrated.ledger.matching_held(), 2 * SOL);
+
+        svm.process(&instructions::audit_round(round, &creators), &[payer])
+            .unwrap();
+    }
+}
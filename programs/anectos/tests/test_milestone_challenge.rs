@@ -0,0 +1,186 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Space;
+use anectos::constants::{CHALLENGE_REWARD_BPS, MILESTONE_CHALLENGE_BOND, MILESTONE_CHALLENGE_PERIOD, MILESTONE_VOTING_PERIOD};
+use anectos::error::AnectosError;
+use anectos::state::{
+    ChallengeResolved, MilestoneChallenge, MilestoneReview, MilestoneReviewStatus, Project,
+};
+use anectos_client::{instructions, pda};
+use common::*;
+
+/// A 10 SOL project over 4 milestones (1, 2, 3 and 4 SOL) backed by 4 SOL from a whale
+/// and 1 SOL from a minnow, with milestones 0 and 1 approved and inside their
+/// challenge windows. Returns (owner, whale, minnow).
+fn approved_project(fixture: &mut RoundFixture) -> (Pubkey, Pubkey, Pubkey) {
+    let owner = fixture.create_project(10 * SOL, 4);
+    let (whale, minnow) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+    fixture.contribute(whale, owner, 4 * SOL).unwrap();
+    fixture.contribute(minnow, owner, SOL).unwrap();
+    fixture.close_withdrawals();
+    for index in 0..2 {
+        fixture
+            .svm
+            .process(&instructions::submit_milestone(owner, fixture.round, index), &[owner])
+            .unwrap();
+        fixture
+            .svm
+            .process(&instructions::vote_milestone(whale, owner, index, 0, true), &[whale])
+            .unwrap();
+    }
+    fixture.svm.advance_clock(MILESTONE_VOTING_PERIOD);
+    for index in 0..2 {
+        fixture
+            .svm
+            .process(&instructions::complete_milestone(whale, owner, index), &[whale])
+            .unwrap();
+    }
+    (owner, whale, minnow)
+}
+
+/// The minnow disputes milestone 1 and both challenge windows then run out.
+fn challenged_project(fixture: &mut RoundFixture) -> (Pubkey, Pubkey) {
+    let (owner, _, minnow) = approved_project(fixture);
+    fixture
+        .svm
+        .process(&instructions::challenge_milestone(minnow, owner, 1, 0, "ipfs://evidence".into()), &[minnow])
+        .unwrap();
+    fixture.svm.advance_clock(MILESTONE_CHALLENGE_PERIOD);
+    (owner, minnow)
+}
+
+fn project(fixture: &RoundFixture, owner: &Pubkey) -> Project {
+    fixture.svm.get(&pda::project_pda(owner).0)
+}
+
+fn challenge_address(owner: &Pubkey, milestone_index: u8) -> Pubkey {
+    let review = pda::milestone_review_pda(&pda::project_pda(owner).0, milestone_index).0;
+    pda::milestone_challenge_pda(&review, 0).0
+}
+
+fn resolve(fixture: &mut RoundFixture, authority: Pubkey, owner: Pubkey, challenger: Pubkey, upheld: bool) -> Result<(), TxError> {
+    let ix = instructions::resolve_challenge(authority, fixture.round, owner, 1, 0, challenger, upheld);
+    fixture.svm.process(&ix, &[authority])
+}
+
+#[cfg(test)]
+mod milestone_challenge_tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_escrows_the_bond_and_locks_the_disputed_milestone() {
+        let mut fixture = RoundFixture::new();
+        let (owner, whale, minnow) = approved_project(&mut fixture);
+
+        // Only contributors can challenge
+        let outsider = fixture.wallet(10 * SOL);
+        let evidence = || "ipfs://evidence".to_string();
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::challenge_milestone(outsider, owner, 1, 0, evidence()), &[outsider]),
+            Err(anchor_error(ErrorCode::AccountNotInitialized))
+        );
+
+        fixture
+            .svm
+            .process(&instructions::challenge_milestone(minnow, owner, 1, 0, evidence()), &[minnow])
+            .unwrap();
+        let escrow = challenge_address(&owner, 1);
+        let rent = fixture.svm.rent().minimum_balance(8 + MilestoneChallenge::INIT_SPACE);
+        assert_eq!(fixture.svm.lamports(&escrow), rent + MILESTONE_CHALLENGE_BOND);
+        let challenge: MilestoneChallenge = fixture.svm.get(&escrow);
+        assert_eq!((challenge.challenger, challenge.bond), (minnow, MILESTONE_CHALLENGE_BOND));
+        // The escrow account already exists for this attempt
+        assert!(fixture
+            .svm
+            .process(&instructions::challenge_milestone(whale, owner, 1, 0, evidence()), &[whale])
+            .is_err());
+
+        // Milestone 0 releases with its window; the 2 SOL of milestone 1 stay locked
+        fixture.svm.advance_clock(MILESTONE_CHALLENGE_PERIOD);
+        let disputed = project(&fixture, &owner);
+        assert_eq!(disputed.disputed_amount, 2 * SOL);
+        assert_eq!(disputed.milestones[1].released_at, i64::MAX);
+        assert_eq!(
+            fixture.withdraw(owner, 3 * SOL + 1),
+            Err(anchor_error(AnectosError::FundsLockedByDispute))
+        );
+        assert_eq!(
+            fixture.withdraw(owner, SOL + 1),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+        fixture.withdraw(owner, SOL).unwrap();
+    }
+
+    #[test]
+    fn test_upheld_challenge_returns_the_bond_with_a_reward() {
+        let mut fixture = RoundFixture::new();
+        let (owner, minnow) = challenged_project(&mut fixture);
+        let round_owner = fixture.round_owner;
+        let escrow = challenge_address(&owner, 1);
+        let escrowed = fixture.svm.lamports(&escrow);
+        let challenger_before = fixture.svm.lamports(&minnow);
+        let vault_before = fixture.svm.lamports(&pda::vault_pda(&owner).0);
+
+        // Only the round owner or its arbitrator resolves disputes
+        let stranger = fixture.wallet(SOL);
+        assert_eq!(
+            resolve(&mut fixture, stranger, owner, minnow, true),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+        resolve(&mut fixture, round_owner, owner, minnow, true).unwrap();
+
+        // 5% of the 2 SOL milestone, booked as a refund of donations
+        let reward = 2 * SOL * CHALLENGE_REWARD_BPS as u64 / 10_000;
+        assert_eq!(fixture.svm.lamports(&minnow), challenger_before + escrowed + reward);
+        assert_eq!(fixture.svm.lamports(&pda::vault_pda(&owner).0), vault_before - reward);
+        assert!(fixture.svm.account(&escrow).is_none());
+        let upheld = project(&fixture, &owner);
+        assert_eq!((upheld.disputed_amount, upheld.bonds_forfeited), (0, 0));
+        assert_eq!((upheld.ledger.refunds_paid, upheld.current_funding), (reward, 5 * SOL - reward));
+        assert!(!upheld.milestones[1].is_achieved);
+        let review: MilestoneReview = fixture.svm.get(&pda::milestone_review_pda(&pda::project_pda(&owner).0, 1).0);
+        assert!(review.status == MilestoneReviewStatus::Rejected);
+        let event = fixture.svm.events::<ChallengeResolved>().pop().unwrap();
+        assert!(event.upheld);
+        assert_eq!((event.reward, event.resolved_by), (reward, round_owner));
+    }
+
+    #[test]
+    fn test_dismissed_challenge_forfeits_the_bond_outside_the_donation_ledger() {
+        let mut fixture = RoundFixture::new();
+        let (owner, minnow) = challenged_project(&mut fixture);
+        let round_owner = fixture.round_owner;
+        let escrow = challenge_address(&owner, 1);
+        let escrowed = fixture.svm.lamports(&escrow);
+        let challenger_before = fixture.svm.lamports(&minnow);
+        let vault_before = fixture.svm.lamports(&pda::vault_pda(&owner).0);
+        let before = project(&fixture, &owner);
+
+        resolve(&mut fixture, round_owner, owner, minnow, false).unwrap();
+
+        // The challenger only gets the challenge account's rent back
+        assert_eq!(fixture.svm.lamports(&minnow), challenger_before + escrowed - MILESTONE_CHALLENGE_BOND);
+        assert_eq!(
+            fixture.svm.lamports(&pda::vault_pda(&owner).0),
+            vault_before + MILESTONE_CHALLENGE_BOND
+        );
+        let dismissed = project(&fixture, &owner);
+        assert_eq!(dismissed.bonds_forfeited, MILESTONE_CHALLENGE_BOND);
+        assert!(dismissed.ledger == before.ledger);
+        assert_eq!((dismissed.current_funding, dismissed.disputed_amount), (5 * SOL, 0));
+
+        // The milestone counts as released from the end of its challenge window, but
+        // the forfeited bond is not withdrawable on top of the milestones
+        assert!(dismissed.milestones[1].is_achieved);
+        assert_eq!(dismissed.releasable(fixture.svm.clock().unix_timestamp), 3 * SOL);
+        fixture.withdraw(owner, 3 * SOL).unwrap();
+        assert_eq!(
+            fixture.withdraw(owner, 1),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+    }
+}
@@ -0,0 +1,191 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::MILESTONE_GRACE_PERIOD;
+use anectos::error::AnectosError;
+use anectos::state::{FundingRound, FundingStage, MatchingClawedBack, MilestoneDeadlinesSet, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+const DAY: i64 = 24 * 60 * 60;
+/// Due dates of the four milestones; only the first expires in these tests
+const DUE_DATES: [i64; 4] = [ROUND_END + 10 * DAY, ROUND_END + 400 * DAY, ROUND_END + 500 * DAY, ROUND_END + 600 * DAY];
+
+fn set_deadlines(fixture: &mut RoundFixture, owner: Pubkey, due_dates: Vec<i64>) -> Result<(), TxError> {
+    fixture
+        .svm
+        .process(&instructions::set_milestone_deadlines(owner, owner, due_dates), &[owner])
+}
+
+/// A 10 SOL project over 4 milestones (1, 2, 3 and 4 SOL) with `DUE_DATES`, fully
+/// funded by Alice and settled with a 2 SOL matching budget once the round ended.
+fn settled_project(fixture: &mut RoundFixture) -> Pubkey {
+    let owner = fixture.create_project(10 * SOL, 4);
+    set_deadlines(fixture, owner, DUE_DATES.to_vec()).unwrap();
+    let alice = fixture.wallet(20 * SOL);
+    fixture.contribute(alice, owner, 10 * SOL).unwrap();
+    fixture.fund_project_pool(owner, 2 * SOL).unwrap();
+    fixture.svm.warp_to(ROUND_END + 1);
+    fixture.settle(owner).unwrap();
+    owner
+}
+
+fn project(fixture: &RoundFixture, owner: &Pubkey) -> Project {
+    fixture.svm.get(&pda::project_pda(owner).0)
+}
+
+fn clawback(fixture: &mut RoundFixture, owner: Pubkey, rollover_round: Option<Pubkey>) -> Result<(), TxError> {
+    let crank = fixture.wallet(SOL);
+    let ix = instructions::clawback_expired_milestones(crank, fixture.round, owner, rollover_round);
+    fixture.svm.process(&ix, &[crank])
+}
+
+#[cfg(test)]
+mod milestone_deadlines_tests {
+    use super::*;
+
+    #[test]
+    fn test_deadlines_are_set_in_order_while_planning() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let now = fixture.svm.clock().unix_timestamp;
+
+        for due_dates in [
+            DUE_DATES[..3].to_vec(),
+            vec![now, DUE_DATES[1], DUE_DATES[2], DUE_DATES[3]],
+            vec![DUE_DATES[1], DUE_DATES[0], DUE_DATES[2], DUE_DATES[3]],
+            vec![DUE_DATES[0], DUE_DATES[0], DUE_DATES[2], DUE_DATES[3]],
+        ] {
+            assert_eq!(
+                set_deadlines(&mut fixture, owner, due_dates),
+                Err(anchor_error(AnectosError::InvalidMilestoneDeadlines))
+            );
+        }
+        let stranger = fixture.wallet(SOL);
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::set_milestone_deadlines(stranger, owner, DUE_DATES.to_vec()), &[stranger]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+
+        set_deadlines(&mut fixture, owner, DUE_DATES.to_vec()).unwrap();
+        let due: Vec<i64> = project(&fixture, &owner).milestones.iter().map(|m| m.due_date).collect();
+        assert_eq!(due, DUE_DATES);
+        assert_eq!(fixture.svm.events::<MilestoneDeadlinesSet>().pop().unwrap().due_dates, DUE_DATES);
+
+        let (round_owner, round) = (fixture.round_owner, fixture.round);
+        let activate = instructions::change_project_funding_stage(round_owner, owner, round, FundingStage::Active);
+        fixture.svm.process(&activate, &[round_owner]).unwrap();
+        assert_eq!(
+            set_deadlines(&mut fixture, owner, DUE_DATES.to_vec()),
+            Err(anchor_error(AnectosError::InvalidFundingStageStatus))
+        );
+    }
+
+    #[test]
+    fn test_team_projects_cannot_set_deadlines_alone() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let member = fixture.wallet(SOL);
+        fixture
+            .svm
+            .process(&instructions::configure_project_team(owner, owner, vec![owner, member], 2), &[owner])
+            .unwrap();
+        assert_eq!(
+            set_deadlines(&mut fixture, owner, DUE_DATES.to_vec()),
+            Err(anchor_error(AnectosError::TeamApprovalRequired))
+        );
+    }
+
+    #[test]
+    fn test_only_the_round_owner_sets_the_rollover_round() {
+        let mut fixture = RoundFixture::new();
+        let next_owner = fixture.wallet(10 * SOL);
+        let next_round = init_round(&mut fixture.svm, next_owner);
+        let round = fixture.round;
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::set_round_rollover(next_owner, round, next_round), &[next_owner]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        let round_owner = fixture.round_owner;
+        fixture
+            .svm
+            .process(&instructions::set_round_rollover(round_owner, round, next_round), &[round_owner])
+            .unwrap();
+        assert_eq!(fixture.svm.get::<FundingRound>(&round).rollover_round, next_round);
+    }
+
+    #[test]
+    fn test_clawback_waits_out_the_grace_period() {
+        let mut fixture = RoundFixture::new();
+        let owner = settled_project(&mut fixture);
+        let settled = project(&fixture, &owner);
+        let allocated = settled.ledger.matching_allocated;
+        assert!(allocated > 0);
+        let round_vault = fixture.round_vault();
+
+        // Still within the grace period at its last second
+        fixture.svm.warp_to(DUE_DATES[0] + MILESTONE_GRACE_PERIOD);
+        let vault_before = fixture.svm.lamports(&round_vault);
+        clawback(&mut fixture, owner, None).unwrap();
+        assert!(project(&fixture, &owner).ledger == settled.ledger);
+        assert_eq!(fixture.svm.lamports(&round_vault), vault_before);
+
+        // The first milestone's 1 SOL of the 10 SOL target carries a tenth of the matching
+        fixture.svm.warp_to(DUE_DATES[0] + MILESTONE_GRACE_PERIOD + 1);
+        clawback(&mut fixture, owner, None).unwrap();
+        let share = allocated / 10;
+        let event = fixture.svm.events::<MatchingClawedBack>().pop().unwrap();
+        assert_eq!((event.milestone_index, event.amount, event.destination_round), (0, share, fixture.round));
+        assert_eq!(fixture.svm.lamports(&round_vault), vault_before + share);
+        let clawed = project(&fixture, &owner);
+        assert_eq!(clawed.ledger.matching_returned, share);
+        assert!(clawed.milestones[0].matching_clawed_back);
+        assert!(!clawed.milestones[1].matching_clawed_back);
+
+        // A milestone is only clawed back once
+        clawback(&mut fixture, owner, None).unwrap();
+        assert_eq!(project(&fixture, &owner).ledger.matching_returned, share);
+    }
+
+    #[test]
+    fn test_clawback_moves_matching_into_the_rollover_round() {
+        let mut fixture = RoundFixture::new();
+        let next_owner = fixture.wallet(10 * SOL);
+        let next_round = init_round(&mut fixture.svm, next_owner);
+        let owner = settled_project(&mut fixture);
+        let allocated = project(&fixture, &owner).ledger.matching_allocated;
+        let (round_owner, round) = (fixture.round_owner, fixture.round);
+        fixture
+            .svm
+            .process(&instructions::set_round_rollover(round_owner, round, next_round), &[round_owner])
+            .unwrap();
+
+        fixture.svm.warp_to(DUE_DATES[0] + MILESTONE_GRACE_PERIOD + 1);
+        assert_eq!(
+            clawback(&mut fixture, owner, None),
+            Err(anchor_error(AnectosError::InvalidRolloverRound))
+        );
+        let next_vault = pda::round_vault_pda(&next_round).0;
+        let (round_vault_before, next_vault_before) = (
+            fixture.svm.lamports(&fixture.round_vault()),
+            fixture.svm.lamports(&next_vault),
+        );
+        let next_pool_before = fixture.svm.get::<FundingRound>(&next_round).matching_pool;
+
+        clawback(&mut fixture, owner, Some(next_round)).unwrap();
+        let share = allocated / 10;
+        assert_eq!(fixture.svm.lamports(&next_vault), next_vault_before + share);
+        assert_eq!(fixture.svm.lamports(&fixture.round_vault()), round_vault_before);
+        assert_eq!(fixture.svm.get::<FundingRound>(&next_round).matching_pool, next_pool_before + share);
+        let event = fixture.svm.events::<MatchingClawedBack>().pop().unwrap();
+        assert_eq!((event.amount, event.destination_round), (share, next_round));
+        // The returned matching leaves this round's books
+        let round_ledger = fixture.svm.get::<FundingRound>(&round).ledger;
+        assert_eq!(round_ledger.matching_returned, share);
+    }
+}
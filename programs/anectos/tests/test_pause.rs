@@ -0,0 +1,126 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::{PAUSE_ALL, PAUSE_CONTRIBUTIONS, PAUSE_WITHDRAWALS};
+use anectos::error::AnectosError;
+use anectos::state::{PauseUpdated, ProtocolConfig};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn pause(fixture: &mut RoundFixture, round: Option<Pubkey>, groups: u8, approvers: &[Pubkey]) -> Result<(), TxError> {
+    let admin = fixture.admin;
+    let signers: Vec<Pubkey> = std::iter::once(admin).chain(approvers.iter().copied()).collect();
+    fixture
+        .svm
+        .process(&instructions::set_pause(admin, round, groups, approvers), &signers)
+}
+
+/// Installs three guardians with a 2-of-3 threshold; returns them.
+fn with_guardians(fixture: &mut RoundFixture) -> [Pubkey; 3] {
+    let guardians = [fixture.wallet(SOL), fixture.wallet(SOL), fixture.wallet(SOL)];
+    let admin = fixture.admin;
+    fixture
+        .svm
+        .process(&instructions::configure_guardians(admin, guardians.to_vec(), 2, &[]), &[admin])
+        .unwrap();
+    guardians
+}
+
+fn withdraw_contribution(fixture: &mut RoundFixture, contributor: Pubkey, creator: Pubkey, amount: u64) -> Result<(), TxError> {
+    let ix = instructions::withdraw_contribution(contributor, fixture.round, creator, amount);
+    fixture.svm.process(&ix, &[contributor])
+}
+
+#[cfg(test)]
+mod pause_tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_and_unpause_need_the_guardian_threshold() {
+        let mut fixture = RoundFixture::new();
+        let [a, b, c] = with_guardians(&mut fixture);
+        let outsider = fixture.wallet(SOL);
+
+        for approvers in [&[][..], &[a][..], &[a, outsider][..]] {
+            assert_eq!(
+                pause(&mut fixture, None, PAUSE_ALL, approvers),
+                Err(anchor_error(AnectosError::GuardianApprovalMissing))
+            );
+        }
+        pause(&mut fixture, None, PAUSE_ALL, &[a, b]).unwrap();
+        let config: ProtocolConfig = fixture.svm.get(&pda::protocol_config_pda().0);
+        assert_eq!(config.paused_groups, PAUSE_ALL);
+        let event = fixture.svm.events::<PauseUpdated>().pop().unwrap();
+        assert_eq!((event.scope, event.previous_groups, event.paused_groups), (Pubkey::default(), 0, PAUSE_ALL));
+
+        // Unpausing is held to the same threshold, by any two guardians
+        assert_eq!(
+            pause(&mut fixture, None, 0, &[c]),
+            Err(anchor_error(AnectosError::GuardianApprovalMissing))
+        );
+        pause(&mut fixture, None, 0, &[b, c]).unwrap();
+        let config: ProtocolConfig = fixture.svm.get(&pda::protocol_config_pda().0);
+        assert_eq!(config.paused_groups, 0);
+        let event = fixture.svm.events::<PauseUpdated>().pop().unwrap();
+        assert_eq!((event.previous_groups, event.paused_groups, event.updated_by), (PAUSE_ALL, 0, fixture.admin));
+    }
+
+    #[test]
+    fn test_round_pause_is_scoped_to_its_round() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let other_round = init_round(&mut fixture.svm, fixture.round_owner);
+        let other_owner = create_project(&mut fixture.svm, other_round, 100 * SOL, 4);
+        let alice = fixture.wallet(20 * SOL);
+        let contribute_elsewhere = |fixture: &mut RoundFixture, amount: u64| {
+            let ix = instructions::contribute(alice, other_round, other_owner, amount);
+            fixture.svm.process(&ix, &[alice])
+        };
+
+        let round = fixture.round;
+        pause(&mut fixture, Some(round), PAUSE_CONTRIBUTIONS, &[]).unwrap();
+        assert_eq!(fixture.svm.events::<PauseUpdated>().pop().unwrap().scope, round);
+        assert_eq!(fixture.contribute(alice, owner, SOL), Err(anchor_error(AnectosError::OperationPaused)));
+        contribute_elsewhere(&mut fixture, SOL).unwrap();
+
+        // A global pause stops every round, and clearing one round's pause does not lift it
+        pause(&mut fixture, None, PAUSE_CONTRIBUTIONS, &[]).unwrap();
+        assert_eq!(contribute_elsewhere(&mut fixture, SOL + 1), Err(anchor_error(AnectosError::OperationPaused)));
+        pause(&mut fixture, Some(round), 0, &[]).unwrap();
+        assert_eq!(fixture.contribute(alice, owner, SOL), Err(anchor_error(AnectosError::OperationPaused)));
+
+        pause(&mut fixture, None, 0, &[]).unwrap();
+        fixture.contribute(alice, owner, SOL).unwrap();
+        contribute_elsewhere(&mut fixture, SOL + 1).unwrap();
+    }
+
+    #[test]
+    fn test_exits_stay_open_while_everything_else_is_paused() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 4 * SOL).unwrap();
+
+        // Donors can still leave while contributions, settlement, governance and
+        // milestones are frozen
+        let round = fixture.round;
+        pause(&mut fixture, Some(round), PAUSE_ALL & !PAUSE_WITHDRAWALS, &[]).unwrap();
+        withdraw_contribution(&mut fixture, alice, owner, SOL).unwrap();
+        pause(&mut fixture, Some(round), PAUSE_WITHDRAWALS, &[]).unwrap();
+        assert_eq!(
+            withdraw_contribution(&mut fixture, alice, owner, SOL),
+            Err(anchor_error(AnectosError::OperationPaused))
+        );
+        pause(&mut fixture, Some(round), 0, &[]).unwrap();
+
+        // ...and owners can take out released milestones
+        fixture.release_milestones(owner, alice, 1);
+        pause(&mut fixture, None, PAUSE_ALL & !PAUSE_WITHDRAWALS, &[]).unwrap();
+        fixture.withdraw(owner, SOL).unwrap();
+
+        // Pausing withdrawals stops them too; refund claims are never paused (see
+        // test_claim_refund)
+        pause(&mut fixture, None, PAUSE_ALL, &[]).unwrap();
+        assert_eq!(fixture.withdraw(owner, SOL), Err(anchor_error(AnectosError::OperationPaused)));
+    }
+}
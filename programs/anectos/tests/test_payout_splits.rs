@@ -0,0 +1,109 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{FundingStage, PayoutDistributed, PayoutRecipient, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn splits(shares: &[(Pubkey, u16)]) -> Vec<PayoutRecipient> {
+    shares
+        .iter()
+        .map(|&(recipient, share_bps)| PayoutRecipient { recipient, share_bps })
+        .collect()
+}
+
+fn set_splits(fixture: &mut RoundFixture, signer: Pubkey, creator: Pubkey, shares: &[(Pubkey, u16)]) -> Result<(), TxError> {
+    fixture
+        .svm
+        .process(&instructions::set_payout_splits(signer, creator, splits(shares)), &[signer])
+}
+
+fn distribute(fixture: &mut RoundFixture, caller: Pubkey, creator: Pubkey, recipients: &[Pubkey]) -> Result<(), TxError> {
+    let ix = instructions::distribute_payout(caller, creator, fixture.round, recipients);
+    fixture.svm.process(&ix, &[caller])
+}
+
+#[cfg(test)]
+mod payout_splits_tests {
+    use super::*;
+
+    #[test]
+    fn test_released_funds_are_split_by_share() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(SOL + 1, 1);
+        let partners = [fixture.wallet(SOL), fixture.wallet(SOL), fixture.wallet(SOL)];
+        set_splits(&mut fixture, owner, owner, &[(partners[0], 6_000), (partners[1], 3_000), (partners[2], 1_000)]).unwrap();
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, SOL + 1).unwrap();
+
+        // Anyone may trigger the payout; the owner can no longer withdraw directly
+        assert_eq!(
+            fixture.withdraw(owner, SOL),
+            Err(anchor_error(AnectosError::PayoutSplitsConfigured))
+        );
+        let keeper = fixture.wallet(SOL);
+        fixture.release_milestones(owner, alice, 1);
+        distribute(&mut fixture, keeper, owner, &partners).unwrap();
+
+        // Rounding dust goes to the last recipient
+        let received: Vec<u64> = partners.iter().map(|p| fixture.svm.lamports(p) - SOL).collect();
+        assert_eq!(received, vec![600_000_000, 300_000_000, 100_000_001]);
+        let events = fixture.svm.events::<PayoutDistributed>();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().zip(&partners).all(|(e, p)| e.recipient == *p && e.total_released == SOL + 1));
+        assert_eq!(events[1].share_bps, 3_000);
+
+        let project: Project = fixture.svm.get(&pda::project_pda(&owner).0);
+        assert_eq!((project.current_funding, project.ledger.donations_withdrawn), (0, SOL + 1));
+        assert_eq!(
+            distribute(&mut fixture, keeper, owner, &partners),
+            Err(anchor_error(AnectosError::NothingToClaim))
+        );
+    }
+
+    #[test]
+    fn test_splits_are_validated_and_locked_once_active() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(SOL, 1);
+        let (a, b) = (fixture.wallet(SOL), fixture.wallet(SOL));
+
+        assert_eq!(
+            set_splits(&mut fixture, a, owner, &[(a, 10_000)]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        for invalid in [vec![(a, 5_000), (b, 4_999)], vec![(a, 5_000), (a, 5_000)], vec![(a, 10_000), (b, 0)], vec![]] {
+            assert_eq!(
+                set_splits(&mut fixture, owner, owner, &invalid),
+                Err(anchor_error(AnectosError::InvalidPayoutSplits))
+            );
+        }
+        set_splits(&mut fixture, owner, owner, &[(a, 10_000)]).unwrap();
+        // Replacing the splits is allowed while planning
+        set_splits(&mut fixture, owner, owner, &[(a, 5_000), (b, 5_000)]).unwrap();
+
+        let (round, round_owner) = (fixture.round, fixture.round_owner);
+        fixture
+            .svm
+            .process(
+                &instructions::change_project_funding_stage(round_owner, owner, round, FundingStage::Active),
+                &[round_owner],
+            )
+            .unwrap();
+        assert_eq!(
+            set_splits(&mut fixture, owner, owner, &[(a, 10_000)]),
+            Err(anchor_error(AnectosError::PayoutSplitsLocked))
+        );
+
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, SOL).unwrap();
+        fixture.release_milestones(owner, alice, 1);
+        assert_eq!(
+            distribute(&mut fixture, alice, owner, &[b, a]),
+            Err(anchor_error(AnectosError::PayoutRecipientMismatch))
+        );
+        distribute(&mut fixture, alice, owner, &[a, b]).unwrap();
+        assert_eq!((fixture.svm.lamports(&a), fixture.svm.lamports(&b)), (SOL + SOL / 2, SOL + SOL / 2));
+    }
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anectos::error::AnectosError;
+use anectos::state::{Contribution, Project, ProjectOwnerTransferred};
+use anectos::{accounts, instruction};
+use anectos_client::instructions::{self, build};
+use anectos_client::pda;
+use common::*;
+
+/// Withdrawal signed by `owner` for the project created by `creator`.
+fn withdraw_as(fixture: &mut RoundFixture, owner: Pubkey, creator: Pubkey, amount: u64) -> Result<(), TxError> {
+    let ix = build(
+        accounts::DistributeFundsToOwner {
+            owner,
+            vault: pda::vault_pda(&creator).0,
+            project: pda::project_pda(&creator).0,
+            funding_round: fixture.round,
+            protocol_config: pda::protocol_config_pda().0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority_pda().0,
+            program: anectos::ID,
+        },
+        instruction::DistributeFundsToOwner { amount },
+    );
+    fixture.svm.process(&ix, &[owner])
+}
+
+fn propose(fixture: &mut RoundFixture, owner: Pubkey, creator: Pubkey, new_owner: Pubkey) -> Result<(), TxError> {
+    fixture
+        .svm
+        .process(&instructions::propose_project_owner(owner, creator, new_owner), &[owner])
+}
+
+#[cfg(test)]
+mod project_ownership_tests {
+    use super::*;
+
+    #[test]
+    fn test_accepted_transfer_moves_control_but_keeps_addresses() {
+        let mut fixture = RoundFixture::new();
+        let creator = fixture.create_project(10 * SOL, 4);
+        let project = pda::project_pda(&creator).0;
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, creator, 3 * SOL).unwrap();
+        fixture.release_milestones(creator, alice, 1);
+        let new_owner = fixture.wallet(SOL);
+
+        propose(&mut fixture, creator, creator, new_owner).unwrap();
+        let transfer = pda::owner_transfer_pda(&project).0;
+        let rent = fixture.svm.lamports(&transfer);
+        assert!(rent > 0);
+        // Nothing moves until the nominee accepts
+        assert_eq!(
+            withdraw_as(&mut fixture, new_owner, creator, SOL),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+
+        let before = fixture.svm.lamports(&creator);
+        fixture
+            .svm
+            .process(&instructions::accept_project_owner(new_owner, creator, creator), &[new_owner])
+            .unwrap();
+        let state: Project = fixture.svm.get(&project);
+        assert_eq!((state.owner, state.creator), (new_owner, creator));
+        assert!(fixture.svm.account(&transfer).is_none());
+        assert_eq!(fixture.svm.lamports(&creator), before + rent);
+        let event = fixture.svm.events::<ProjectOwnerTransferred>().pop().unwrap();
+        assert_eq!((event.project, event.previous_owner, event.new_owner), (project, creator, new_owner));
+
+        // Withdrawals now go to the new owner, from the same vault
+        assert_eq!(
+            withdraw_as(&mut fixture, creator, creator, SOL),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        let vault = pda::vault_pda(&creator).0;
+        let vault_before = fixture.svm.lamports(&vault);
+        withdraw_as(&mut fixture, new_owner, creator, SOL).unwrap();
+        assert_eq!(fixture.svm.lamports(&new_owner), 2 * SOL);
+        assert_eq!(fixture.svm.lamports(&vault), vault_before - SOL);
+
+        // Existing contribution records keep pointing at the same project
+        fixture.contribute(alice, creator, SOL).unwrap();
+        let contribution: Contribution = fixture.svm.get(&pda::contribution_pda(&project, &alice).0);
+        assert_eq!((contribution.project, contribution.total_contributed), (project, 4 * SOL));
+    }
+
+    #[test]
+    fn test_only_the_owner_proposes_and_only_the_nominee_accepts() {
+        let mut fixture = RoundFixture::new();
+        let creator = fixture.create_project(100 * SOL, 4);
+        let (nominee, mallory) = (fixture.wallet(SOL), fixture.wallet(SOL));
+
+        assert_eq!(
+            propose(&mut fixture, mallory, creator, mallory),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        assert_eq!(
+            propose(&mut fixture, creator, creator, creator),
+            Err(anchor_error(AnectosError::InvalidNewOwner))
+        );
+
+        propose(&mut fixture, creator, creator, mallory).unwrap();
+        // Proposing again replaces the nominee
+        propose(&mut fixture, creator, creator, nominee).unwrap();
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::accept_project_owner(mallory, creator, creator), &[mallory]),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        fixture
+            .svm
+            .process(&instructions::accept_project_owner(nominee, creator, creator), &[nominee])
+            .unwrap();
+        assert_eq!(fixture.svm.get::<Project>(&pda::project_pda(&creator).0).owner, nominee);
+
+        // The new owner can hand the project on again; the creator no longer can
+        assert_eq!(
+            propose(&mut fixture, creator, creator, mallory),
+            Err(anchor_error(ErrorCode::ConstraintHasOne))
+        );
+        propose(&mut fixture, nominee, creator, creator).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{MilestoneReview, MilestoneReviewStatus, ProjectMeta, ProjectTeam, SDGGoals, TeamAction, TeamActionExecuted};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn team(fixture: &RoundFixture, creator: &Pubkey) -> ProjectTeam {
+    fixture.svm.get(&pda::project_team_pda(&pda::project_pda(creator).0).0)
+}
+
+/// Proposes `action` as `member` and returns its id.
+fn propose(fixture: &mut RoundFixture, member: Pubkey, creator: Pubkey, action: TeamAction) -> Result<u64, TxError> {
+    let id = team(fixture, &creator).action_count;
+    fixture
+        .svm
+        .process(&instructions::propose_team_action(member, creator, id, action), &[member])?;
+    Ok(id)
+}
+
+fn approve(fixture: &mut RoundFixture, member: Pubkey, creator: Pubkey, id: u64) -> Result<(), TxError> {
+    fixture
+        .svm
+        .process(&instructions::approve_team_action(member, creator, id), &[member])
+}
+
+fn execute(
+    fixture: &mut RoundFixture,
+    member: Pubkey,
+    creator: Pubkey,
+    id: u64,
+    proposer: Pubkey,
+    action: &TeamAction,
+) -> Result<(), TxError> {
+    let ix = instructions::execute_team_action(member, creator, fixture.round, id, proposer, action, None);
+    fixture.svm.process(&ix, &[member])
+}
+
+#[cfg(test)]
+mod project_team_tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawal_executes_once_the_threshold_approves() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let [a, b, c] = [fixture.wallet(SOL), fixture.wallet(SOL), fixture.wallet(SOL)];
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 5 * SOL).unwrap();
+        // The first two milestones release 3 SOL
+        fixture.release_milestones(owner, alice, 2);
+        fixture
+            .svm
+            .process(&instructions::configure_project_team(owner, owner, vec![a, b, c], 2), &[owner])
+            .unwrap();
+
+        // The owner key alone no longer moves funds
+        assert_eq!(
+            fixture.withdraw(owner, SOL),
+            Err(anchor_error(AnectosError::TeamApprovalRequired))
+        );
+
+        // The team is held to the released milestones like the owner
+        let too_much = TeamAction::Withdraw { amount: 4 * SOL, recipient: a };
+        let id = propose(&mut fixture, a, owner, too_much.clone()).unwrap();
+        approve(&mut fixture, b, owner, id).unwrap();
+        assert_eq!(
+            execute(&mut fixture, a, owner, id, a, &too_much),
+            Err(anchor_error(AnectosError::MilestoneNotReleased))
+        );
+
+        let treasury = fixture.wallet(SOL);
+        let action = TeamAction::Withdraw { amount: 2 * SOL, recipient: treasury };
+        let before = fixture.svm.lamports(&a);
+        let id = propose(&mut fixture, a, owner, action.clone()).unwrap();
+        assert_eq!(
+            execute(&mut fixture, a, owner, id, a, &action),
+            Err(anchor_error(AnectosError::TeamThresholdNotMet))
+        );
+        let mallory = fixture.wallet(SOL);
+        assert_eq!(
+            approve(&mut fixture, mallory, owner, id),
+            Err(anchor_error(AnectosError::NotTeamMember))
+        );
+        approve(&mut fixture, b, owner, id).unwrap();
+        assert_eq!(
+            approve(&mut fixture, b, owner, id),
+            Err(anchor_error(AnectosError::TeamActionAlreadyApproved))
+        );
+
+        execute(&mut fixture, c, owner, id, a, &action).unwrap();
+        assert_eq!(fixture.svm.lamports(&treasury), 3 * SOL);
+        // The proposal is closed and its rent returned to the proposer
+        let project = pda::project_pda(&owner).0;
+        assert!(fixture.svm.account(&pda::team_action_pda(&project, id).0).is_none());
+        assert_eq!(fixture.svm.lamports(&a), before);
+        let event = fixture.svm.events::<TeamActionExecuted>().pop().unwrap();
+        assert_eq!((event.id, event.executed_by, event.approvals), (id, c, 2));
+        assert!(event.action == action);
+    }
+
+    #[test]
+    fn test_team_changes_membership_and_acts_through_proposals() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(4 * SOL, 4);
+        let [a, b, c] = [fixture.wallet(SOL), fixture.wallet(SOL), fixture.wallet(SOL)];
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::configure_project_team(owner, owner, vec![a, a], 1), &[owner]),
+            Err(anchor_error(AnectosError::InvalidTeamConfig))
+        );
+        fixture
+            .svm
+            .process(&instructions::configure_project_team(owner, owner, vec![a, b], 2), &[owner])
+            .unwrap();
+        assert_eq!(
+            fixture
+                .svm
+                .process(&instructions::submit_milestone(owner, fixture.round, 0), &[owner]),
+            Err(anchor_error(AnectosError::TeamApprovalRequired))
+        );
+
+        // b approves a pending withdrawal, then both agree to swap b for c at 1-of-2
+        let withdrawal = TeamAction::Withdraw { amount: SOL, recipient: b };
+        let stale = propose(&mut fixture, b, owner, withdrawal.clone()).unwrap();
+        let rotate = TeamAction::UpdateTeam { members: vec![a, c], threshold: 1 };
+        let id = propose(&mut fixture, a, owner, rotate.clone()).unwrap();
+        approve(&mut fixture, b, owner, id).unwrap();
+        execute(&mut fixture, b, owner, id, a, &rotate).unwrap();
+        assert_eq!((team(&fixture, &owner).members, team(&fixture, &owner).threshold), (vec![a, c], 1));
+
+        // Approvals of removed members no longer count
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 2 * SOL).unwrap();
+        assert_eq!(
+            execute(&mut fixture, a, owner, stale, b, &withdrawal),
+            Err(anchor_error(AnectosError::TeamThresholdNotMet))
+        );
+
+        let title = TeamAction::UpdateMeta {
+            title: "Team wells".into(),
+            description: "Run by a consortium".into(),
+            image_metadata_uri: String::new(),
+            sdg_goals: vec![SDGGoals::CleanWaterAndSanitation],
+        };
+        let id = propose(&mut fixture, c, owner, title.clone()).unwrap();
+        execute(&mut fixture, c, owner, id, c, &title).unwrap();
+        let project = pda::project_pda(&owner).0;
+        let meta: ProjectMeta = fixture.svm.get(&pda::project_metadata_pda(&project).0);
+        assert_eq!((meta.title.as_str(), meta.revision), ("Team wells", 1));
+
+        let submit = TeamAction::SubmitMilestone { milestone_index: 0 };
+        let id = propose(&mut fixture, a, owner, submit.clone()).unwrap();
+        assert_eq!(
+            execute(&mut fixture, c, owner, id, a, &submit),
+            Err(anchor_error(AnectosError::ContributionsStillWithdrawable))
+        );
+        fixture.close_withdrawals();
+        execute(&mut fixture, c, owner, id, a, &submit).unwrap();
+        let review: MilestoneReview = fixture.svm.get(&pda::milestone_review_pda(&project, 0).0);
+        assert!(review.status == MilestoneReviewStatus::Voting);
+    }
+}
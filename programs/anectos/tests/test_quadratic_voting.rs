@@ -0,0 +1,99 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{Proposal, QuadraticVoteCast, VoiceCredits, VotingMode};
+use anectos_client::{instructions, pda};
+use common::*;
+
+const EPOCH: i64 = 7 * 24 * 60 * 60;
+const CREDITS: u64 = 100;
+const MIN_STAKE: u64 = 100;
+
+/// Governance with quadratic voting enabled
+fn quadratic_governance() -> GovernanceFixture {
+    let mut gov = GovernanceFixture::new();
+    let admin = gov.fixture.admin;
+    let ix = instructions::configure_quadratic_voting(admin, CREDITS, EPOCH, 1, MIN_STAKE);
+    gov.fixture.svm.process(&ix, &[admin]).unwrap();
+    gov
+}
+
+/// Moves the clock just past the start of the next epoch.
+fn next_epoch(gov: &mut GovernanceFixture) {
+    let now = gov.fixture.svm.clock().unix_timestamp;
+    gov.fixture.svm.warp_to((now / EPOCH + 1) * EPOCH + 1);
+}
+
+fn qv_vote(gov: &mut GovernanceFixture, voter: Pubkey, id: u64, approve: bool, credits: u64) -> Result<(), TxError> {
+    let epoch = gov.fixture.svm.get::<Proposal>(&pda::proposal_pda(id).0).epoch;
+    gov.fixture
+        .svm
+        .process(&instructions::cast_quadratic_vote(voter, id, epoch, approve, credits), &[voter])
+}
+
+#[cfg(test)]
+mod quadratic_voting_tests {
+    use super::*;
+
+    #[test]
+    fn test_n_votes_cost_n_squared_credits() {
+        let mut gov = quadratic_governance();
+        let (alice, bob, proposer) = (gov.holder(MIN_STAKE), gov.holder(5 * MIN_STAKE), gov.holder(10));
+        gov.stake(alice, MIN_STAKE).unwrap();
+        gov.stake(bob, 5 * MIN_STAKE).unwrap();
+        next_epoch(&mut gov);
+        let (id, _) = gov.propose_whitelist(proposer, VotingMode::Quadratic);
+
+        qv_vote(&mut gov, alice.0, id, true, 9).unwrap();
+        // Topping up to 16 credits adds only the 4th vote
+        qv_vote(&mut gov, alice.0, id, true, 7).unwrap();
+        let events = gov.fixture.svm.events::<QuadraticVoteCast>();
+        assert_eq!((events[0].votes_added, events[0].total_votes), (1, 4));
+        assert_eq!(
+            qv_vote(&mut gov, alice.0, id, false, 1),
+            Err(anchor_error(AnectosError::VoteDirectionMismatch))
+        );
+        assert_eq!(
+            qv_vote(&mut gov, alice.0, id, true, CREDITS - 15),
+            Err(anchor_error(AnectosError::InsufficientVoiceCredits))
+        );
+
+        // A larger stake buys no larger budget
+        qv_vote(&mut gov, bob.0, id, false, CREDITS).unwrap();
+        assert_eq!(
+            qv_vote(&mut gov, bob.0, id, false, 1),
+            Err(anchor_error(AnectosError::InsufficientVoiceCredits))
+        );
+
+        let proposal: Proposal = gov.fixture.svm.get(&pda::proposal_pda(id).0);
+        assert_eq!((proposal.yes_votes, proposal.no_votes, proposal.voter_count), (4, 10, 2));
+        let credits: VoiceCredits = gov.fixture.svm.get(&pda::voice_credits_pda(proposal.epoch, &alice.0).0);
+        assert_eq!((credits.allocated, credits.spent), (CREDITS, 16));
+    }
+
+    #[test]
+    fn test_credits_need_a_stake_locked_before_the_epoch() {
+        let mut gov = quadratic_governance();
+        let (alice, small, late, proposer) =
+            (gov.holder(MIN_STAKE), gov.holder(MIN_STAKE - 1), gov.holder(MIN_STAKE), gov.holder(10));
+        gov.stake(alice, MIN_STAKE).unwrap();
+        gov.stake(small, MIN_STAKE - 1).unwrap();
+        next_epoch(&mut gov);
+        gov.stake(late, MIN_STAKE).unwrap();
+        let (id, _) = gov.propose_whitelist(proposer, VotingMode::Quadratic);
+
+        for voter in [small.0, late.0] {
+            assert_eq!(
+                qv_vote(&mut gov, voter, id, true, 1),
+                Err(anchor_error(AnectosError::InsufficientGovernanceTokens))
+            );
+        }
+
+        // The stake behind a budget cannot move to another wallet during the epoch
+        qv_vote(&mut gov, alice.0, id, true, 1).unwrap();
+        assert_eq!(gov.unstake(alice, MIN_STAKE), Err(anchor_error(AnectosError::StakeLocked)));
+        next_epoch(&mut gov);
+        gov.unstake(alice, MIN_STAKE).unwrap();
+    }
+}
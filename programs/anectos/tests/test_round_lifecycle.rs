@@ -0,0 +1,229 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use anectos::error::AnectosError;
+use anectos::math::{isqrt_u128, matching_allocation};
+use anectos::state::{
+    Contribution, ContributionMade, FundingRound, FundsWithdrawn, MatchingSettled, Project, RoundClosed,
+};
+use anectos::{accounts, instruction};
+use anectos_client::instructions::{self, build};
+use anectos_client::pda;
+use common::*;
+
+/// Two projects with 4 SOL + 1 SOL and 9 SOL of donations, each with a 5 SOL budget.
+/// Project A has a single 5 SOL milestone.
+struct Lifecycle {
+    fixture: RoundFixture,
+    project_a: Pubkey,
+    project_b: Pubkey,
+    alice: Pubkey,
+    bob: Pubkey,
+    carol: Pubkey,
+}
+
+impl Lifecycle {
+    fn new() -> Self {
+        let mut fixture = RoundFixture::new();
+        let project_a = fixture.create_project(5 * SOL, 1);
+        let project_b = fixture.create_project(20 * SOL, 4);
+        let (alice, bob, carol) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+        Self { fixture, project_a, project_b, alice, bob, carol }
+    }
+
+    fn fund_and_contribute(&mut self) {
+        let f = &mut self.fixture;
+        f.fund_project_pool(self.project_a, 5 * SOL).unwrap();
+        f.fund_project_pool(self.project_b, 5 * SOL).unwrap();
+        f.contribute(self.alice, self.project_a, 4 * SOL).unwrap();
+        f.contribute(self.bob, self.project_a, SOL).unwrap();
+        f.contribute(self.carol, self.project_b, 9 * SOL).unwrap();
+    }
+
+    fn project(&self, owner: &Pubkey) -> Project {
+        self.fixture.svm.get(&pda::project_pda(owner).0)
+    }
+}
+
+#[cfg(test)]
+mod round_lifecycle_tests {
+    use super::*;
+
+    #[test]
+    fn test_create_fund_contribute_settle_withdraw_close() {
+        let mut l = Lifecycle::new();
+        let round = l.fixture.round;
+        let round_vault = l.fixture.round_vault();
+        let rent_floor = l.fixture.svm.rent().minimum_balance(0);
+
+        // Fund each project's matching budget; lamports sit in the round vault
+        let funder_before = l.fixture.svm.lamports(&l.fixture.round_owner);
+        l.fixture.fund_project_pool(l.project_a, 5 * SOL).unwrap();
+        l.fixture.fund_project_pool(l.project_b, 5 * SOL).unwrap();
+        assert_eq!(l.fixture.svm.lamports(&l.fixture.round_owner), funder_before - 10 * SOL);
+        assert_eq!(l.fixture.svm.lamports(&round_vault), rent_floor + 10 * SOL);
+        assert_eq!(l.project(&l.project_a).matching_pool, 5 * SOL);
+
+        // Contribute; the contributor pays the donation plus rent for their record
+        let vault_a = pda::vault_pda(&l.project_a).0;
+        let contribution_rent = {
+            l.fixture.contribute(l.alice, l.project_a, 4 * SOL).unwrap();
+            let record = pda::contribution_pda(&pda::project_pda(&l.project_a).0, &l.alice).0;
+            let contribution: Contribution = l.fixture.svm.get(&record);
+            assert_eq!((contribution.contributor, contribution.total_contributed), (l.alice, 4 * SOL));
+            l.fixture.svm.lamports(&record)
+        };
+        assert_eq!(l.fixture.svm.lamports(&l.alice), 6 * SOL - contribution_rent);
+        assert_eq!(l.fixture.svm.lamports(&vault_a), 4 * SOL);
+        let made = l.fixture.svm.events::<ContributionMade>();
+        assert_eq!((made.len(), made[0].amount, made[0].new_total_funding), (1, 4 * SOL, 4 * SOL));
+
+        l.fixture.contribute(l.bob, l.project_a, SOL).unwrap();
+        l.fixture.contribute(l.carol, l.project_b, 9 * SOL).unwrap();
+
+        let area_a = isqrt_u128(4 * SOL as u128) + isqrt_u128(SOL as u128);
+        let area_b = isqrt_u128(9 * SOL as u128);
+        let project_a = l.project(&l.project_a);
+        assert_eq!((project_a.area, project_a.current_funding), (area_a, 5 * SOL));
+        assert_eq!(project_a.ledger.donations_received, 5 * SOL);
+        let funding_round: FundingRound = l.fixture.svm.get(&round);
+        assert_eq!(funding_round.area, area_a + area_b);
+        assert_eq!(funding_round.total_donations, 14 * SOL);
+
+        // Settle both projects; the round vault pays each project vault its allocation
+        let expected_a = matching_allocation(5 * SOL, area_a, area_a + area_b, 0).unwrap() as u64;
+        let expected_b = matching_allocation(5 * SOL, area_b, area_a + area_b, 0).unwrap() as u64;
+        l.fixture.settle(l.project_a).unwrap();
+        let settled = l.fixture.svm.events::<MatchingSettled>();
+        assert_eq!(settled[0].amount, expected_a);
+        assert_eq!(settled[0].round_vault_before - settled[0].round_vault_after, expected_a);
+        l.fixture.settle(l.project_b).unwrap();
+        assert_eq!(l.fixture.svm.lamports(&round_vault), rent_floor + 10 * SOL - expected_a - expected_b);
+        assert_eq!(l.fixture.svm.lamports(&vault_a), 5 * SOL + expected_a);
+        assert_eq!(l.project(&l.project_a).ledger.matching_allocated, expected_a);
+        assert!(l.fixture.svm.get::<FundingRound>(&round).settlement_started);
+
+        // Settling again is a no-op
+        l.fixture.settle(l.project_a).unwrap();
+        assert!(l.fixture.svm.events::<MatchingSettled>().is_empty());
+        assert_eq!(l.fixture.svm.lamports(&vault_a), 5 * SOL + expected_a);
+
+        // Once its milestone is released, the owner withdraws donations first, then matching
+        let alice = l.alice;
+        l.fixture.release_milestones(l.project_a, alice, 1);
+        let owner_before = l.fixture.svm.lamports(&l.project_a);
+        l.fixture.withdraw(l.project_a, 3 * SOL).unwrap();
+        let withdrawn = l.fixture.svm.events::<FundsWithdrawn>();
+        assert_eq!((withdrawn[0].from_donations, withdrawn[0].from_matching), (3 * SOL, 0));
+        let rest = 2 * SOL + expected_a;
+        assert_eq!(
+            l.fixture.withdraw(l.project_a, rest + 1),
+            Err(anchor_error(AnectosError::InsufficientProjectVaultFunds))
+        );
+        l.fixture.withdraw(l.project_a, rest).unwrap();
+        let withdrawn = l.fixture.svm.events::<FundsWithdrawn>();
+        assert_eq!((withdrawn[0].from_donations, withdrawn[0].from_matching), (2 * SOL, expected_a));
+        assert_eq!(l.fixture.svm.lamports(&l.project_a), owner_before + 5 * SOL + expected_a);
+        // Emptied to zero lamports, the vault is garbage collected
+        assert!(l.fixture.svm.account(&vault_a).is_none());
+        let project_a = l.project(&l.project_a);
+        assert_eq!((project_a.current_funding, project_a.ledger.matching_withdrawn), (0, expected_a));
+
+        // Closing returns the round account's rent to its owner
+        let round_owner = l.fixture.round_owner;
+        let round_rent = l.fixture.svm.lamports(&round);
+        let owner_before = l.fixture.svm.lamports(&round_owner);
+        l.fixture
+            .svm
+            .process(&instructions::close_round(round_owner, round), &[round_owner])
+            .unwrap();
+        assert_eq!(l.fixture.svm.events::<RoundClosed>()[0].lamports_reclaimed, round_rent);
+        assert_eq!(l.fixture.svm.lamports(&round_owner), owner_before + round_rent);
+        assert!(l.fixture.svm.account(&round).is_none());
+    }
+
+    #[test]
+    fn test_only_the_project_owner_can_settle() {
+        let mut l = Lifecycle::new();
+        l.fund_and_contribute();
+        let mallory = l.fixture.wallet(SOL);
+        let round = l.fixture.round;
+        let ix = build(
+            accounts::SettleMatchingForProject {
+                owner: mallory,
+                funding_round: round,
+                round_vault: pda::round_vault_pda(&round).0,
+                project: pda::project_pda(&l.project_a).0,
+                project_vault: pda::vault_pda(&l.project_a).0,
+                protocol_config: pda::protocol_config_pda().0,
+                system_program: system_program::ID,
+                event_authority: pda::event_authority_pda().0,
+                program: anectos::ID,
+            },
+            instruction::SettleMatchingForProject {},
+        );
+        assert_eq!(l.fixture.svm.process(&ix, &[mallory]), Err(anchor_error(ErrorCode::ConstraintHasOne)));
+        assert_eq!(l.project(&l.project_a).ledger.matching_allocated, 0);
+    }
+
+    #[test]
+    fn test_only_the_project_owner_can_withdraw() {
+        let mut l = Lifecycle::new();
+        l.fund_and_contribute();
+        let mallory = l.fixture.wallet(SOL);
+        let round = l.fixture.round;
+        let vault_a = pda::vault_pda(&l.project_a).0;
+        // Mallory passes project A's vault and project as her own
+        let ix = build(
+            accounts::DistributeFundsToOwner {
+                owner: mallory,
+                vault: vault_a,
+                project: pda::project_pda(&l.project_a).0,
+                funding_round: round,
+                protocol_config: pda::protocol_config_pda().0,
+                system_program: system_program::ID,
+                event_authority: pda::event_authority_pda().0,
+                program: anectos::ID,
+            },
+            instruction::DistributeFundsToOwner { amount: SOL },
+        );
+        // Seeds come from the project's creator, so ownership itself is what stops her
+        assert_eq!(l.fixture.svm.process(&ix, &[mallory]), Err(anchor_error(ErrorCode::ConstraintHasOne)));
+        // Signing as the owner without their key is rejected before the program runs
+        let ix = instructions::distribute_funds_to_owner(l.project_a, round, SOL);
+        assert_eq!(l.fixture.svm.process(&ix, &[mallory]), Err(TxError::MissingSignature(l.project_a)));
+        assert_eq!(l.fixture.svm.lamports(&vault_a), 5 * SOL);
+    }
+
+    #[test]
+    fn test_vested_matching_unlocks_after_the_cliff() {
+        let mut l = Lifecycle::new();
+        let round_owner = l.fixture.round_owner;
+        let round = l.fixture.round;
+        let ix = instructions::set_round_vesting(round_owner, round, 100, 1_000);
+        l.fixture.svm.process(&ix, &[round_owner]).unwrap();
+        l.fund_and_contribute();
+        let alice = l.alice;
+        l.fixture.release_milestones(l.project_a, alice, 1);
+        l.fixture.settle(l.project_a).unwrap();
+        let allocated = l.project(&l.project_a).ledger.matching_allocated;
+        assert!(allocated > 0);
+
+        // Before the cliff only donations are withdrawable
+        assert_eq!(
+            l.fixture.withdraw(l.project_a, 5 * SOL + 1),
+            Err(anchor_error(AnectosError::InsufficientProjectVaultFunds))
+        );
+        l.fixture.svm.advance_clock(500);
+        l.fixture.withdraw(l.project_a, 5 * SOL + allocated / 2).unwrap();
+        assert_eq!(
+            l.fixture.withdraw(l.project_a, allocated / 2 + 1),
+            Err(anchor_error(AnectosError::InsufficientProjectVaultFunds))
+        );
+        l.fixture.svm.advance_clock(500);
+        l.fixture.withdraw(l.project_a, allocated - allocated / 2).unwrap();
+        assert_eq!(l.project(&l.project_a).ledger.matching_withdrawn, allocated);
+    }
+}
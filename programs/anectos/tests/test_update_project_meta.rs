@@ -0,0 +1,102 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{FundingStage, ProjectMeta, ProjectMetaUpdated, SDGGoals};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn meta(fixture: &RoundFixture, owner: &Pubkey) -> ProjectMeta {
+    let project = pda::project_pda(owner).0;
+    fixture.svm.get(&pda::project_metadata_pda(&project).0)
+}
+
+fn update(
+    fixture: &mut RoundFixture,
+    owner: Pubkey,
+    round_owner: Option<Pubkey>,
+    title: &str,
+) -> Result<(), TxError> {
+    let ix = instructions::update_project_meta(
+        owner,
+        fixture.round,
+        round_owner,
+        title.into(),
+        "Wells and pumps for three villages".into(),
+        "ar://new-image".into(),
+        vec![SDGGoals::CleanWaterAndSanitation, SDGGoals::GoodHealthAndWellBeing, SDGGoals::CleanWaterAndSanitation],
+    );
+    let signers: Vec<Pubkey> = [Some(owner), round_owner].into_iter().flatten().collect();
+    fixture.svm.process(&ix, &signers)
+}
+
+#[cfg(test)]
+mod update_project_meta_tests {
+    use super::*;
+
+    #[test]
+    fn test_owner_updates_metadata_during_planning() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let before = meta(&fixture, &owner);
+        assert_eq!(before.revision, 0);
+
+        update(&mut fixture, owner, None, "Clean water for all").unwrap();
+
+        let after = meta(&fixture, &owner);
+        assert_eq!(after.revision, 1);
+        assert_eq!(after.title, "Clean water for all");
+        assert_eq!(after.image_metadata_uri, "ar://new-image");
+        assert!(after.sdg_goals == vec![SDGGoals::CleanWaterAndSanitation, SDGGoals::GoodHealthAndWellBeing]);
+
+        let event = fixture.svm.events::<ProjectMetaUpdated>().pop().unwrap();
+        assert_eq!(event.revision, 1);
+        assert_eq!(event.previous_content_hash, before.content_hash().unwrap());
+        assert_eq!(event.content_hash, after.content_hash().unwrap());
+        assert_ne!(event.previous_content_hash, event.content_hash);
+        assert_eq!(event.approved_by, None);
+    }
+
+    #[test]
+    fn test_updates_after_planning_need_the_round_owner() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let round = fixture.round;
+        let round_owner = fixture.round_owner;
+        fixture
+            .svm
+            .process(
+                &instructions::change_project_funding_stage(round_owner, owner, round, FundingStage::Active),
+                &[round_owner],
+            )
+            .unwrap();
+
+        assert_eq!(
+            update(&mut fixture, owner, None, "Renamed"),
+            Err(anchor_error(AnectosError::MetadataUpdateRequiresApproval))
+        );
+        let mallory = fixture.wallet(SOL);
+        assert_eq!(
+            update(&mut fixture, owner, Some(mallory), "Renamed"),
+            Err(anchor_error(AnectosError::Unauthorized))
+        );
+        assert_eq!(meta(&fixture, &owner).revision, 0);
+
+        update(&mut fixture, owner, Some(round_owner), "Renamed").unwrap();
+        assert_eq!(meta(&fixture, &owner).title, "Renamed");
+        let event = fixture.svm.events::<ProjectMetaUpdated>().pop().unwrap();
+        assert_eq!(event.approved_by, Some(round_owner));
+        assert!(event.funding_stage == FundingStage::Active);
+    }
+
+    #[test]
+    fn test_update_is_validated_like_creation() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        assert_eq!(
+            update(&mut fixture, owner, None, &"t".repeat(65)),
+            Err(anchor_error(AnectosError::TitleTooLong))
+        );
+        assert_eq!(meta(&fixture, &owner).title, "Clean water");
+    }
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::error::AnectosError;
+use anectos::state::{FundingStage, MatchingClaimed, Project, VestingCancelled};
+use anectos_client::{instructions, pda};
+use common::*;
+
+const CLIFF: i64 = 100;
+const DURATION: i64 = 1_000;
+
+/// A 5 SOL single-milestone project fully funded by Alice, with a 2 SOL matching
+/// budget settled under a `CLIFF`/`DURATION` schedule. With `released` its milestone
+/// is approved before settlement, so vesting alone limits claims.
+/// Returns (fixture, owner, alice).
+fn vesting_project(released: bool) -> (RoundFixture, Pubkey, Pubkey) {
+    let mut fixture = RoundFixture::new();
+    let (round, round_owner) = (fixture.round, fixture.round_owner);
+    fixture
+        .svm
+        .process(&instructions::set_round_vesting(round_owner, round, CLIFF, DURATION), &[round_owner])
+        .unwrap();
+    let owner = fixture.create_project(5 * SOL, 1);
+    let alice = fixture.wallet(10 * SOL);
+    fixture.fund_project_pool(owner, 2 * SOL).unwrap();
+    fixture.contribute(alice, owner, 5 * SOL).unwrap();
+    if released {
+        fixture.release_milestones(owner, alice, 1);
+    }
+    fixture.settle(owner).unwrap();
+    (fixture, owner, alice)
+}
+
+fn claim(fixture: &mut RoundFixture, owner: Pubkey) -> Result<(), TxError> {
+    let ix = instructions::claim_vested(owner, owner, fixture.round);
+    fixture.svm.process(&ix, &[owner])
+}
+
+fn project(fixture: &RoundFixture, owner: &Pubkey) -> Project {
+    fixture.svm.get(&pda::project_pda(owner).0)
+}
+
+#[cfg(test)]
+mod vesting_tests {
+    use super::*;
+
+    #[test]
+    fn test_vested_matching_waits_for_released_milestones() {
+        let (mut fixture, owner, alice) = vesting_project(false);
+        let allocated = project(&fixture, &owner).ledger.matching_allocated;
+        assert_eq!(allocated, 2 * SOL);
+
+        // Fully vested, but no milestone has been approved yet
+        fixture.svm.advance_clock(DURATION);
+        assert_eq!(claim(&mut fixture, owner), Err(anchor_error(AnectosError::MilestoneNotReleased)));
+
+        fixture.release_milestones(owner, alice, 1);
+        let before = fixture.svm.lamports(&owner);
+        claim(&mut fixture, owner).unwrap();
+        assert_eq!(fixture.svm.lamports(&owner), before + allocated);
+        assert_eq!(project(&fixture, &owner).ledger.matching_withdrawn, allocated);
+        let event = fixture.svm.events::<MatchingClaimed>().pop().unwrap();
+        assert_eq!((event.amount, event.vested_total), (allocated, allocated));
+    }
+
+    #[test]
+    fn test_matching_vests_linearly_after_the_cliff() {
+        let (mut fixture, owner, _) = vesting_project(true);
+        let start = project(&fixture, &owner).vesting_start;
+        assert_eq!(start, fixture.svm.clock().unix_timestamp);
+        let total = 2 * SOL;
+
+        fixture.svm.warp_to(start + CLIFF - 1);
+        assert_eq!(claim(&mut fixture, owner), Err(anchor_error(AnectosError::NothingToClaim)));
+
+        // Halfway through, half has vested
+        fixture.svm.warp_to(start + DURATION / 2);
+        let before = fixture.svm.lamports(&owner);
+        claim(&mut fixture, owner).unwrap();
+        assert_eq!(fixture.svm.lamports(&owner), before + total / 2);
+        assert_eq!(claim(&mut fixture, owner), Err(anchor_error(AnectosError::NothingToClaim)));
+
+        fixture.svm.warp_to(start + DURATION);
+        claim(&mut fixture, owner).unwrap();
+        assert_eq!(fixture.svm.lamports(&owner), before + total);
+        let event = fixture.svm.events::<MatchingClaimed>().pop().unwrap();
+        assert_eq!((event.amount, event.vested_total), (total / 2, total));
+        assert_eq!(project(&fixture, &owner).ledger.matching_withdrawn, total);
+        assert_eq!(claim(&mut fixture, owner), Err(anchor_error(AnectosError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_cancel_vesting_returns_unvested_matching_of_a_rejected_project() {
+        let (mut fixture, owner, _) = vesting_project(false);
+        let start = project(&fixture, &owner).vesting_start;
+        let (round, round_owner) = (fixture.round, fixture.round_owner);
+        let cancel = instructions::cancel_vesting(round_owner, round, owner);
+        fixture.svm.warp_to(start + DURATION / 4);
+        assert_eq!(
+            fixture.svm.process(&cancel, &[round_owner]),
+            Err(anchor_error(AnectosError::ProjectNotRejected))
+        );
+
+        let stage = instructions::change_project_funding_stage(round_owner, owner, round, FundingStage::Rejected);
+        fixture.svm.process(&stage, &[round_owner]).unwrap();
+        let (round_vault, vault) = (fixture.round_vault(), pda::vault_pda(&owner).0);
+        let (round_vault_before, vault_before) = (fixture.svm.lamports(&round_vault), fixture.svm.lamports(&vault));
+        fixture.svm.process(&cancel, &[round_owner]).unwrap();
+
+        // Three quarters had not vested yet and go back to the round
+        let returned = 2 * SOL * 3 / 4;
+        assert_eq!(fixture.svm.lamports(&round_vault), round_vault_before + returned);
+        assert_eq!(fixture.svm.lamports(&vault), vault_before - returned);
+        let event = fixture.svm.events::<VestingCancelled>().pop().unwrap();
+        assert_eq!((event.returned, event.vested_total), (returned, 2 * SOL / 4));
+
+        // The vested quarter stays the project's, and vests no further
+        fixture.svm.warp_to(start + DURATION);
+        let cancelled = project(&fixture, &owner);
+        assert_eq!(cancelled.ledger.matching_returned, returned);
+        assert_eq!(cancelled.claimable_matching(start + DURATION), 2 * SOL / 4);
+        assert_eq!(
+            fixture.svm.process(&cancel, &[round_owner]),
+            Err(anchor_error(AnectosError::NothingToClaim))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anectos::constants::MIN_CONTRIBUTION_AMOUNT;
+use anectos::error::AnectosError;
+use anectos::state::{Contribution, ContributionWithdrawn, FundingRound, Project};
+use anectos_client::{instructions, pda};
+use common::*;
+
+fn withdraw_contribution(fixture: &mut RoundFixture, contributor: Pubkey, creator: Pubkey, amount: u64) -> Result<(), TxError> {
+    let ix = instructions::withdraw_contribution(contributor, fixture.round, creator, amount);
+    fixture.svm.process(&ix, &[contributor])
+}
+
+/// (project area, round area, project current_funding, round total_donations)
+fn qf_state(fixture: &RoundFixture, creator: &Pubkey) -> (u128, u128, u64, u64) {
+    let project: Project = fixture.svm.get(&pda::project_pda(creator).0);
+    let round: FundingRound = fixture.svm.get(&fixture.round);
+    (project.area, round.area, project.current_funding, round.total_donations)
+}
+
+#[cfg(test)]
+mod withdraw_contribution_tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawal_restores_the_smaller_contribution_exactly() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(100 * SOL, 4);
+        let (alice, bob) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+        fixture.contribute(bob, owner, 2 * SOL).unwrap();
+        let bob_only = qf_state(&fixture, &owner);
+        fixture.contribute(alice, owner, SOL).unwrap();
+        let before = qf_state(&fixture, &owner);
+        let wallet = fixture.svm.lamports(&alice);
+
+        fixture.contribute(alice, owner, 3 * SOL + 7).unwrap();
+        withdraw_contribution(&mut fixture, alice, owner, 3 * SOL + 7).unwrap();
+        assert_eq!(qf_state(&fixture, &owner), before);
+        assert_eq!(fixture.svm.lamports(&alice), wallet);
+        let event = fixture.svm.events::<ContributionWithdrawn>().pop().unwrap();
+        assert_eq!((event.amount, event.remaining_contribution), (3 * SOL + 7, SOL));
+        assert_eq!(event.new_total_funding, 3 * SOL);
+
+        // Withdrawing everything leaves the round as if Alice never contributed
+        withdraw_contribution(&mut fixture, alice, owner, SOL).unwrap();
+        assert_eq!(qf_state(&fixture, &owner), bob_only);
+        let project = pda::project_pda(&owner).0;
+        let contribution: Contribution = fixture.svm.get(&pda::contribution_pda(&project, &alice).0);
+        assert_eq!(contribution.total_contributed, 0);
+        assert_eq!(fixture.svm.lamports(&alice), wallet + SOL);
+    }
+
+    #[test]
+    fn test_withdrawal_is_bounded_and_closes_before_the_round_ends() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let alice = fixture.wallet(10 * SOL);
+        fixture.contribute(alice, owner, 2 * SOL).unwrap();
+
+        assert_eq!(
+            withdraw_contribution(&mut fixture, alice, owner, 2 * SOL + 1),
+            Err(anchor_error(AnectosError::WithdrawalExceedsContribution))
+        );
+        withdraw_contribution(&mut fixture, alice, owner, SOL / 2).unwrap();
+
+        // Milestones are only reviewed, and so funds only reach the owner, once
+        // contributions are locked in
+        fixture.release_milestones(owner, alice, 1);
+        fixture.withdraw(owner, SOL).unwrap();
+        assert_eq!(
+            withdraw_contribution(&mut fixture, alice, owner, SOL / 2),
+            Err(anchor_error(AnectosError::ContributionWithdrawalClosed))
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_leaves_the_minimum_or_nothing_and_tracks_contributors() {
+        let mut fixture = RoundFixture::new();
+        let owner = fixture.create_project(10 * SOL, 4);
+        let (alice, bob) = (fixture.wallet(10 * SOL), fixture.wallet(10 * SOL));
+        let contributors = |fixture: &RoundFixture| fixture.svm.get::<FundingRound>(&fixture.round).contributor_count;
+        fixture.contribute(alice, owner, SOL).unwrap();
+        fixture.contribute(alice, owner, SOL).unwrap();
+        fixture.contribute(bob, owner, SOL).unwrap();
+        // Repeat contributions do not count Alice twice
+        assert_eq!(contributors(&fixture), 2);
+
+        assert_eq!(
+            withdraw_contribution(&mut fixture, alice, owner, 2 * SOL - MIN_CONTRIBUTION_AMOUNT + 1),
+            Err(anchor_error(AnectosError::RemainingContributionTooSmall))
+        );
+        withdraw_contribution(&mut fixture, alice, owner, 2 * SOL - MIN_CONTRIBUTION_AMOUNT).unwrap();
+        assert_eq!(contributors(&fixture), 2);
+        withdraw_contribution(&mut fixture, alice, owner, MIN_CONTRIBUTION_AMOUNT).unwrap();
+        assert_eq!(contributors(&fixture), 1);
+
+        // Coming back counts again
+        fixture.contribute(alice, owner, SOL).unwrap();
+        assert_eq!(contributors(&fixture), 2);
+    }
+}